@@ -0,0 +1,62 @@
+//! Enemy actions - named special moves defined in enemy data
+
+use super::CardEffect;
+use serde::{Deserialize, Serialize};
+
+/// A named enemy move whose effects resolve through the CombatResolver
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EnemyAction {
+    pub name: String,
+    pub effects: Vec<CardEffect>,
+}
+
+impl EnemyAction {
+    /// Intent text, e.g. "Web: Weak 2" or "Roar: self +3 Strength"
+    pub fn summary(&self) -> String {
+        let parts: Vec<String> = self.effects.iter().filter_map(effect_label).collect();
+        if parts.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}: {}", self.name, parts.join(", "))
+        }
+    }
+
+    /// True when the action deals damage to its target
+    pub fn deals_damage(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| matches!(effect, CardEffect::Damage(_)))
+    }
+
+    /// True when any effect lands on a party member rather than the enemy itself
+    pub fn targets_party(&self) -> bool {
+        self.effects.iter().any(|effect| match effect {
+            CardEffect::Damage(_) | CardEffect::Stress(_) => true,
+            CardEffect::ApplyStatus { target_self, .. } => !target_self,
+            _ => false,
+        })
+    }
+}
+
+fn effect_label(effect: &CardEffect) -> Option<String> {
+    match effect {
+        CardEffect::Damage(amount) => Some(format!("Hit {}", amount)),
+        CardEffect::Block(amount) => Some(format!("Block {}", amount)),
+        CardEffect::Stress(amount) => Some(format!("Stress {}", amount)),
+        CardEffect::Heal(amount) => Some(format!("Heal {}", amount)),
+        CardEffect::ApplyStatus {
+            effect_type,
+            duration,
+            value,
+            target_self,
+        } => {
+            let amount = if *value > 0 { *value } else { *duration };
+            Some(if *target_self {
+                format!("self +{} {:?}", amount, effect_type)
+            } else {
+                format!("{:?} {}", effect_type, amount)
+            })
+        }
+        _ => None,
+    }
+}
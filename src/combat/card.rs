@@ -25,6 +25,57 @@ impl CardClass {
             CardClass::Mystic => class_name == "Mystic",
         }
     }
+
+    /// Class named on the card's badge; None for cards anyone can play
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            CardClass::Any => None,
+            CardClass::Soldier => Some("Soldier"),
+            CardClass::Scout => Some("Scout"),
+            CardClass::Healer => Some("Healer"),
+            CardClass::Mystic => Some("Mystic"),
+        }
+    }
+}
+
+/// Marks a Foundry-upgraded card in an adventurer's deck additions, e.g. "cleave+"
+pub const UPGRADE_SUFFIX: char = '+';
+
+/// The card a deck-addition entry refers to, with any upgrade mark stripped
+pub fn base_card_id(id: &str) -> &str {
+    id.strip_suffix(UPGRADE_SUFFIX).unwrap_or(id)
+}
+
+/// Whether the deck additions already hold this card, upgraded or not
+pub fn knows_card(deck_additions: &[String], id: &str) -> bool {
+    deck_additions.iter().any(|known| base_card_id(known) == id)
+}
+
+/// Fewest cards a class deck may hold; a full starting hand
+const MIN_CLASS_DECK: usize = 5;
+
+/// Broad card category used to filter deck views
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardCategory {
+    Attack,
+    Skill,
+    Power,
+}
+
+impl CardCategory {
+    pub const ALL: [CardCategory; 3] = [
+        CardCategory::Attack,
+        CardCategory::Skill,
+        CardCategory::Power,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CardCategory::Attack => "Attacks",
+            CardCategory::Skill => "Skills",
+            CardCategory::Power => "Powers",
+        }
+    }
 }
 
 /// A playable card
@@ -41,6 +92,9 @@ pub struct Card {
     pub class: CardClass,
     #[serde(default)]
     pub required_knowledge: i32,
+    /// Labels other systems key off, e.g. "movement" cards that wounded legs make dearer
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Card {
@@ -54,51 +108,116 @@ impl Card {
                     | CardEffect::DamageIfLowHp { .. }
                     | CardEffect::DamageIfEnemyActed { .. }
                     | CardEffect::DamageIfVulnerable { .. }
+                    | CardEffect::DamagePerEnergy(_)
             )
         })
     }
 
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// X-cost cards spend all remaining energy and scale with it
+    pub fn is_x_cost(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|e| matches!(e, CardEffect::DamagePerEnergy(_)))
+    }
+
+    /// Attacks deal damage; powers apply statuses or bank energy; everything else is a skill
+    pub fn category(&self) -> CardCategory {
+        if self.is_attack() {
+            CardCategory::Attack
+        } else if self.effects.iter().any(|e| {
+            matches!(
+                e,
+                CardEffect::ApplyStatus { .. }
+                    | CardEffect::GainEnergy(_)
+                    | CardEffect::GainEnergyNextTurn(_)
+                    | CardEffect::ReduceCardCost(_)
+            )
+        }) {
+            CardCategory::Power
+        } else {
+            CardCategory::Skill
+        }
+    }
+
+    pub fn is_upgraded(&self) -> bool {
+        self.id.ends_with(UPGRADE_SUFFIX)
+    }
+
+    /// The Foundry-forged version of this card: a `+` on its id and name, stronger effects
+    pub fn upgraded(&self) -> Card {
+        if self.is_upgraded() {
+            return self.clone();
+        }
+        Card {
+            id: format!("{}{}", self.id, UPGRADE_SUFFIX),
+            name: format!("{}{}", self.name, UPGRADE_SUFFIX),
+            description: format!("{} Forged: stronger effects.", self.description),
+            effects: self.effects.iter().map(CardEffect::upgraded).collect(),
+            ..self.clone()
+        }
+    }
+
     /// Check if this card can be used by the given class
-    #[allow(dead_code)]
     pub fn usable_by(&self, class_name: &str) -> bool {
         self.class.matches(class_name)
     }
 
-    /// Load base cards for a specific class (includes "Any" cards).
-    pub fn load_for_class(class_name: &str) -> Vec<Card> {
+    /// Load base cards for a specific class (includes "Any" cards), with a warning if
+    /// the data left the class short and it was topped up
+    pub fn load_for_class(class_name: &str) -> (Vec<Card>, Option<String>) {
         match crate::data::cards::CardData::load_all() {
-            Ok(all_cards) => all_cards
-                .iter()
-                .filter(|c| c.class_matches(class_name) && !c.is_unlockable())
-                .map(|c| c.to_card())
-                .collect(),
+            Ok(all_cards) => Self::class_deck(&all_cards, class_name),
             Err(e) => {
                 eprintln!("Failed to load cards from JSON: {}. Using fallback.", e);
-                Self::fallback_starter_hand()
+                (Self::fallback_starter_hand(), None)
             }
         }
     }
 
-    /// Load a class deck with Knowledge-unlocked card additions.
-    pub fn load_deck_for_class(class_name: &str, deck_additions: &[String]) -> Vec<Card> {
-        let mut deck = Self::load_for_class(class_name);
+    /// Load a class deck with Knowledge-unlocked card additions, forged ones upgraded,
+    /// and any thin-deck warning from `load_for_class`
+    pub fn load_deck_for_class(
+        class_name: &str,
+        deck_additions: &[String],
+    ) -> (Vec<Card>, Option<String>) {
+        let (mut deck, warning) = Self::load_for_class(class_name);
         if let Ok(all_cards) = crate::data::cards::CardData::load_all() {
-            for id in deck_additions {
-                if let Some(card) = all_cards
-                    .iter()
-                    .find(|c| c.id == *id && c.class_matches(class_name))
-                {
-                    deck.push(card.to_card());
-                }
-            }
+            deck.extend(Self::additions(&all_cards, class_name, deck_additions));
         }
-        deck
+        (deck, warning)
+    }
+
+    /// Cards named by deck-addition ids the class can use; `+` ids come back upgraded
+    fn additions(
+        all_cards: &[crate::data::cards::CardData],
+        class_name: &str,
+        deck_additions: &[String],
+    ) -> Vec<Card> {
+        deck_additions
+            .iter()
+            .filter_map(|id| {
+                let base_id = base_card_id(id);
+                let card = all_cards
+                    .iter()
+                    .find(|c| c.id == base_id && c.class_matches(class_name))?
+                    .to_card();
+                Some(if base_id == id.as_str() {
+                    card
+                } else {
+                    card.upgraded()
+                })
+            })
+            .collect()
     }
 
     /// Load starter hand for a class
     #[allow(dead_code)]
     pub fn starter_hand_for_class(class_name: &str) -> Vec<Card> {
-        let cards = Self::load_for_class(class_name);
+        let (cards, _) = Self::load_for_class(class_name);
         cards.into_iter().take(5).collect()
     }
 
@@ -126,6 +245,40 @@ impl Card {
         }
     }
 
+    /// Base cards for a class, topped up with repeats of the shared cards when the data
+    /// gives it fewer than `MIN_CLASS_DECK` so the class never fights empty-handed. The
+    /// warning says when that happened, for the caller to show
+    fn class_deck(
+        all_cards: &[crate::data::cards::CardData],
+        class_name: &str,
+    ) -> (Vec<Card>, Option<String>) {
+        let mut deck: Vec<Card> = all_cards
+            .iter()
+            .filter(|c| c.class_matches(class_name) && !c.is_unlockable())
+            .map(|c| c.to_card())
+            .collect();
+        if deck.len() >= MIN_CLASS_DECK {
+            return (deck, None);
+        }
+
+        let warning = format!(
+            "cards.json has only {} base cards for {}; topping up with shared cards.",
+            deck.len(),
+            class_name
+        );
+        let mut shared: Vec<Card> = all_cards
+            .iter()
+            .filter(|c| c.class == CardClass::Any && !c.is_unlockable())
+            .map(|c| c.to_card())
+            .collect();
+        if shared.is_empty() {
+            shared = Self::fallback_starter_hand();
+        }
+        let missing = MIN_CLASS_DECK - deck.len();
+        deck.extend(shared.into_iter().cycle().take(missing));
+        (deck, Some(warning))
+    }
+
     /// Fallback hand if JSON loading fails
     fn fallback_starter_hand() -> Vec<Card> {
         vec![
@@ -138,6 +291,7 @@ impl Card {
                 image_path: Some("assets/images/cards/strike.png".to_string()),
                 class: CardClass::Any,
                 required_knowledge: 0,
+                tags: vec![],
             },
             Card {
                 id: "guard".to_string(),
@@ -148,7 +302,67 @@ impl Card {
                 image_path: Some("assets/images/cards/guard.png".to_string()),
                 class: CardClass::Any,
                 required_knowledge: 0,
+                tags: vec![],
             },
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::cards::CardData;
+
+    fn card_data(id: &str, class: CardClass) -> CardData {
+        CardData {
+            id: id.to_string(),
+            name: id.to_string(),
+            cost: 1,
+            description: String::new(),
+            effects: vec![CardEffect::Damage(4)],
+            image_path: None,
+            class,
+            required_knowledge: 0,
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn class_without_cards_still_gets_a_full_playable_hand() {
+        let all_cards = vec![
+            card_data("cleave", CardClass::Soldier),
+            card_data("strike", CardClass::Any),
+            card_data("guard", CardClass::Any),
+        ];
+        let (deck, warning) = Card::class_deck(&all_cards, "Mystic");
+        assert_eq!(deck.len(), MIN_CLASS_DECK);
+        assert!(warning.is_some_and(|w| w.contains("Mystic")));
+        assert!(Card::class_deck(&all_cards, "Soldier").1.is_some());
+        assert!(deck.iter().all(|card| card.usable_by("Mystic")));
+    }
+
+    #[test]
+    fn forged_additions_load_upgraded() {
+        let all_cards = vec![card_data("cleave", CardClass::Soldier)];
+        let additions = vec!["cleave+".to_string()];
+        let cards = Card::additions(&all_cards, "Soldier", &additions);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].id, "cleave+");
+        assert_eq!(cards[0].name, "cleave+");
+        assert_eq!(cards[0].effects, vec![CardEffect::Damage(7)]);
+        assert!(knows_card(&additions, "cleave"));
+    }
+
+    #[test]
+    fn upgrading_twice_changes_nothing() {
+        let card = card_data("cleave", CardClass::Soldier).to_card().upgraded();
+        assert_eq!(card.upgraded().effects, card.effects);
+    }
+
+    #[test]
+    fn class_without_any_shared_cards_falls_back_to_basics() {
+        let (deck, _) = Card::class_deck(&[card_data("cleave", CardClass::Soldier)], "Mystic");
+        assert_eq!(deck.len(), MIN_CLASS_DECK);
+        assert!(deck.iter().all(|card| card.usable_by("Mystic")));
+    }
+}
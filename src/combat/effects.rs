@@ -2,8 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Flat boost a Foundry upgrade gives damage, block, healing and stress-relief values
+const UPGRADE_BONUS: i32 = 3;
+
 /// Effects emitted by cards - resolved by CombatResolver
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum CardEffect {
     /// Deal damage to target
     Damage(i32),
@@ -50,4 +53,117 @@ pub enum CardEffect {
     StressResistance(i32),
     /// Disable playing attack cards for the rest of this turn
     DisableAttacks,
+    /// Lower the cost of cards played later this turn
+    ReduceCardCost(i32),
+    /// X-cost: spend all remaining energy, dealing this much damage per energy spent
+    DamagePerEnergy(i32),
+    /// Bring the foremost downed ally back with this much HP
+    Revive(i32),
+}
+
+impl CardEffect {
+    /// The same effect after a Foundry upgrade; costs such as self-stress and debuffs a
+    /// card puts on its own player stay as they were
+    pub fn upgraded(&self) -> CardEffect {
+        match self.clone() {
+            CardEffect::Damage(n) => CardEffect::Damage(n + UPGRADE_BONUS),
+            CardEffect::Block(n) => CardEffect::Block(n + UPGRADE_BONUS),
+            CardEffect::ReduceStress(n) => CardEffect::ReduceStress(n + UPGRADE_BONUS),
+            CardEffect::Heal(n) => CardEffect::Heal(n + UPGRADE_BONUS),
+            CardEffect::EnemyStress(n) => CardEffect::EnemyStress(n + UPGRADE_BONUS),
+            CardEffect::Revive(n) => CardEffect::Revive(n + UPGRADE_BONUS),
+            CardEffect::DrawCards(n) => CardEffect::DrawCards(n + 1),
+            CardEffect::GainEnergy(n) => CardEffect::GainEnergy(n + 1),
+            CardEffect::GainEnergyNextTurn(n) => CardEffect::GainEnergyNextTurn(n + 1),
+            CardEffect::DamagePerEnergy(n) => CardEffect::DamagePerEnergy(n + 1),
+            CardEffect::StressResistance(n) => {
+                CardEffect::StressResistance((n + UPGRADE_BONUS * 5).min(100))
+            }
+            CardEffect::DamageIfNoBlock { base, bonus } => CardEffect::DamageIfNoBlock {
+                base: base + UPGRADE_BONUS,
+                bonus,
+            },
+            CardEffect::DamageIfLowHp {
+                base,
+                bonus,
+                threshold_percent,
+            } => CardEffect::DamageIfLowHp {
+                base: base + UPGRADE_BONUS,
+                bonus,
+                threshold_percent,
+            },
+            CardEffect::DamageIfEnemyActed { base, bonus } => CardEffect::DamageIfEnemyActed {
+                base: base + UPGRADE_BONUS,
+                bonus,
+            },
+            CardEffect::DamageIfVulnerable { base, bonus } => CardEffect::DamageIfVulnerable {
+                base: base + UPGRADE_BONUS,
+                bonus,
+            },
+            CardEffect::ApplyStatus {
+                effect_type,
+                duration,
+                value,
+                target_self,
+            } if effect_type.is_debuff() != target_self => CardEffect::ApplyStatus {
+                effect_type,
+                duration: duration + 1,
+                value,
+                target_self,
+            },
+            unchanged => unchanged,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgrades_leave_stress_costs_alone() {
+        assert_eq!(CardEffect::Stress(4).upgraded(), CardEffect::Stress(4));
+        assert_eq!(
+            CardEffect::SelfStress(4).upgraded(),
+            CardEffect::SelfStress(4)
+        );
+        assert_eq!(
+            CardEffect::Damage(4).upgraded(),
+            CardEffect::Damage(4 + UPGRADE_BONUS)
+        );
+    }
+
+    #[test]
+    fn upgrades_extend_helpful_statuses_only() {
+        use crate::kingdom::StatusType;
+
+        let status = |effect_type, target_self| CardEffect::ApplyStatus {
+            effect_type,
+            duration: 2,
+            value: 1,
+            target_self,
+        };
+        let longer = |effect_type, target_self| CardEffect::ApplyStatus {
+            effect_type,
+            duration: 3,
+            value: 1,
+            target_self,
+        };
+        assert_eq!(
+            status(StatusType::Vulnerable, true).upgraded(),
+            status(StatusType::Vulnerable, true)
+        );
+        assert_eq!(
+            status(StatusType::Vulnerable, false).upgraded(),
+            longer(StatusType::Vulnerable, false)
+        );
+        assert_eq!(
+            status(StatusType::Strength, true).upgraded(),
+            longer(StatusType::Strength, true)
+        );
+        assert_eq!(
+            status(StatusType::Strength, false).upgraded(),
+            status(StatusType::Strength, false)
+        );
+    }
 }
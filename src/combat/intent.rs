@@ -0,0 +1,79 @@
+//! Intent patterns - a data-driven cycle of telegraphed enemy moves
+
+use super::{EnemyAction, EnemyIntent};
+use serde::{Deserialize, Serialize};
+
+/// One step of an enemy's intent cycle, as written in enemies.json,
+/// e.g. `[{ "attack": 6 }, { "block": 5 }, "buff"]`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentSpec {
+    Attack(i32),
+    AttackAll(i32),
+    Block(i32),
+    Buff,
+    Debuff,
+    /// The enemy's next named action, or a plain attack if it has none
+    Special,
+}
+
+/// Intent for `turn` (counted from 1) from a cycle; None when the cycle is empty.
+/// Each pass through the cycle moves Special on to the enemy's next action
+pub fn pattern_intent(
+    pattern: &[IntentSpec],
+    turn: usize,
+    actions: &[EnemyAction],
+    base_damage: i32,
+) -> Option<EnemyIntent> {
+    if pattern.is_empty() {
+        return None;
+    }
+    let step = turn.saturating_sub(1);
+    Some(match &pattern[step % pattern.len()] {
+        IntentSpec::Attack(amount) => EnemyIntent::Attack(*amount),
+        IntentSpec::AttackAll(amount) => EnemyIntent::AttackAll(*amount),
+        IntentSpec::Block(amount) => EnemyIntent::Block(*amount),
+        IntentSpec::Buff => EnemyIntent::Buff,
+        IntentSpec::Debuff => EnemyIntent::Debuff,
+        IntentSpec::Special if actions.is_empty() => EnemyIntent::Attack(base_damage),
+        IntentSpec::Special => {
+            let cycle = step / pattern.len();
+            EnemyIntent::Special(actions[cycle % actions.len()].clone())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specs_read_from_data() {
+        let pattern: Vec<IntentSpec> =
+            serde_json::from_str(r#"[{ "attack": 6 }, { "block": 5 }, "buff"]"#).unwrap();
+        assert_eq!(
+            pattern,
+            vec![
+                IntentSpec::Attack(6),
+                IntentSpec::Block(5),
+                IntentSpec::Buff
+            ]
+        );
+    }
+
+    #[test]
+    fn the_cycle_repeats_from_turn_one() {
+        let pattern = [IntentSpec::Block(5), IntentSpec::Attack(20)];
+        let intent = |turn| pattern_intent(&pattern, turn, &[], 8);
+        assert_eq!(intent(1), Some(EnemyIntent::Block(5)));
+        assert_eq!(intent(2), Some(EnemyIntent::Attack(20)));
+        assert_eq!(intent(3), Some(EnemyIntent::Block(5)));
+        assert_eq!(pattern_intent(&[], 1, &[], 8), None);
+    }
+
+    #[test]
+    fn special_steps_without_actions_become_attacks() {
+        let intent = pattern_intent(&[IntentSpec::Special], 1, &[], 8);
+        assert_eq!(intent, Some(EnemyIntent::Attack(8)));
+    }
+}
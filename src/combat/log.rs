@@ -0,0 +1,123 @@
+//! Combat log - a bounded record of resolved effects
+
+use std::collections::VecDeque;
+
+/// Most recent entries kept in memory for a single fight
+pub const LOG_CAPACITY: usize = 200;
+/// Where the full log is written when a fight ends with capture enabled
+pub const COMBAT_LOG_DUMP_PATH: &str = "frontier_combat_log.txt";
+
+/// Ring buffer of the last `capacity` log lines, plus an optional full history for debugging
+#[derive(Clone, Debug)]
+pub struct CombatLog {
+    entries: VecDeque<String>,
+    capacity: usize,
+    full_history: Option<Vec<String>>,
+}
+
+impl CombatLog {
+    pub fn new() -> Self {
+        Self::with_capacity(LOG_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            full_history: None,
+        }
+    }
+
+    /// Debug option: with `capture`, also keep every entry so the whole fight can be
+    /// dumped when it ends
+    pub fn with_capture(capture: bool) -> Self {
+        Self {
+            full_history: capture.then(Vec::new),
+            ..Self::new()
+        }
+    }
+
+    /// Record a line, dropping the oldest once the buffer is full
+    pub fn push(&mut self, entry: String) {
+        if let Some(history) = &mut self.full_history {
+            history.push(entry.clone());
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Retained entries, oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &String> {
+        self.entries.iter()
+    }
+
+    /// Write the full history to `path`; does nothing unless capture was on when the fight began
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn dump_full_history(&self, path: &str) -> Result<bool, String> {
+        let Some(history) = &self.full_history else {
+            return Ok(false);
+        };
+        std::fs::write(path, history.join("\n"))
+            .map(|()| true)
+            .map_err(|e| format!("Combat log dump failed: {}", e))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn dump_full_history(&self, _path: &str) -> Result<bool, String> {
+        Ok(false)
+    }
+}
+
+impl Default for CombatLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::{CardEffect, CombatResolver, Unit};
+
+    #[test]
+    fn log_keeps_only_the_most_recent_entries() {
+        let mut resolver = CombatResolver::new();
+        let mut player = Unit::new_player("Marcus", 40);
+        let mut enemy = Unit::new_enemy("Boar", 10_000, None);
+        for _ in 0..100 {
+            resolver.resolve(&CardEffect::Damage(1), &mut player, &mut enemy);
+            resolver.resolve(&CardEffect::Block(1), &mut player, &mut enemy);
+            resolver.resolve(&CardEffect::Damage(1), &mut player, &mut enemy);
+        }
+        let kept = resolver.log.iter().count();
+        assert!(kept > 0 && kept <= LOG_CAPACITY);
+    }
+
+    #[test]
+    fn oldest_entries_fall_off_first() {
+        let mut log = CombatLog::with_capacity(3);
+        for i in 0..5 {
+            log.push(format!("line {}", i));
+        }
+        let kept: Vec<&String> = log.iter().collect();
+        assert_eq!(kept, ["line 2", "line 3", "line 4"]);
+    }
+
+    #[test]
+    fn full_history_is_kept_only_when_captured() {
+        let mut captured = CombatLog::with_capture(true);
+        let mut plain = CombatLog::with_capture(false);
+        for i in 0..LOG_CAPACITY + 5 {
+            captured.push(format!("line {}", i));
+            plain.push(format!("line {}", i));
+        }
+        assert_eq!(
+            captured.full_history.as_ref().map(Vec::len),
+            Some(LOG_CAPACITY + 5)
+        );
+        assert!(plain.full_history.is_none());
+        assert_eq!(captured.iter().count(), LOG_CAPACITY);
+    }
+}
@@ -0,0 +1,17 @@
+//! Loot - cards enemies can leave behind when defeated
+
+use serde::{Deserialize, Serialize};
+
+/// A card an enemy may drop on defeat, with its drop chance (0.0 - 1.0)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LootDrop {
+    pub card_id: String,
+    pub chance: f32,
+}
+
+impl LootDrop {
+    /// Roll this drop against the game RNG
+    pub fn roll(&self) -> bool {
+        macroquad_toolkit::rng::chance(self.chance)
+    }
+}
@@ -2,12 +2,22 @@
 //!
 //! Cards emit effects; systems resolve them. Cards never directly mutate state.
 
+mod action;
 mod card;
 mod effects;
+mod intent;
+mod log;
+mod loot;
+mod reinforcement;
 mod resolver;
 mod unit;
 
-pub use card::{Card, CardClass};
+pub use action::EnemyAction;
+pub use card::{base_card_id, knows_card, Card, CardCategory, CardClass, UPGRADE_SUFFIX};
 pub use effects::CardEffect;
+pub use intent::IntentSpec;
+pub use log::{CombatLog, COMBAT_LOG_DUMP_PATH};
+pub use loot::LootDrop;
+pub use reinforcement::Reinforcement;
 pub use resolver::CombatResolver;
-pub use unit::{EnemyAiPattern, EnemyIntent, Unit};
+pub use unit::{EnemyAiPattern, EnemyIntent, TargetStrategy, Unit};
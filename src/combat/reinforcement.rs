@@ -0,0 +1,31 @@
+//! Reinforcements - help an enemy calls in as a fight drags on
+
+use super::Unit;
+use serde::{Deserialize, Serialize};
+
+/// Another enemy this one calls to its side, announced a round before it arrives
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Reinforcement {
+    /// Enemy template that arrives
+    pub enemy_id: String,
+    /// Round the reinforcement arrives on
+    #[serde(default)]
+    pub turn: Option<usize>,
+    /// Or: called once the caller is at or below this share of its max HP
+    #[serde(default)]
+    pub hp_percent: Option<i32>,
+    /// Announced at the start of this round; arrives at the start of the next
+    #[serde(default)]
+    pub telegraphed: bool,
+}
+
+impl Reinforcement {
+    /// Whether to announce the arrival at the start of `turn`: a round ahead of schedule,
+    /// or as soon as the caller is worn down to its threshold
+    pub fn due_next(&self, turn: usize, caller: &Unit) -> bool {
+        self.turn.is_some_and(|arrival| turn + 1 >= arrival)
+            || self
+                .hp_percent
+                .is_some_and(|percent| caller.hp * 100 <= caller.max_hp * percent)
+    }
+}
@@ -1,6 +1,7 @@
 //! Combat resolution - effects are validated and applied here
 
-use super::{CardEffect, Unit};
+use super::{CardEffect, CombatLog, EnemyAction, Unit};
+use crate::kingdom::StressOutcome;
 
 /// Turn-specific modifiers that reset at end of turn
 #[derive(Clone, Debug, Default)]
@@ -17,19 +18,30 @@ pub struct TurnModifiers {
     pub cards_to_draw: i32,
     /// Energy to gain (accumulated during resolution)
     pub energy_to_gain: i32,
+    /// Energy knocked off every card played for the rest of this turn
+    pub cost_reduction: i32,
+    /// Energy spent on the X-cost card being resolved
+    pub x_energy: i32,
 }
 
 impl TurnModifiers {
     pub fn reset(&mut self) {
         self.stress_resistance = 0;
+        self.reset_member();
+        // Note: enemy_acted_last_turn and energy_next_turn are updated by combat logic
+    }
+
+    /// Clear what the previous member's cards set up for their own turn; stress
+    /// resistance and the enemy's last action stay with the whole party
+    pub fn reset_member(&mut self) {
         self.attacks_disabled = false;
         self.cards_to_draw = 0;
         self.energy_to_gain = 0;
-        // Note: enemy_acted_last_turn and energy_next_turn are updated by combat logic
+        self.cost_reduction = 0;
+        self.x_energy = 0;
     }
 
     /// Called at start of new turn to apply energy from previous turn
-    #[allow(dead_code)]
     pub fn start_turn(&mut self) -> i32 {
         let extra = self.energy_next_turn;
         self.energy_next_turn = 0;
@@ -39,8 +51,8 @@ impl TurnModifiers {
 
 /// Resolves card effects into state changes
 pub struct CombatResolver {
-    /// Log of resolved effects for replay/debugging
-    pub log: Vec<String>,
+    /// Recent resolved effects for the battle report and debugging
+    pub log: CombatLog,
     /// Turn-specific modifiers
     pub turn_mods: TurnModifiers,
 }
@@ -48,7 +60,7 @@ pub struct CombatResolver {
 impl CombatResolver {
     pub fn new() -> Self {
         Self {
-            log: Vec::new(),
+            log: CombatLog::new(),
             turn_mods: TurnModifiers::default(),
         }
     }
@@ -60,36 +72,147 @@ impl CombatResolver {
     }
 
     /// Apply stress with resistance considered
-    pub fn apply_stress_to_player(&self, player: &mut Unit, amount: i32) {
+    pub fn apply_stress_to_player(&mut self, player: &mut Unit, amount: i32) -> StressOutcome {
         let reduced = if self.turn_mods.stress_resistance > 0 {
             let reduction = (amount * self.turn_mods.stress_resistance) / 100;
             (amount - reduction).max(0)
         } else {
             amount
         };
-        player.add_stress(reduced);
+        let outcome = player.add_stress(reduced);
+        self.log_stress_outcome(&player.name, &outcome);
+        outcome
+    }
+
+    /// Record resolve breakthroughs and new traumas in the combat log
+    pub fn log_stress_outcome(&mut self, name: &str, outcome: &StressOutcome) {
+        match outcome {
+            StressOutcome::Breakthrough => self.log.push(format!(
+                "{}'s resolve breaks through! Stress eases and Strength surges",
+                name
+            )),
+            StressOutcome::Trauma(trauma) => {
+                self.log
+                    .push(format!("{} is Afflicted and gains {}", name, trauma.name()))
+            }
+            StressOutcome::Steady => {}
+        }
+    }
+
+    /// Resolve an enemy's special action against a party member.
+    /// Returns (damage taken, stress to apply); stress goes through `apply_stress_to_player`.
+    pub fn resolve_enemy_action(
+        &mut self,
+        action: &EnemyAction,
+        enemy: &mut Unit,
+        player: &mut Unit,
+    ) -> (i32, i32) {
+        self.log
+            .push(format!("{} uses {}", enemy.name, action.name));
+        let hp_before = player.hp;
+        let mut stress = 0;
+        for effect in &action.effects {
+            match effect {
+                CardEffect::Stress(amount) => stress += amount,
+                _ => self.resolve(effect, enemy, player),
+            }
+        }
+        ((hp_before - player.hp).max(0), stress)
+    }
+
+    /// Damage a damage effect sends at the target before its Vulnerable and block.
+    /// None for effects that deal no damage. Shared by `resolve` and `preview`.
+    pub fn effect_damage(&self, effect: &CardEffect, player: &Unit, target: &Unit) -> Option<i32> {
+        let damage = match effect {
+            CardEffect::Damage(amount) => player.modified_damage(*amount),
+            CardEffect::DamageIfNoBlock { base, bonus } => {
+                if target.block == 0 {
+                    base + bonus
+                } else {
+                    *base
+                }
+            }
+            CardEffect::DamageIfLowHp {
+                base,
+                bonus,
+                threshold_percent,
+            } => {
+                let threshold = (player.max_hp * threshold_percent) / 100;
+                if player.hp < threshold {
+                    base + bonus
+                } else {
+                    *base
+                }
+            }
+            CardEffect::DamageIfEnemyActed { base, bonus } => {
+                let total = if self.turn_mods.enemy_acted_last_turn {
+                    base + bonus
+                } else {
+                    *base
+                };
+                player.modified_damage(total)
+            }
+            CardEffect::DamageIfVulnerable { base, bonus } => {
+                if target.has_status(crate::kingdom::StatusType::Vulnerable) {
+                    base + bonus
+                } else {
+                    *base
+                }
+            }
+            CardEffect::DamagePerEnergy(per_energy) => {
+                player.modified_damage(per_energy * self.turn_mods.x_energy)
+            }
+            _ => return None,
+        };
+        Some((damage - player.injury_damage_penalty()).max(0))
+    }
+
+    /// Player and target as they would stand after `effects`, leaving the real units untouched.
+    /// Only HP, block, and statuses are simulated; damage uses `effect_damage` like `resolve`.
+    pub fn preview(&self, effects: &[CardEffect], player: &Unit, target: &Unit) -> (Unit, Unit) {
+        let mut player = player.clone();
+        let mut target = target.clone();
+        for effect in effects {
+            if let Some(damage) = self.effect_damage(effect, &player, &target) {
+                target.take_damage(damage);
+                continue;
+            }
+            match effect {
+                CardEffect::Block(amount) => player.add_block(*amount),
+                CardEffect::Heal(amount) => player.heal(*amount),
+                CardEffect::ClearDebuffs => player.clear_debuffs(),
+                CardEffect::ApplyStatus {
+                    effect_type,
+                    duration,
+                    value,
+                    target_self,
+                } => {
+                    let status =
+                        crate::kingdom::StatusEffect::new(effect_type.clone(), *duration, *value);
+                    let who = if *target_self {
+                        &mut player
+                    } else {
+                        &mut target
+                    };
+                    who.add_status(status);
+                }
+                _ => {}
+            }
+        }
+        (player, target)
     }
 
     /// Resolve an effect from player to target (or self)
     pub fn resolve(&mut self, effect: &CardEffect, player: &mut Unit, target: &mut Unit) {
+        let damage = self.effect_damage(effect, player, target).unwrap_or(0);
         match effect {
-            CardEffect::Damage(amount) => {
-                let mut dmg = *amount;
-                // Apply Strength
-                if let Some(s) = player
-                    .statuses
-                    .iter()
-                    .find(|s| s.effect_type == crate::kingdom::StatusType::Strength)
-                {
-                    dmg += s.value;
-                }
-                // Apply Weak (-25%)
-                if player.has_status(crate::kingdom::StatusType::Weak) {
-                    dmg = (dmg as f32 * 0.75) as i32;
-                }
-                target.take_damage(dmg);
+            CardEffect::Damage(_)
+            | CardEffect::DamageIfNoBlock { .. }
+            | CardEffect::DamageIfLowHp { .. }
+            | CardEffect::DamagePerEnergy(_) => {
+                target.take_damage(damage);
                 self.log
-                    .push(format!("{} takes {} damage", target.name, dmg));
+                    .push(format!("{} takes {} damage", target.name, damage));
             }
             CardEffect::Block(amount) => {
                 player.add_block(*amount);
@@ -102,9 +225,10 @@ impl CombatResolver {
                     .push(format!("{} gains {} stress", target.name, amount));
             }
             CardEffect::SelfStress(amount) => {
-                player.add_stress(*amount);
+                let outcome = player.add_stress(*amount);
                 self.log
                     .push(format!("{} gains {} stress (self)", player.name, amount));
+                self.log_stress_outcome(&player.name, &outcome);
             }
             CardEffect::ReduceStress(amount) => {
                 if player
@@ -151,63 +275,19 @@ impl CombatResolver {
                 self.log
                     .push(format!("{} gains {} stress", target.name, amount));
             }
-            CardEffect::DamageIfNoBlock { base, bonus } => {
-                let total = if target.block == 0 {
-                    base + bonus
-                } else {
-                    *base
-                };
-                target.take_damage(total);
-                self.log
-                    .push(format!("{} takes {} damage", target.name, total));
-            }
-            CardEffect::DamageIfLowHp {
-                base,
-                bonus,
-                threshold_percent,
-            } => {
-                let threshold = (player.max_hp * threshold_percent) / 100;
-                let total = if player.hp < threshold {
-                    base + bonus
-                } else {
-                    *base
-                };
-                target.take_damage(total);
-                self.log
-                    .push(format!("{} takes {} damage", target.name, total));
-            }
-            CardEffect::DamageIfEnemyActed { base, bonus } => {
-                let total = if self.turn_mods.enemy_acted_last_turn {
-                    base + bonus
-                } else {
-                    *base
-                };
-                let mut dmg = total;
-                // Apply Strength
-                if let Some(s) = player
-                    .statuses
-                    .iter()
-                    .find(|s| s.effect_type == crate::kingdom::StatusType::Strength)
-                {
-                    dmg += s.value;
-                }
-                // Apply Weak (-25%)
-                if player.has_status(crate::kingdom::StatusType::Weak) {
-                    dmg = (dmg as f32 * 0.75) as i32;
-                }
-                target.take_damage(dmg);
+            CardEffect::DamageIfEnemyActed { .. } => {
+                target.take_damage(damage);
                 self.log.push(format!(
                     "{} takes {} damage (enemy acted: {})",
-                    target.name, dmg, self.turn_mods.enemy_acted_last_turn
+                    target.name, damage, self.turn_mods.enemy_acted_last_turn
                 ));
             }
-            CardEffect::DamageIfVulnerable { base, bonus } => {
+            CardEffect::DamageIfVulnerable { .. } => {
                 let is_vulnerable = target.has_status(crate::kingdom::StatusType::Vulnerable);
-                let total = if is_vulnerable { base + bonus } else { *base };
-                target.take_damage(total);
+                target.take_damage(damage);
                 self.log.push(format!(
                     "{} takes {} damage (vulnerable: {})",
-                    target.name, total, is_vulnerable
+                    target.name, damage, is_vulnerable
                 ));
             }
             CardEffect::ApplyStatus {
@@ -237,7 +317,28 @@ impl CombatResolver {
                 self.log
                     .push(format!("{} cannot play attacks this turn", player.name));
             }
+            CardEffect::ReduceCardCost(amount) => {
+                self.turn_mods.cost_reduction += amount;
+                self.log.push(format!(
+                    "{}'s cards cost {} less this turn",
+                    player.name, amount
+                ));
+            }
+            // Needs the whole party to pick an ally; `revive_ally` resolves it
+            CardEffect::Revive(_) => {}
+        }
+    }
+
+    /// Stand a downed ally back up with `hp`; false if they are not downed
+    pub fn revive_ally(&mut self, reviver: &str, ally: &mut Unit, hp: i32) -> bool {
+        if !ally.revive(hp) {
+            return false;
         }
+        self.log.push(format!(
+            "{} revives {} with {} HP",
+            reviver, ally.name, ally.hp
+        ));
+        true
     }
 }
 
@@ -246,3 +347,81 @@ impl Default for CombatResolver {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stress_taken(resistance: i32, amount: i32) -> i32 {
+        let mut resolver = CombatResolver::new();
+        resolver.turn_mods.stress_resistance = resistance;
+        let mut player = Unit::new_player("Marcus", 40);
+        resolver.apply_stress_to_player(&mut player, amount);
+        player.stress
+    }
+
+    #[test]
+    fn half_resistance_halves_stress() {
+        assert_eq!(stress_taken(50, 10), 5);
+        // The reduction rounds down, so an odd amount keeps the spare point
+        assert_eq!(stress_taken(50, 7), 4);
+    }
+
+    #[test]
+    fn no_resistance_passes_stress_through() {
+        assert_eq!(stress_taken(0, 9), 9);
+    }
+
+    #[test]
+    fn full_resistance_negates_stress() {
+        assert_eq!(stress_taken(100, 12), 0);
+    }
+
+    #[test]
+    fn revive_only_raises_the_downed() {
+        let mut resolver = CombatResolver::new();
+        let mut ally = Unit::new_player("Bram", 30);
+        assert!(!resolver.revive_ally("Ada", &mut ally, 10));
+        assert_eq!(ally.hp, 30);
+
+        ally.hp = 0;
+        assert!(resolver.revive_ally("Ada", &mut ally, 10));
+        assert_eq!(ally.hp, 10);
+
+        ally.hp = 0;
+        ally.dead = true;
+        assert!(!resolver.revive_ally("Ada", &mut ally, 10));
+        assert_eq!(ally.hp, 0);
+    }
+
+    #[test]
+    fn stacked_resistance_cards_keep_the_strongest() {
+        let mut resolver = CombatResolver::new();
+        let mut player = Unit::new_player("Marcus", 40);
+        let mut enemy = Unit::new_enemy("Boar", 30, None);
+        for percent in [25, 60, 40] {
+            resolver.resolve(
+                &CardEffect::StressResistance(percent),
+                &mut player,
+                &mut enemy,
+            );
+        }
+        assert_eq!(resolver.turn_mods.stress_resistance, 60);
+    }
+
+    #[test]
+    fn broken_arm_blunts_every_attack() {
+        let resolver = CombatResolver::new();
+        let mut player = Unit::new_player("Marcus", 40);
+        player.injuries.push(crate::kingdom::Injury::broken_arm());
+        let enemy = Unit::new_enemy("Boar", 30, None);
+        let hit = |effect| resolver.effect_damage(&effect, &player, &enemy);
+        assert_eq!(hit(CardEffect::Damage(6)), Some(4));
+        assert_eq!(
+            hit(CardEffect::DamageIfNoBlock { base: 3, bonus: 2 }),
+            Some(3)
+        );
+        assert_eq!(hit(CardEffect::Damage(1)), Some(0));
+        assert_eq!(hit(CardEffect::Block(5)), None);
+    }
+}
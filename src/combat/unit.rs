@@ -1,16 +1,33 @@
 //! Combat units - players and enemies
 
-use crate::kingdom::{ResolveState, StatusEffect, StatusType, Trauma, TraumaType};
+use super::intent::pattern_intent;
+use super::{EnemyAction, IntentSpec, LootDrop, Reinforcement};
+use crate::kingdom::{
+    gain_stress, heart_attack_damage, FormationRow, Injury, ResolveState, Stacking, StatusEffect,
+    StatusType, StressOutcome, Trauma,
+};
 use serde::{Deserialize, Serialize};
 
+/// Status duration long enough to outlast any single fight
+const FIGHT_LONG_DURATION: i32 = 99;
+
+/// Bleed a harrier's Debuff opens, per turn, and how many turns it runs
+const HARRIER_BLEED: i32 = 2;
+const HARRIER_BLEED_TURNS: i32 = 3;
+
+/// Turn slot in each four-turn cycle where an enemy uses a special action
+const SPECIAL_ACTION_SLOT: usize = 3;
+
 /// What an enemy intends to do next turn
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum EnemyIntent {
-    Attack(i32), // Damage amount
-    Block(i32),  // Block amount
-    Buff,        // Strengthening self
-    Debuff,      // Weakening player
-    Unknown,     // Intent not yet revealed
+    Attack(i32),          // Damage amount
+    AttackAll(i32),       // Damage to every living party member
+    Block(i32),           // Block amount
+    Buff,                 // Strengthening self
+    Debuff,               // Weakening player
+    Special(EnemyAction), // Named action from enemy data
+    Unknown,              // Intent not yet revealed
 }
 
 impl EnemyIntent {
@@ -18,12 +35,38 @@ impl EnemyIntent {
     pub fn description(&self) -> String {
         match self {
             EnemyIntent::Attack(dmg) => format!("Attack {}", dmg),
+            EnemyIntent::AttackAll(dmg) => format!("Attack all: {}", dmg),
             EnemyIntent::Block(amt) => format!("Block {}", amt),
             EnemyIntent::Buff => "Buff".to_string(),
             EnemyIntent::Debuff => "Debuff".to_string(),
+            EnemyIntent::Special(action) => action.summary(),
             EnemyIntent::Unknown => "???".to_string(),
         }
     }
+
+    /// Formation row this intent reaches; melee hits the front, harriers and hexes reach the back
+    pub fn target_row(&self, pattern: &EnemyAiPattern) -> Option<FormationRow> {
+        match self {
+            EnemyIntent::Attack(_) if *pattern == EnemyAiPattern::Harrier => {
+                Some(FormationRow::Back)
+            }
+            EnemyIntent::Special(action)
+                if action.deals_damage() && *pattern == EnemyAiPattern::Harrier =>
+            {
+                Some(FormationRow::Back)
+            }
+            EnemyIntent::Attack(_) => Some(FormationRow::Front),
+            EnemyIntent::Special(action) if action.deals_damage() => Some(FormationRow::Front),
+            EnemyIntent::Debuff => Some(FormationRow::Back),
+            EnemyIntent::Special(action) if action.targets_party() => Some(FormationRow::Back),
+            _ => None,
+        }
+    }
+
+    /// True for intents that strike every living member rather than one target
+    pub fn hits_whole_party(&self) -> bool {
+        matches!(self, EnemyIntent::AttackAll(_))
+    }
 }
 
 /// Enemy AI pattern loaded from data.
@@ -38,6 +81,34 @@ pub enum EnemyAiPattern {
     Ravager,
 }
 
+/// How an enemy picks which party member its intent lands on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TargetStrategy {
+    /// Go for the most wounded member
+    #[default]
+    LowestHp,
+    /// Strike anyone within reach
+    Random,
+    /// Always the foremost member within reach
+    FrontRow,
+}
+
+impl TargetStrategy {
+    /// Pick a target from `(party index, hp)` candidates listed in formation order
+    pub fn pick(self, candidates: &[(usize, i32)]) -> Option<usize> {
+        match self {
+            TargetStrategy::LowestHp => candidates
+                .iter()
+                .min_by_key(|(_, hp)| *hp)
+                .map(|(idx, _)| *idx),
+            TargetStrategy::Random => {
+                macroquad_toolkit::rng::choose(candidates).map(|(idx, _)| *idx)
+            }
+            TargetStrategy::FrontRow => candidates.first().map(|(idx, _)| *idx),
+        }
+    }
+}
+
 /// A combat unit (player adventurer or enemy)
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Unit {
@@ -55,10 +126,30 @@ pub struct Unit {
     pub ai_pattern: EnemyAiPattern,
     #[serde(default)]
     pub traumas: Vec<Trauma>,
+    /// Wounds carried in from the kingdom; some raise card costs
+    #[serde(default)]
+    pub injuries: Vec<Injury>,
     #[serde(default)]
     pub resolve_state: Option<ResolveState>,
     #[serde(default)]
     pub heart_attacks: u32,
+    #[serde(default)]
+    pub target_strategy: TargetStrategy,
+    /// Cards this enemy may leave behind when defeated
+    #[serde(default)]
+    pub drops: Vec<LootDrop>,
+    /// Named special actions this enemy can take
+    #[serde(default)]
+    pub actions: Vec<EnemyAction>,
+    /// Data-driven intent cycle; empty falls back to the AI pattern's own
+    #[serde(default)]
+    pub intent_pattern: Vec<IntentSpec>,
+    /// Another enemy this one calls in mid-fight
+    #[serde(default)]
+    pub reinforcement: Option<Reinforcement>,
+    /// Beyond saving. A player at 0 HP is only downed, and can be revived, until this is set
+    #[serde(default)]
+    pub dead: bool,
 }
 
 impl Unit {
@@ -76,8 +167,15 @@ impl Unit {
             statuses: vec![],
             ai_pattern: EnemyAiPattern::Bruiser,
             traumas: vec![],
+            injuries: vec![],
             resolve_state: None,
             heart_attacks: 0,
+            target_strategy: TargetStrategy::default(),
+            drops: vec![],
+            actions: vec![],
+            intent_pattern: vec![],
+            reinforcement: None,
+            dead: false,
         }
     }
 
@@ -95,8 +193,15 @@ impl Unit {
             statuses: vec![],
             ai_pattern: EnemyAiPattern::Bruiser,
             traumas: vec![],
+            injuries: vec![],
             resolve_state: None,
             heart_attacks: 0,
+            target_strategy: TargetStrategy::default(),
+            drops: vec![],
+            actions: vec![],
+            intent_pattern: vec![],
+            reinforcement: None,
+            dead: false,
         }
     }
 
@@ -138,8 +243,27 @@ impl Unit {
             statuses: vec![],
             ai_pattern,
             traumas: vec![],
+            injuries: vec![],
             resolve_state: None,
             heart_attacks: 0,
+            target_strategy: TargetStrategy::default(),
+            drops: vec![],
+            actions: vec![],
+            intent_pattern: vec![],
+            reinforcement: None,
+            dead: false,
+        }
+    }
+
+    /// Status a Debuff intent leaves on its target, by AI pattern; harriers draw blood
+    pub fn debuff_status(&self) -> Option<StatusEffect> {
+        match self.ai_pattern {
+            EnemyAiPattern::Harrier => Some(StatusEffect::new(
+                StatusType::Bleed,
+                HARRIER_BLEED_TURNS,
+                HARRIER_BLEED,
+            )),
+            _ => None,
         }
     }
 
@@ -155,7 +279,20 @@ impl Unit {
             return;
         }
 
+        if let Some(intent) =
+            pattern_intent(&self.intent_pattern, turn, &self.actions, self.base_damage)
+        {
+            self.intent = intent;
+            return;
+        }
+
         let pattern = turn % 4;
+        if pattern == SPECIAL_ACTION_SLOT && !self.actions.is_empty() {
+            let action = &self.actions[(turn / 4) % self.actions.len()];
+            self.intent = EnemyIntent::Special(action.clone());
+            return;
+        }
+
         self.intent = match self.ai_pattern {
             EnemyAiPattern::Bruiser => match pattern {
                 0 => EnemyIntent::Attack(self.base_damage),
@@ -196,29 +333,19 @@ impl Unit {
         };
     }
 
-    /// Execute the current intent, returning damage dealt (if attack)
+    /// Execute the current intent, returning damage dealt (if attack).
+    /// Special actions return nothing here; the CombatResolver applies their effects.
     pub fn execute_intent(&mut self) -> (i32, i32) {
         if self.has_status(StatusType::Stun) {
             return (0, 0);
         }
 
-        match self.intent {
-            EnemyIntent::Attack(dmg) => {
-                let mut actual = dmg;
-                if let Some(strength) = self
-                    .statuses
-                    .iter()
-                    .find(|s| s.effect_type == StatusType::Strength)
-                {
-                    actual += strength.value;
-                }
-                if self.has_status(StatusType::Weak) {
-                    actual = (actual as f32 * 0.75) as i32;
-                }
-                (actual.max(0), 0)
+        match &self.intent {
+            EnemyIntent::Attack(dmg) | EnemyIntent::AttackAll(dmg) => {
+                (self.modified_damage(*dmg), 0)
             }
             EnemyIntent::Block(amt) => {
-                self.block += amt;
+                self.block += *amt;
                 (0, 0)
             }
             EnemyIntent::Buff => {
@@ -230,23 +357,72 @@ impl Unit {
                         self.add_status(StatusEffect::new(StatusType::Regen, 3, 3));
                     }
                     _ => {
-                        self.base_damage += 2;
+                        self.base_damage += crate::data::balance().enemy_buff_damage;
                     }
                 }
                 (0, 0)
             }
             EnemyIntent::Debuff => (0, 2), // Returns stress to add
-            EnemyIntent::Unknown => (0, 0),
+            EnemyIntent::Special(_) | EnemyIntent::Unknown => (0, 0),
         }
     }
 
-    pub fn take_damage(&mut self, amount: i32) -> i32 {
-        let mut final_damage = amount;
+    /// Damage the current intent would deal before the target's block, without acting
+    pub fn intent_damage(&self) -> i32 {
+        if self.has_status(StatusType::Stun) {
+            return 0;
+        }
+        match &self.intent {
+            EnemyIntent::Attack(dmg) | EnemyIntent::AttackAll(dmg) => self.modified_damage(*dmg),
+            EnemyIntent::Special(action) => action
+                .effects
+                .iter()
+                .map(|effect| match effect {
+                    super::CardEffect::Damage(amount) => self.modified_damage(*amount),
+                    _ => 0,
+                })
+                .sum(),
+            _ => 0,
+        }
+    }
 
-        // Vulnerable: +50% damage
+    /// Apply this unit's Strength and Weak to an outgoing hit
+    pub fn modified_damage(&self, base: i32) -> i32 {
+        let mut actual = base;
+        if let Some(strength) = self
+            .statuses
+            .iter()
+            .find(|s| s.effect_type == StatusType::Strength)
+        {
+            actual += strength.value;
+        }
+        if self.has_status(StatusType::Weak) {
+            actual = (actual as f32 * 0.75) as i32;
+        }
+        actual.max(0)
+    }
+
+    /// Damage the unit's injuries take off each attack it makes
+    pub fn injury_damage_penalty(&self) -> i32 {
+        self.injuries.iter().map(|i| i.damage_penalty()).sum()
+    }
+
+    /// Incoming hit after Vulnerable (+50% damage), before block
+    fn vulnerable_damage(&self, amount: i32) -> i32 {
         if self.has_status(StatusType::Vulnerable) {
-            final_damage = (final_damage as f32 * 1.5) as i32;
+            (amount as f32 * 1.5) as i32
+        } else {
+            amount
         }
+    }
+
+    /// HP this unit would lose to a hit of `amount`, after Vulnerable and block
+    pub fn damage_through_block(&self, amount: i32) -> i32 {
+        (self.vulnerable_damage(amount) - self.block).max(0)
+    }
+
+    pub fn take_damage(&mut self, amount: i32) -> i32 {
+        let final_damage = self.vulnerable_damage(amount);
 
         let blocked = final_damage.min(self.block);
         self.block -= blocked;
@@ -260,40 +436,38 @@ impl Unit {
         self.block += amount;
     }
 
-    pub fn add_stress(&mut self, amount: i32) {
-        let before = self.stress;
-        self.stress = (self.stress + amount).max(0);
-        let reached_max_stress = self.stress >= 200;
-
-        if !self.is_player || amount <= 0 {
-            return;
-        }
-
-        if before < 100 && self.stress >= 100 && self.resolve_state.is_none() {
-            if macroquad_toolkit::rng::chance(0.25) {
-                self.resolve_state = Some(ResolveState::Virtuous);
-                self.stress = 80;
-                self.add_status(StatusEffect::new(StatusType::Strength, 3, 2));
-                self.add_status(StatusEffect::new(StatusType::Regen, 3, 2));
-            } else {
-                self.resolve_state = Some(ResolveState::Afflicted);
-                self.stress = 100;
-                self.add_status(StatusEffect::new(StatusType::Weak, 3, 0));
-                if !self
-                    .traumas
-                    .iter()
-                    .any(|t| t.trauma_type == TraumaType::Broken)
-                {
-                    self.traumas.push(Trauma::new(TraumaType::Broken));
-                }
-            }
+    pub fn add_stress(&mut self, amount: i32) -> StressOutcome {
+        if !self.is_player {
+            self.stress = (self.stress + amount).max(0);
+            return StressOutcome::Steady;
         }
 
-        if reached_max_stress || self.stress >= 200 {
+        let change = gain_stress(
+            &mut self.stress,
+            &mut self.traumas,
+            &mut self.resolve_state,
+            amount,
+        );
+        if change.heart_attack {
             self.heart_attacks += 1;
-            self.hp -= (self.max_hp / 2).max(1);
-            self.stress = 100;
+            self.hp -= heart_attack_damage(self.max_hp);
+        }
+        if change.afflicted {
+            self.add_status(StatusEffect::new(StatusType::Weak, 3, 0));
         }
+        if matches!(change.outcome, StressOutcome::Breakthrough) {
+            self.grant_breakthrough_buff();
+        }
+        change.outcome
+    }
+
+    /// Strength that lasts for the rest of the fight after a resolve breakthrough
+    pub fn grant_breakthrough_buff(&mut self) {
+        self.add_status(StatusEffect::new(
+            StatusType::Strength,
+            FIGHT_LONG_DURATION,
+            crate::data::balance().breakthrough_strength,
+        ));
     }
 
     pub fn reduce_stress(&mut self, amount: i32) {
@@ -305,24 +479,47 @@ impl Unit {
         self.hp = (self.hp + amount).min(self.max_hp);
     }
 
+    /// A fallen party member who can still be brought back this fight
+    pub fn is_downed(&self) -> bool {
+        self.is_player && self.hp <= 0 && !self.dead
+    }
+
+    /// Stand a downed member back up with `hp`, shedding the statuses that dropped them
+    pub fn revive(&mut self, hp: i32) -> bool {
+        if !self.is_downed() {
+            return false;
+        }
+        self.hp = hp.clamp(1, self.max_hp.max(1));
+        self.block = 0;
+        self.statuses.clear();
+        true
+    }
+
     /// Clear all debuff status effects
     pub fn clear_debuffs(&mut self) {
         self.statuses.retain(|s| !s.is_debuff());
     }
 
+    /// Add a status, combining with one of the same type by its `StatusType::stacking` rule
     pub fn add_status(&mut self, effect: StatusEffect) {
-        // Check if existing status of same type, extend duration or value?
-        // Simple simplified rule: Add to list. (Duplicates stack intensity?)
-        // Or unique by type?
-        // Let's replace if exists for simplicity, or add if unique.
         if let Some(existing) = self
             .statuses
             .iter_mut()
             .find(|s| s.effect_type == effect.effect_type)
         {
-            existing.duration = existing.duration.max(effect.duration);
-            if effect.value > existing.value {
-                existing.value = effect.value;
+            match effect.effect_type.stacking() {
+                Stacking::Refresh => {
+                    existing.duration = existing.duration.max(effect.duration);
+                    existing.value = existing.value.max(effect.value);
+                }
+                Stacking::SumValue => {
+                    existing.duration = existing.duration.max(effect.duration);
+                    existing.value += effect.value;
+                }
+                Stacking::SumDuration => {
+                    existing.duration += effect.duration;
+                    existing.value = existing.value.max(effect.value);
+                }
             }
         } else {
             self.statuses.push(effect);
@@ -333,17 +530,17 @@ impl Unit {
         self.statuses.iter().any(|s| s.effect_type == status_type)
     }
 
+    /// Apply Regen, Poison, Burn, and Bleed, then count every status down a turn
     pub fn tick_statuses(&mut self) {
-        // Apply end-of-turn effects like Regen/Bleed here?
-        // Or simplify: just reduce duration.
-        // Let's implement Regen here.
-
         let mut hp_change = 0;
 
         self.statuses.retain_mut(|s| {
             if s.effect_type == StatusType::Regen {
                 hp_change += s.value;
-            } else if s.effect_type == StatusType::Poison || s.effect_type == StatusType::Burn {
+            } else if matches!(
+                s.effect_type,
+                StatusType::Poison | StatusType::Burn | StatusType::Bleed
+            ) {
                 hp_change -= s.value;
             }
 
@@ -351,8 +548,187 @@ impl Unit {
             s.duration > 0
         });
 
-        if hp_change != 0 {
+        // The fallen do not regenerate, and nothing heals past max_hp even if it shrank mid-fight
+        if hp_change != 0 && self.hp > 0 {
             self.hp = (self.hp + hp_change).min(self.max_hp);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::TraumaType;
+
+    #[test]
+    fn lowest_hp_strategy_picks_most_wounded() {
+        let candidates = [(0, 30), (1, 12), (2, 25)];
+        assert_eq!(TargetStrategy::LowestHp.pick(&candidates), Some(1));
+    }
+
+    #[test]
+    fn front_row_strategy_picks_foremost() {
+        let candidates = [(2, 40), (3, 5)];
+        assert_eq!(TargetStrategy::FrontRow.pick(&candidates), Some(2));
+    }
+
+    #[test]
+    fn random_strategy_stays_within_candidates() {
+        let candidates = [(1, 10), (3, 20)];
+        let picked = TargetStrategy::Random.pick(&candidates);
+        assert!(picked.is_some_and(|idx| idx == 1 || idx == 3));
+    }
+
+    #[test]
+    fn strategies_return_none_without_candidates() {
+        assert_eq!(TargetStrategy::LowestHp.pick(&[]), None);
+        assert_eq!(TargetStrategy::FrontRow.pick(&[]), None);
+    }
+
+    #[test]
+    fn enemies_default_to_lowest_hp() {
+        let enemy = Unit::new_enemy("Forest Beast", 30, None);
+        assert_eq!(enemy.target_strategy, TargetStrategy::LowestHp);
+    }
+
+    fn web() -> EnemyAction {
+        EnemyAction {
+            name: "Web".to_string(),
+            effects: vec![crate::combat::CardEffect::ApplyStatus {
+                effect_type: StatusType::Weak,
+                duration: 2,
+                value: 0,
+                target_self: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn special_action_takes_its_turn_slot() {
+        let mut enemy = Unit::new_enemy("Spider", 20, None);
+        enemy.actions = vec![web()];
+        enemy.roll_intent(SPECIAL_ACTION_SLOT);
+        assert_eq!(enemy.intent, EnemyIntent::Special(web()));
+        assert_eq!(enemy.intent.description(), "Web: Weak 2");
+        enemy.roll_intent(SPECIAL_ACTION_SLOT + 1);
+        assert!(matches!(enemy.intent, EnemyIntent::Attack(_)));
+    }
+
+    #[test]
+    fn special_action_debuffs_through_resolver() {
+        let mut enemy = Unit::new_enemy("Spider", 20, None);
+        let mut player = Unit::new_player("Hero", 30);
+        let mut resolver = crate::combat::CombatResolver::new();
+        let (damage, stress) = resolver.resolve_enemy_action(&web(), &mut enemy, &mut player);
+        assert_eq!((damage, stress), (0, 0));
+        assert!(player.has_status(StatusType::Weak));
+    }
+
+    #[test]
+    fn lethal_math_counts_strength_vulnerable_and_block() {
+        let mut enemy = Unit::new_enemy_with_pattern("Brute", 30, 8, None, EnemyAiPattern::Bruiser);
+        enemy.intent = EnemyIntent::Attack(8);
+        enemy.add_status(StatusEffect::new(StatusType::Strength, 2, 2));
+        assert_eq!(enemy.intent_damage(), 10);
+
+        let mut player = Unit::new_player("Hero", 30);
+        player.add_status(StatusEffect::new(StatusType::Vulnerable, 2, 0));
+        player.add_block(5);
+        assert_eq!(player.damage_through_block(enemy.intent_damage()), 10);
+    }
+
+    #[test]
+    fn bleed_runs_its_course_through_block() {
+        let mut unit = Unit::new_player("Hero", 30);
+        unit.block = 10;
+        unit.add_status(StatusEffect::new(StatusType::Bleed, 3, 2));
+        for _ in 0..4 {
+            unit.tick_statuses();
+        }
+        assert_eq!(unit.hp, 24);
+        assert!(unit.statuses.is_empty());
+    }
+
+    #[test]
+    fn harriers_debuff_with_bleed() {
+        let mut enemy = Unit::new_enemy("Cutthroat", 30, None);
+        assert!(enemy.debuff_status().is_none());
+        enemy.ai_pattern = EnemyAiPattern::Harrier;
+        let bleed = enemy.debuff_status().unwrap();
+        assert_eq!(bleed.effect_type, StatusType::Bleed);
+        assert_eq!(bleed.value * bleed.duration, 6);
+    }
+
+    #[test]
+    fn poison_values_sum_when_reapplied() {
+        let mut unit = Unit::new_player("Hero", 30);
+        unit.add_status(StatusEffect::new(StatusType::Poison, 3, 2));
+        unit.add_status(StatusEffect::new(StatusType::Poison, 2, 3));
+        let poison = &unit.statuses[0];
+        assert_eq!(unit.statuses.len(), 1);
+        assert_eq!((poison.duration, poison.value), (3, 5));
+    }
+
+    #[test]
+    fn vulnerable_refreshes_duration_without_stacking() {
+        let mut unit = Unit::new_player("Hero", 30);
+        unit.add_status(StatusEffect::new(StatusType::Vulnerable, 2, 0));
+        unit.tick_statuses();
+        unit.add_status(StatusEffect::new(StatusType::Vulnerable, 2, 0));
+        let vulnerable = &unit.statuses[0];
+        assert_eq!(unit.statuses.len(), 1);
+        assert_eq!((vulnerable.duration, vulnerable.value), (2, 0));
+    }
+
+    #[test]
+    fn regen_heals_enemies_and_players_up_to_max_hp() {
+        let mut enemy = Unit::new_enemy("Troll", 40, None);
+        let mut player = Unit::new_player("Hero", 30);
+        enemy.hp = 30;
+        player.hp = 25;
+        enemy.add_status(StatusEffect::new(StatusType::Regen, 4, 4));
+        player.add_status(StatusEffect::new(StatusType::Regen, 4, 2));
+        for _ in 0..4 {
+            enemy.tick_statuses();
+            player.tick_statuses();
+        }
+        assert_eq!(enemy.hp, 40);
+        assert_eq!(player.hp, 30);
+        assert!(enemy.statuses.is_empty() && player.statuses.is_empty());
+    }
+
+    #[test]
+    fn regen_clamps_to_a_lowered_max_hp() {
+        let mut enemy = Unit::new_enemy("Troll", 40, None);
+        enemy.add_status(StatusEffect::new(StatusType::Regen, 2, 5));
+        enemy.max_hp = 30;
+        enemy.tick_statuses();
+        assert_eq!(enemy.hp, 30);
+    }
+
+    #[test]
+    fn crossing_a_stress_threshold_mid_fight_reports_the_trauma() {
+        let mut player = Unit::new_player("Marcus", 40);
+        player.stress = crate::data::balance().fearful_stress - 1;
+        let outcome = player.add_stress(2);
+        assert!(matches!(
+            outcome,
+            StressOutcome::Trauma(ref trauma) if trauma.trauma_type == TraumaType::Fearful
+        ));
+
+        // Already Fearful: staying above the line raises nothing new
+        assert!(matches!(player.add_stress(1), StressOutcome::Steady));
+    }
+
+    #[test]
+    fn heart_attack_lands_before_the_resolve_roll() {
+        let balance = crate::data::balance();
+        for _ in 0..20 {
+            let mut player = Unit::new_player("Marcus", 40);
+            player.stress = balance.max_stress - 10;
+            player.add_stress(balance.heart_attack_stress);
+            assert_eq!(player.heart_attacks, 1);
+            assert_eq!(player.hp, 20);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Balance tuning - stress thresholds, treatment costs, and rewards from balance.json
+
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+static BALANCE: OnceLock<BalanceConfig> = OnceLock::new();
+
+/// Numbers designers tune without recompiling; anything missing from the file keeps its default
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BalanceConfig {
+    /// Stress at which an adventurer turns Fearful
+    pub fearful_stress: i32,
+    /// Stress at which an adventurer turns Paranoid
+    pub paranoid_stress: i32,
+    /// Stress ceiling: reaching it forces a resolve check and breaks the Afflicted
+    pub max_stress: i32,
+    /// Stress at which the heart gives out
+    pub heart_attack_stress: i32,
+    /// Odds that reaching max stress becomes a resolve breakthrough instead of an affliction
+    pub resolve_breakthrough_chance: f32,
+    /// Stress an adventurer drops to after a resolve breakthrough
+    pub breakthrough_stress: i32,
+    /// Strength granted for the rest of the fight by a resolve breakthrough
+    pub breakthrough_strength: i32,
+    /// Odds that a Chapel visit bringing stress to 0 sheds a trauma
+    pub chapel_trauma_recovery_chance: f32,
+    /// Daily odds that a calm adventurer sheds a low-severity trauma on their own
+    pub daily_trauma_recovery_chance: f32,
+    /// Supplies spent on one infirmary treatment
+    pub infirmary_supply_cost: i32,
+    /// HP restored by one infirmary treatment
+    pub infirmary_heal: i32,
+    /// Supplies spent on one chapel visit
+    pub chapel_supply_cost: i32,
+    /// Stress relieved by one chapel visit
+    pub chapel_stress_relief: i32,
+    /// Flat XP each survivor earns for a won mission
+    pub victory_xp_base: i32,
+    /// Extra victory XP per point of mission difficulty
+    pub victory_xp_per_difficulty: i32,
+    /// Percent of a mission's listed rewards actually paid out
+    pub mission_reward_percent: i32,
+    /// Energy each member starts a turn with before mission modifiers
+    pub base_energy: i32,
+    /// Stress the targeted member takes from every enemy action
+    pub enemy_turn_stress: i32,
+    /// Percent of max HP a rest node restores
+    pub rest_heal_percent: i32,
+    /// Stress a rest node relieves
+    pub rest_stress_relief: i32,
+    /// Damage an enemy gains from a plain Buff intent
+    pub enemy_buff_damage: i32,
+}
+
+impl Default for BalanceConfig {
+    fn default() -> Self {
+        Self {
+            fearful_stress: 50,
+            paranoid_stress: 75,
+            max_stress: 100,
+            heart_attack_stress: 200,
+            resolve_breakthrough_chance: 0.5,
+            breakthrough_stress: 50,
+            breakthrough_strength: 2,
+            chapel_trauma_recovery_chance: 0.5,
+            daily_trauma_recovery_chance: 0.15,
+            infirmary_supply_cost: 10,
+            infirmary_heal: 10,
+            chapel_supply_cost: 10,
+            chapel_stress_relief: 20,
+            victory_xp_base: 10,
+            victory_xp_per_difficulty: 2,
+            mission_reward_percent: 100,
+            base_energy: 3,
+            enemy_turn_stress: 2,
+            rest_heal_percent: 10,
+            rest_stress_relief: 5,
+            enemy_buff_damage: 2,
+        }
+    }
+}
+
+impl BalanceConfig {
+    /// Load the tuning values from the balance.json asset file
+    pub fn load() -> Result<BalanceConfig, String> {
+        let balance = crate::load_asset!("balance.json", BalanceConfig)?;
+        balance.validate()?;
+        Ok(balance)
+    }
+
+    /// Reject values that would break a fight or the stress ladder
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0 < self.fearful_stress
+            && self.fearful_stress < self.paranoid_stress
+            && self.paranoid_stress < self.max_stress
+            && self.max_stress < self.heart_attack_stress)
+        {
+            return Err(
+                "balance.json: stress thresholds must rise from fearful to heart attack"
+                    .to_string(),
+            );
+        }
+        let ranges = [
+            ("base_energy", self.base_energy, 1, 10),
+            ("enemy_turn_stress", self.enemy_turn_stress, 0, 50),
+            ("rest_heal_percent", self.rest_heal_percent, 0, 100),
+            (
+                "rest_stress_relief",
+                self.rest_stress_relief,
+                0,
+                self.max_stress,
+            ),
+            ("enemy_buff_damage", self.enemy_buff_damage, 0, 20),
+            (
+                "breakthrough_stress",
+                self.breakthrough_stress,
+                0,
+                self.max_stress - 1,
+            ),
+            ("breakthrough_strength", self.breakthrough_strength, 0, 20),
+            (
+                "mission_reward_percent",
+                self.mission_reward_percent,
+                0,
+                1000,
+            ),
+            ("infirmary_supply_cost", self.infirmary_supply_cost, 0, 1000),
+            ("chapel_supply_cost", self.chapel_supply_cost, 0, 1000),
+        ];
+        for (name, value, min, max) in ranges {
+            if !(min..=max).contains(&value) {
+                return Err(format!(
+                    "balance.json: {} is {}, expected {} to {}",
+                    name, value, min, max
+                ));
+            }
+        }
+        let chances = [
+            (
+                "resolve_breakthrough_chance",
+                self.resolve_breakthrough_chance,
+            ),
+            (
+                "chapel_trauma_recovery_chance",
+                self.chapel_trauma_recovery_chance,
+            ),
+            (
+                "daily_trauma_recovery_chance",
+                self.daily_trauma_recovery_chance,
+            ),
+        ];
+        for (name, chance) in chances {
+            if !(0.0..=1.0).contains(&chance) {
+                return Err(format!(
+                    "balance.json: {} is {}, expected 0 to 1",
+                    name, chance
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// A mission reward after the payout percentage
+    pub fn mission_reward(&self, listed: i32) -> i32 {
+        listed * self.mission_reward_percent / 100
+    }
+}
+
+/// The game's balance values, read from disk the first time they are needed
+pub fn balance() -> &'static BalanceConfig {
+    BALANCE.get_or_init(|| {
+        BalanceConfig::load().unwrap_or_else(|e| {
+            eprintln!("{}. Using default balance.", e);
+            BalanceConfig::default()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_balance_keeps_thresholds_in_order() {
+        let balance = BalanceConfig::load().expect("balance.json should parse");
+        assert!(balance.fearful_stress < balance.paranoid_stress);
+        assert!(balance.paranoid_stress < balance.max_stress);
+        assert!(balance.max_stress < balance.heart_attack_stress);
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        let zero_energy = BalanceConfig {
+            base_energy: 0,
+            ..BalanceConfig::default()
+        };
+        assert!(zero_energy.validate().is_err());
+
+        let inverted = BalanceConfig {
+            paranoid_stress: 40,
+            ..BalanceConfig::default()
+        };
+        assert!(inverted.validate().is_err());
+
+        let certain_breakthrough = BalanceConfig {
+            resolve_breakthrough_chance: 1.5,
+            ..BalanceConfig::default()
+        };
+        assert!(certain_breakthrough.validate().is_err());
+        assert!(BalanceConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let balance: BalanceConfig = serde_json::from_str(r#"{ "max_stress": 120 }"#).unwrap();
+        assert_eq!(balance.max_stress, 120);
+        assert_eq!(
+            balance.fearful_stress,
+            BalanceConfig::default().fearful_stress
+        );
+    }
+}
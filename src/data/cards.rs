@@ -17,6 +17,9 @@ pub struct CardData {
     pub class: CardClass,
     #[serde(default)]
     pub required_knowledge: i32,
+    /// Labels other systems key off, e.g. "movement" cards that wounded legs make dearer
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl CardData {
@@ -41,6 +44,7 @@ impl CardData {
             image_path: self.image_path.clone(),
             class: self.class.clone(),
             required_knowledge: self.required_knowledge,
+            tags: self.tags.clone(),
         }
     }
 
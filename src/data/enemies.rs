@@ -1,7 +1,15 @@
 //! Enemy data loading from JSON
 
-use crate::combat::{EnemyAiPattern, Unit};
+use crate::combat::{
+    EnemyAction, EnemyAiPattern, IntentSpec, LootDrop, Reinforcement, TargetStrategy, Unit,
+};
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+static ENEMIES: OnceLock<Vec<EnemyData>> = OnceLock::new();
+
+/// Extra enemy max HP, in percent of its base, for each party member beyond the first
+const PARTY_HP_PERCENT_PER_MEMBER: i32 = 35;
 
 /// Enemy template from data file
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -16,6 +24,25 @@ pub struct EnemyData {
     pub ai_pattern: EnemyAiPattern,
     #[serde(default)]
     pub image_path: Option<String>,
+    #[serde(default)]
+    pub target_strategy: TargetStrategy,
+    #[serde(default)]
+    pub drops: Vec<LootDrop>,
+    /// Named special actions, e.g. a web that applies Weak
+    #[serde(default)]
+    pub actions: Vec<EnemyAction>,
+    /// Intent cycle by turn, e.g. `[{ "attack": 6 }, { "block": 5 }, "buff"]`; empty keeps
+    /// the AI pattern's cycle
+    #[serde(default)]
+    pub intent_pattern: Vec<IntentSpec>,
+    /// Enemy called into the fight, on `reinforce_turn` or once this one's HP falls to
+    /// `reinforce_hp_percent` of its max
+    #[serde(default)]
+    pub reinforce_id: Option<String>,
+    #[serde(default)]
+    pub reinforce_turn: Option<usize>,
+    #[serde(default)]
+    pub reinforce_hp_percent: Option<i32>,
 }
 
 impl EnemyData {
@@ -24,6 +51,14 @@ impl EnemyData {
         crate::load_asset!("enemies.json", Vec<EnemyData>)
     }
 
+    /// Copy with max HP raised for a party of `size`; solo parties face the base template
+    pub fn scaled_for_party(&self, size: usize) -> EnemyData {
+        let extra_members = size.saturating_sub(1) as i32;
+        let mut scaled = self.clone();
+        scaled.max_hp = self.max_hp * (100 + PARTY_HP_PERCENT_PER_MEMBER * extra_members) / 100;
+        scaled
+    }
+
     /// Convert to a combat Unit
     pub fn to_unit(&self) -> Unit {
         let mut unit = Unit::new_enemy_with_pattern(
@@ -33,50 +68,161 @@ impl EnemyData {
             self.image_path.clone(),
             self.ai_pattern.clone(),
         );
+        unit.target_strategy = self.target_strategy;
+        unit.drops = self.drops.clone();
+        unit.actions = self.actions.clone();
+        unit.intent_pattern = self.intent_pattern.clone();
+        unit.reinforcement = self.reinforce_id.clone().map(|enemy_id| Reinforcement {
+            enemy_id,
+            turn: self.reinforce_turn,
+            hp_percent: self.reinforce_hp_percent,
+            telegraphed: false,
+        });
         unit.roll_intent(1);
         unit
     }
 }
 
-/// Get a random enemy appropriate for the given difficulty
-#[allow(dead_code)]
-pub fn random_enemy_for_difficulty(difficulty: i32) -> Unit {
-    random_enemy_for_region_and_difficulty("", difficulty)
+/// Pick a random enemy template for region and difficulty, if any load.
+pub fn random_enemy_data(region_id: &str, difficulty: i32) -> Option<EnemyData> {
+    let enemies = EnemyData::load_all().ok()?;
+    let suitable = enemy_pool(&enemies, region_id, difficulty);
+    macroquad_toolkit::rng::choose(&suitable).map(|enemy| (*enemy).clone())
+}
+
+/// Ultimate fallback when no enemy data loads
+pub fn fallback_enemy() -> Unit {
+    Unit::new_enemy("Forest Beast", 30, None)
+}
+
+/// Roll which enemy a map node will hold, for region and difficulty.
+pub fn roll_enemy_id(enemies: &[EnemyData], region_id: &str, difficulty: i32) -> Option<String> {
+    let suitable = enemy_pool(enemies, region_id, difficulty);
+    macroquad_toolkit::rng::choose(&suitable).map(|enemy| enemy.id.clone())
+}
+
+/// Enemy templates, read from disk the first time they are needed
+fn all_enemies() -> &'static [EnemyData] {
+    ENEMIES.get_or_init(|| {
+        EnemyData::load_all().unwrap_or_else(|e| {
+            eprintln!("Failed to load enemies: {}", e);
+            vec![]
+        })
+    })
+}
+
+/// Look up an enemy template by id.
+pub fn enemy_by_id(id: &str) -> Option<EnemyData> {
+    all_enemies().iter().find(|enemy| enemy.id == id).cloned()
+}
+
+/// Rough danger label for an enemy's threat level.
+pub fn threat_label(threat_level: i32) -> &'static str {
+    match threat_level {
+        i32::MIN..=1 => "Low",
+        2 => "Moderate",
+        3 => "High",
+        _ => "Deadly",
+    }
+}
+
+/// Candidate enemies for a fight: the region's own pool first, then difficulty.
+///
+/// Regions with no enemies of their own borrow from every region. The fight draws from
+/// the pool's strongest threat tier that does not exceed the difficulty, so harder
+/// fights bring stronger enemies. If nothing in the pool is weak enough, the pool's
+/// lowest threat tier is used instead.
+pub fn enemy_pool<'a>(
+    enemies: &'a [EnemyData],
+    region_id: &str,
+    difficulty: i32,
+) -> Vec<&'a EnemyData> {
+    let regional: Vec<&EnemyData> = enemies
+        .iter()
+        .filter(|e| region_id.is_empty() || e.region == region_id)
+        .collect();
+    let pool = if regional.is_empty() {
+        enemies.iter().collect()
+    } else {
+        regional
+    };
+
+    let tier = pool
+        .iter()
+        .map(|e| e.threat_level)
+        .filter(|threat| *threat <= difficulty)
+        .max()
+        .or_else(|| pool.iter().map(|e| e.threat_level).min());
+    pool.into_iter()
+        .filter(|e| Some(e.threat_level) == tier)
+        .collect()
 }
 
-/// Get a random enemy appropriate for region and difficulty.
-pub fn random_enemy_for_region_and_difficulty(region_id: &str, difficulty: i32) -> Unit {
-    match EnemyData::load_all() {
-        Ok(enemies) => {
-            let mut suitable: Vec<_> = enemies
-                .iter()
-                .filter(|e| {
-                    e.threat_level <= difficulty && (region_id.is_empty() || e.region == region_id)
-                })
-                .collect();
-
-            if suitable.is_empty() && !region_id.is_empty() {
-                suitable = enemies
-                    .iter()
-                    .filter(|e| e.threat_level <= difficulty)
-                    .collect();
-            }
-
-            if suitable.is_empty() {
-                // Fallback to any enemy
-                if let Some(enemy) = enemies.first() {
-                    return enemy.to_unit();
-                }
-            } else {
-                // Pick a random one
-                if let Some(enemy) = macroquad_toolkit::rng::choose(&suitable) {
-                    return enemy.to_unit();
-                }
-            }
-
-            // Ultimate fallback
-            Unit::new_enemy("Forest Beast", 30, None)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sunken_valley_never_spawns_dark_woods_enemies() {
+        let enemies = EnemyData::load_all().expect("enemies.json should parse");
+        for difficulty in 0..=6 {
+            let pool = enemy_pool(&enemies, "sunken_valley", difficulty);
+            assert!(!pool.is_empty());
+            assert!(pool.iter().all(|e| e.region == "sunken_valley"));
         }
-        Err(_) => Unit::new_enemy("Forest Beast", 30, None),
+    }
+
+    #[test]
+    fn unknown_region_falls_back_to_all_regions() {
+        let enemies = EnemyData::load_all().expect("enemies.json should parse");
+        let pool = enemy_pool(&enemies, "uncharted_peaks", 1);
+        assert!(!pool.is_empty());
+        assert!(pool.iter().all(|e| e.threat_level <= 1));
+    }
+
+    #[test]
+    fn harder_fights_draw_from_the_matching_threat_tier() {
+        let enemies = EnemyData::load_all().expect("enemies.json should parse");
+        for difficulty in 1..=3 {
+            let pool = enemy_pool(&enemies, "dark_woods", difficulty);
+            assert!(!pool.is_empty());
+            assert!(pool.iter().all(|e| e.threat_level == difficulty));
+        }
+        let wolf = enemy_by_id("shadow_wolf").expect("shadow_wolf in enemies.json");
+        assert_eq!(wolf.to_unit().base_damage, wolf.base_damage);
+    }
+
+    #[test]
+    fn sunken_knight_telegraphs_its_wind_up_from_data() {
+        let knight = enemy_by_id("sunken_knight").expect("sunken_knight in enemies.json");
+        let mut unit = knight.to_unit();
+        assert_eq!(unit.intent, crate::combat::EnemyIntent::Attack(11));
+        unit.roll_intent(3);
+        assert_eq!(unit.intent, crate::combat::EnemyIntent::Attack(22));
+    }
+
+    #[test]
+    fn only_data_patterns_sweep_the_whole_party() {
+        let sergeant = enemy_by_id("returned_sergeant").expect("returned_sergeant in enemies.json");
+        let mut unit = sergeant.to_unit();
+        unit.roll_intent(3);
+        assert_eq!(unit.intent, crate::combat::EnemyIntent::AttackAll(7));
+
+        let mut plain_bruiser = sergeant;
+        plain_bruiser.intent_pattern.clear();
+        let mut unit = plain_bruiser.to_unit();
+        unit.roll_intent(3);
+        assert_eq!(unit.intent, crate::combat::EnemyIntent::Attack(9));
+    }
+
+    #[test]
+    fn full_party_faces_more_enemy_hp_than_solo() {
+        let enemies = EnemyData::load_all().expect("enemies.json should parse");
+        let enemy = enemies.first().expect("at least one enemy");
+        let solo = enemy.scaled_for_party(1).to_unit();
+        let party = enemy.scaled_for_party(4).to_unit();
+        assert_eq!(solo.max_hp, enemy.max_hp);
+        assert!(party.max_hp > solo.max_hp);
+        assert_eq!(party.hp, party.max_hp);
     }
 }
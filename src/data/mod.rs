@@ -9,10 +9,13 @@
 // Wait, I replaced `load_json` and `load_asset` with a macro. The macro does NOT use `DeserializeOwned` trait bound on a function. It just calls `serde_json::from_str::<$type>`.
 // So the import IS unused.
 
+mod balance;
 pub mod cards;
 pub mod enemies;
+pub mod names;
 
-pub use enemies::random_enemy_for_region_and_difficulty;
+pub use balance::balance;
+pub use enemies::{enemy_by_id, fallback_enemy, random_enemy_data, roll_enemy_id, threat_label};
 // CardData and EnemyData are used internally
 
 /// Macro to load JSON from assets with WASM support
@@ -0,0 +1,114 @@
+//! Name data loading from JSON - first names, surnames, and earned epithets
+
+use crate::kingdom::{Adventurer, Gender};
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+/// Used when the name pools are missing or empty
+const FALLBACK_NAME: &str = "Wanderer";
+
+/// Name pools from data file
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct NameData {
+    #[serde(default)]
+    pub male: Vec<String>,
+    #[serde(default)]
+    pub female: Vec<String>,
+    #[serde(default)]
+    pub surnames: Vec<String>,
+    /// Ordered from least to most renowned; the last one earned wins
+    #[serde(default)]
+    pub epithets: Vec<EpithetData>,
+}
+
+/// A title earned by reaching every listed milestone
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EpithetData {
+    pub title: String,
+    /// Only this class can earn it; any class if unset
+    #[serde(default)]
+    pub class: Option<String>,
+    #[serde(default)]
+    pub missions: u32,
+    #[serde(default)]
+    pub kills: u32,
+    #[serde(default)]
+    pub level: i32,
+}
+
+impl NameData {
+    /// Load the name pools from the names.json asset file
+    pub fn load() -> Result<NameData, String> {
+        crate::load_asset!("names.json", NameData)
+    }
+}
+
+impl EpithetData {
+    /// Whether the adventurer has reached every milestone this title asks for
+    pub fn earned_by(&self, adv: &Adventurer) -> bool {
+        self.class
+            .as_ref()
+            .is_none_or(|class| *class == format!("{:?}", adv.class))
+            && adv.missions_completed >= self.missions
+            && adv.kills >= self.kills
+            && adv.level >= self.level
+    }
+}
+
+/// A first name for the gender followed by a surname
+pub fn random_full_name(gender: &Gender) -> String {
+    let data = NameData::load().unwrap_or_default();
+    let pool = match gender {
+        Gender::Male => &data.male,
+        Gender::Female => &data.female,
+    };
+    let first = pick(pool).unwrap_or(FALLBACK_NAME);
+    join_surname(first, &data)
+}
+
+/// Give a fixed first name a random surname
+pub fn with_surname(first: &str) -> String {
+    join_surname(first, &NameData::load().unwrap_or_default())
+}
+
+/// The most renowned epithet the adventurer has earned, if any
+pub fn epithet_for(adv: &Adventurer) -> Option<String> {
+    NameData::load()
+        .ok()?
+        .epithets
+        .into_iter()
+        .rev()
+        .find(|epithet| epithet.earned_by(adv))
+        .map(|epithet| epithet.title)
+}
+
+fn join_surname(first: &str, data: &NameData) -> String {
+    match pick(&data.surnames) {
+        Some(surname) => format!("{} {}", first, surname),
+        None => first.to_string(),
+    }
+}
+
+fn pick(pool: &[String]) -> Option<&str> {
+    if pool.is_empty() {
+        return None;
+    }
+    Some(pool[rng::gen_range(0, pool.len())].as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_pools_and_epithet_classes_are_valid() {
+        let data = NameData::load().expect("names.json should parse");
+        assert!(!data.male.is_empty() && !data.female.is_empty() && !data.surnames.is_empty());
+        let classes = ["Soldier", "Scout", "Healer", "Mystic"];
+        assert!(data
+            .epithets
+            .iter()
+            .filter_map(|epithet| epithet.class.as_deref())
+            .all(|class| classes.contains(&class)));
+    }
+}
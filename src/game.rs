@@ -2,18 +2,40 @@
 //!
 //! Only one GameState is active at a time. Transitions are explicit.
 
-use crate::kingdom::{KingdomState, Roster};
-use crate::save::{ensure_save_directory, SaveData};
+use crate::kingdom::{KingdomState, Roster, TutorialStep};
+use crate::save::{
+    ensure_save_directory, DailyScore, Leaderboard, RunHistory, RunRecord, SaveData, Settings,
+};
 use crate::state::*;
 use macroquad::prelude::*;
-use macroquad_toolkit::assets::{load_texture_from_pack_or_file, AssetPack};
-use macroquad_toolkit::ui::draw_ui_text;
-use std::collections::HashMap;
+use macroquad_toolkit::assets::AssetPack;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+use std::collections::{HashMap, VecDeque};
+
+mod daily;
+mod help;
+mod loading;
+
+use loading::LoadingState;
 
 const ASSET_PACK_PATH: &str = "assets.zip";
+/// Seconds each queued notification toast stays on screen
+const NOTIFICATION_SECONDS: f32 = 2.5;
+const TUTORIAL_W: f32 = 760.0;
+const TUTORIAL_H: f32 = 58.0;
+
+/// A new kingdom on the preferred difficulty with the starting roster
+fn fresh_run(settings: &Settings) -> (KingdomState, Roster) {
+    (
+        KingdomState::for_difficulty(settings.difficulty),
+        Roster::starter(),
+    )
+}
 
 /// Top-level game state enum - explicit state machine
 pub enum GameState {
+    /// Streaming textures in at startup
+    Loading(LoadingState),
     /// Kingdom base management
     Base(BaseState),
     /// Selecting a mission to embark on
@@ -21,13 +43,27 @@ pub enum GameState {
     /// Active mission/expedition
     Mission(MissionState),
     /// Turn-based card combat
-    Combat(CombatState),
+    Combat(Box<CombatState>),
     /// Post-mission results and consequences
     Results(ResultState),
     /// Narrative event with choices
     Event(EventState),
+    /// Daily kingdom decision between expeditions
+    KingdomEvent(KingdomEventState),
+    /// Continue, daily challenge, history, and settings
+    MainMenu(MainMenuState),
     /// Recruit new adventurers
     Recruit(RecruitState),
+    /// Upgrade learned cards with supplies
+    Foundry(FoundryState),
+    /// Every adventurer has fallen
+    GameOver(GameOverState),
+    /// Past runs, sorted by a chosen measure
+    History(HistoryState),
+    /// The fallen and what they achieved
+    Memorial(MemorialState),
+    /// Saved preferences
+    Settings(SettingsState),
 }
 
 impl Default for GameState {
@@ -42,119 +78,83 @@ pub struct Game {
     pub kingdom: KingdomState,
     pub roster: Roster,
     pub message: Option<(String, f32)>, // (message, time remaining)
+    /// Toasts raised by states, shown one at a time through `message`
+    pub notifications: VecDeque<String>,
     pub textures: HashMap<String, Texture2D>,
+    /// Contextual help overlay, toggled with F1; pauses the active state while open
+    pub help_open: bool,
+    /// Screen from the save's in-progress snapshot, opened once loading finishes
+    resume: Option<InProgress>,
+    /// Write the autosave slot whenever a mission's results are dismissed
+    pub autosave_enabled: bool,
 }
 
 impl Game {
     pub async fn new() -> Self {
         // Try to load existing save
-        let (mut kingdom, roster) = if SaveData::exists(&SaveData::default_path()) {
-            match SaveData::load(&SaveData::default_path()) {
+        let settings = Settings::load();
+        let mut resume = None;
+        let run = if SaveData::exists(&SaveData::default_path()) {
+            match SaveData::load_latest(&SaveData::default_path()) {
                 Ok(save) => {
                     eprintln!("Loaded save file");
+                    resume = save.in_progress;
                     (save.kingdom, save.roster)
                 }
                 Err(e) => {
                     eprintln!("Failed to load save: {}", e);
-                    (KingdomState::default(), Roster::starter())
+                    fresh_run(&settings)
                 }
             }
         } else {
-            (KingdomState::default(), Roster::starter())
+            fresh_run(&settings)
         };
-        kingdom.ensure_current_buildings();
-
-        let asset_pack = AssetPack::load(ASSET_PACK_PATH).await.ok();
-
-        // Load textures
-        let mut textures = HashMap::new();
-
-        // Helper to load texture if file exists
-        async fn load_tex(asset_pack: Option<&AssetPack>, path: &str) -> Option<Texture2D> {
-            load_texture_from_pack_or_file(asset_pack, path, FilterMode::Linear)
-                .await
-                .ok()
-        }
-
-        // Helper to parse textures from JSON string
-        async fn parse_textures_from_json(
-            json_str: &str,
-            field_name: &str,
-            textures: &mut HashMap<String, Texture2D>,
-            asset_pack: Option<&AssetPack>,
-        ) {
-            if let Ok(items) = serde_json::from_str::<Vec<serde_json::Value>>(json_str) {
-                for item in items {
-                    if let Some(path) = item.get(field_name).and_then(|v| v.as_str()) {
-                        if let Some(tex) = load_tex(asset_pack, path).await {
-                            textures.insert(path.to_string(), tex);
-                        }
-                    }
-                }
-            }
-        }
-
-        macro_rules! load_textures {
-            ($path:literal, $field:expr, $textures:expr) => {{
-                #[cfg(target_arch = "wasm32")]
-                let content = include_str!(concat!("../", $path));
-
-                #[cfg(not(target_arch = "wasm32"))]
-                let content = match std::fs::read_to_string($path) {
-                    Ok(c) => c,
-                    Err(_) => include_str!(concat!("../", $path)).to_string(),
-                };
-
-                parse_textures_from_json(&content, $field, $textures, asset_pack.as_ref()).await;
-            }};
-        }
+        let mut game = Self::with_run(run, &settings).await;
+        game.resume = resume;
+        game
+    }
 
-        // Load all textures from JSON data files
-        load_textures!("assets/cards.json", "image_path", &mut textures);
-        load_textures!("assets/enemies.json", "image_path", &mut textures);
-
-        // Character images (adventurers are generated, not from JSON)
-        let char_images = [
-            "soldier_male",
-            "soldier_female",
-            "scout_male",
-            "scout_female",
-            "healer_male",
-            "healer_female",
-            "mystic_male",
-            "mystic_female",
-        ];
-        for name in char_images {
-            let path = format!("assets/images/characters/{}.png", name);
-            if let Some(tex) = load_tex(asset_pack.as_ref(), &path).await {
-                textures.insert(path, tex);
-            }
-        }
+    /// Start today's daily challenge instead of the saved campaign
+    pub async fn new_daily() -> Self {
+        let settings = Settings::load();
+        Self::with_run(daily::daily_run(daily::today()), &settings).await
+    }
 
-        // Region images
-        let region_images = ["dark_woods", "ruined_outpost", "sunken_valley"];
-        for name in region_images {
-            let path = format!("assets/images/regions/{}.png", name);
-            if let Some(tex) = load_tex(asset_pack.as_ref(), &path).await {
-                textures.insert(path, tex);
-            }
-        }
+    async fn with_run((mut kingdom, roster): (KingdomState, Roster), settings: &Settings) -> Self {
+        kingdom.ensure_current_buildings();
+        apply_settings(settings);
+        // Read balance.json now rather than mid-fight
+        crate::data::balance();
 
-        // UI backdrops
-        let ui_images = ["command_table"];
-        for name in ui_images {
-            let path = format!("assets/images/ui/{}.png", name);
-            if let Some(tex) = load_tex(asset_pack.as_ref(), &path).await {
-                textures.insert(path, tex);
-            }
-        }
+        // Textures stream in across frames behind the loading screen
+        let asset_pack = AssetPack::load(ASSET_PACK_PATH).await.ok();
 
         Self {
-            state: GameState::default(),
+            state: GameState::Loading(LoadingState::new(asset_pack)),
             kingdom,
             roster,
             message: None,
-            textures,
+            notifications: VecDeque::new(),
+            textures: HashMap::new(),
+            help_open: false,
+            resume: None,
+            autosave_enabled: true,
+        }
+    }
+
+    /// Load the next batch of startup textures, opening the main menu once they are all
+    /// in; a daily run launched with `--daily` goes straight to its base
+    pub async fn update_loading(&mut self) {
+        let GameState::Loading(loading) = &mut self.state else {
+            return;
+        };
+        loading.load_batch(&mut self.textures).await;
+        if loading.is_done() {
+            self.state = if self.kingdom.daily_challenge.is_some() {
+                GameState::default()
+            } else {
+                GameState::MainMenu(MainMenuState::default())
+            };
         }
     }
 
@@ -168,8 +168,34 @@ impl Game {
             }
         }
 
-        // Handle save/load only in base state
-        if matches!(self.state, GameState::Base(_)) {
+        if matches!(self.state, GameState::Loading(_)) {
+            return;
+        }
+
+        if is_key_pressed(KeyCode::F1) {
+            self.help_open = !self.help_open;
+        } else if self.help_open && is_key_pressed(KeyCode::Escape) {
+            self.help_open = false;
+        }
+        if self.help_open {
+            return;
+        }
+        if is_key_pressed(KeyCode::F2) {
+            self.toggle_colorblind_mode();
+        }
+        if is_key_pressed(KeyCode::F3) {
+            self.cycle_new_game_difficulty();
+        }
+
+        // Save and load anywhere a run can be picked up again
+        if matches!(
+            self.state,
+            GameState::Base(_)
+                | GameState::MissionSelect(_)
+                | GameState::Mission(_)
+                | GameState::Event(_)
+                | GameState::Combat(_)
+        ) {
             if is_key_pressed(KeyCode::F5) {
                 self.save_game();
             }
@@ -178,41 +204,115 @@ impl Game {
             }
         }
 
+        let transition = match &mut self.state {
+            GameState::Loading(_) => None,
+            GameState::Base(state) => state.update(&mut self.kingdom, &mut self.roster),
+            GameState::MissionSelect(state) => state.update(&self.roster, &self.kingdom),
+            GameState::Mission(state) => state.update(&mut self.kingdom),
+            GameState::Combat(state) => state.update(),
+            GameState::Results(state) => state.update(&mut self.kingdom, &mut self.roster),
+            GameState::Event(state) => state.update(),
+            GameState::KingdomEvent(state) => state.update(&mut self.kingdom, &mut self.roster),
+            GameState::MainMenu(state) => state.update(),
+            GameState::Recruit(state) => state.update(&mut self.kingdom, &mut self.roster),
+            GameState::Foundry(state) => state.update(&mut self.kingdom, &mut self.roster),
+            GameState::GameOver(state) => state.update(),
+            GameState::History(state) => state.update(),
+            GameState::Memorial(state) => state.update(&self.roster),
+            GameState::Settings(state) => state.update(),
+        };
+
+        // Collect toasts before a transition can drop the state that raised them
+        self.collect_notifications();
+        if let Some(transition) = transition {
+            self.transition(transition);
+            self.check_game_over();
+        }
+        self.update_tutorial();
+    }
+
+    /// Flip colorblind mode and remember it for next time
+    fn toggle_colorblind_mode(&mut self) {
+        let mut settings = Settings::load();
+        settings.colorblind = !crate::ui::colorblind_mode();
+        crate::ui::set_colorblind_mode(settings.colorblind);
+        let label = if settings.colorblind { "on" } else { "off" };
+        let message = match settings.save() {
+            Ok(()) => format!("Colorblind mode {}", label),
+            Err(e) => format!("Colorblind mode {} (not saved: {})", label, e),
+        };
+        self.message = Some((message, 2.0));
+    }
+
+    /// Pick the preset the next kingdom is founded on; the current run keeps its own
+    fn cycle_new_game_difficulty(&mut self) {
+        let mut settings = Settings::load();
+        settings.difficulty = settings.difficulty.next();
+        let label = settings.difficulty.label();
+        let message = match settings.save() {
+            Ok(()) => format!("New kingdoms start on {}", label),
+            Err(e) => format!("New kingdoms start on {} (not saved: {})", label, e),
+        };
+        self.message = Some((message, 2.0));
+    }
+
+    /// Advance the first-run hints as the player reaches each screen; F10 or Skip dismisses them
+    fn update_tutorial(&mut self) {
+        let step = self.kingdom.tutorial_step;
+        if step.is_done() {
+            return;
+        }
+
+        let (skip_x, skip_y, skip_w, skip_h) = tutorial_skip_rect();
+        if is_key_pressed(KeyCode::F10) || crate::ui::was_clicked(skip_x, skip_y, skip_w, skip_h) {
+            self.kingdom.tutorial_step = TutorialStep::Done;
+            return;
+        }
+
+        let reached = match &self.state {
+            GameState::Base(_) if step == TutorialStep::Debrief => Some(TutorialStep::Done),
+            GameState::Base(state) if state.is_forming_party() => Some(TutorialStep::FormParty),
+            GameState::MissionSelect(_) => Some(TutorialStep::ChooseMission),
+            GameState::Mission(_) => Some(TutorialStep::Travel),
+            GameState::Combat(_) => Some(TutorialStep::Fight),
+            GameState::Results(_) => Some(TutorialStep::Debrief),
+            _ => None,
+        };
+        if let Some(reached) = reached.filter(|reached| *reached > step) {
+            self.kingdom.tutorial_step = reached;
+        }
+    }
+
+    /// Whether the current tutorial hint belongs to the screen being shown
+    fn tutorial_on_screen(&self, step: TutorialStep) -> bool {
+        match (step, &self.state) {
+            (TutorialStep::SelectAdventurer, GameState::Base(state)) => !state.is_forming_party(),
+            (TutorialStep::FormParty, GameState::Base(state)) => state.is_forming_party(),
+            (TutorialStep::ChooseMission, GameState::MissionSelect(_))
+            | (TutorialStep::Travel, GameState::Mission(_))
+            | (TutorialStep::Fight, GameState::Combat(_))
+            | (TutorialStep::Debrief, GameState::Results(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Drain state notifications into the toast queue and show the next one when idle
+    fn collect_notifications(&mut self) {
         match &mut self.state {
-            GameState::Base(state) => {
-                if let Some(transition) = state.update(&mut self.kingdom, &mut self.roster) {
-                    self.transition(transition);
-                }
-            }
+            GameState::Combat(state) => self.notifications.extend(state.notifications.drain(..)),
+            GameState::Results(state) => self.notifications.extend(state.notifications.drain(..)),
+            GameState::Recruit(state) => self.notifications.extend(state.notifications.drain(..)),
+            GameState::Foundry(state) => self.notifications.extend(state.notifications.drain(..)),
+            GameState::Settings(state) => self.notifications.extend(state.notifications.drain(..)),
             GameState::MissionSelect(state) => {
-                if let Some(transition) = state.update(&self.roster, &self.kingdom) {
-                    self.transition(transition);
-                }
-            }
-            GameState::Mission(state) => {
-                if let Some(transition) = state.update() {
-                    self.transition(transition);
-                }
-            }
-            GameState::Combat(state) => {
-                if let Some(transition) = state.update() {
-                    self.transition(transition);
-                }
-            }
-            GameState::Results(state) => {
-                if let Some(transition) = state.update(&mut self.kingdom, &mut self.roster) {
-                    self.transition(transition);
-                }
-            }
-            GameState::Event(state) => {
-                if let Some(transition) = state.update() {
-                    self.transition(transition);
-                }
+                self.notifications.extend(state.notifications.drain(..))
             }
-            GameState::Recruit(state) => {
-                if let Some(transition) = state.update(&mut self.kingdom, &mut self.roster) {
-                    self.transition(transition);
-                }
+            _ => {}
+        }
+
+        if self.message.is_none() {
+            if let Some(next) = self.notifications.pop_front() {
+                self.message = Some((next, NOTIFICATION_SECONDS));
             }
         }
     }
@@ -220,44 +320,212 @@ impl Game {
     /// Draw current state
     pub fn draw(&self) {
         match &self.state {
+            GameState::Loading(state) => state.draw(),
             GameState::Base(state) => state.draw(&self.kingdom, &self.roster, &self.textures),
             GameState::MissionSelect(state) => state.draw(&self.kingdom, &self.textures),
-            GameState::Mission(state) => state.draw(&self.textures),
+            GameState::Mission(state) => state.draw(&self.kingdom, &self.textures),
             GameState::Combat(state) => state.draw(&self.textures),
             GameState::Results(state) => state.draw(&self.textures),
             GameState::Event(state) => state.draw(&self.textures),
-            GameState::Recruit(state) => state.draw(&self.kingdom, &self.textures),
+            GameState::KingdomEvent(state) => state.draw(&self.textures),
+            GameState::MainMenu(state) => state.draw(),
+            GameState::Recruit(state) => state.draw(&self.kingdom, &self.roster, &self.textures),
+            GameState::Foundry(state) => state.draw(&self.kingdom, &self.roster),
+            GameState::GameOver(state) => state.draw(),
+            GameState::History(state) => state.draw(),
+            GameState::Memorial(state) => state.draw(&self.roster),
+            GameState::Settings(state) => state.draw(),
+        }
+
+        self.draw_tutorial();
+        if self.help_open {
+            help::draw_help(&self.state);
         }
 
         // Draw message if any
         if let Some((msg, _)) = &self.message {
-            let x = screen_width() / 2.0 - 100.0;
-            draw_rectangle(x - 10.0, 10.0, 220.0, 35.0, Color::from_rgba(0, 0, 0, 200));
+            let width = measure_ui_text(msg, None, 24, 1.0).width.max(200.0);
+            let x = (screen_width() - width) / 2.0;
+            draw_rectangle(
+                x - 10.0,
+                10.0,
+                width + 20.0,
+                35.0,
+                Color::from_rgba(0, 0, 0, 200),
+            );
             draw_ui_text(msg, x, 35.0, 24.0, YELLOW);
         }
     }
 
+    /// First-run hint banner along the bottom of the screen
+    fn draw_tutorial(&self) {
+        let step = self.kingdom.tutorial_step;
+        if step.is_done() || !self.tutorial_on_screen(step) {
+            return;
+        }
+
+        let x = (screen_width() - TUTORIAL_W) / 2.0;
+        let y = screen_height() - TUTORIAL_H - 12.0;
+        draw_rectangle(
+            x,
+            y,
+            TUTORIAL_W,
+            TUTORIAL_H,
+            Color::from_rgba(12, 16, 20, 235),
+        );
+        draw_rectangle_lines(x, y, TUTORIAL_W, TUTORIAL_H, 2.0, GOLD);
+        draw_ui_text("TUTORIAL", x + 14.0, y + 22.0, 16.0, GOLD);
+        draw_ui_text(step.hint(), x + 14.0, y + 44.0, 17.0, WHITE);
+
+        let (skip_x, skip_y, skip_w, skip_h) = tutorial_skip_rect();
+        let hovered = crate::ui::is_mouse_over(skip_x, skip_y, skip_w, skip_h);
+        draw_rectangle_lines(
+            skip_x,
+            skip_y,
+            skip_w,
+            skip_h,
+            1.0,
+            if hovered { WHITE } else { GRAY },
+        );
+        draw_ui_text("[F10] Skip", skip_x + 10.0, skip_y + 17.0, 14.0, LIGHTGRAY);
+    }
+
     /// Handle explicit state transitions
     fn transition(&mut self, transition: StateTransition) {
+        if let GameState::Combat(combat) = &self.state {
+            let path = crate::combat::COMBAT_LOG_DUMP_PATH;
+            match combat.resolver.log.dump_full_history(path) {
+                Ok(true) => self.message = Some((format!("Combat log written to {}", path), 2.0)),
+                Ok(false) => {}
+                Err(e) => self.message = Some((e, 3.0)),
+            }
+        }
+        let leaving_results = matches!(self.state, GameState::Results(_));
+        // Continue opens the screen the save was made on, or the base
+        let transition = match transition {
+            StateTransition::ToContinue => self
+                .resume
+                .take()
+                .map_or(StateTransition::ToBase, InProgress::resume),
+            other => other,
+        };
         self.state = match transition {
-            StateTransition::ToBase => GameState::Base(BaseState::default()),
+            StateTransition::ToBase | StateTransition::ToContinue => {
+                GameState::Base(BaseState::default())
+            }
             StateTransition::ToMissionSelect(select) => GameState::MissionSelect(select),
             StateTransition::ToMission(mission) => GameState::Mission(mission),
             StateTransition::ToCombat(combat) => GameState::Combat(combat),
             StateTransition::ToResults(results) => GameState::Results(results),
             StateTransition::ToEvent(event) => GameState::Event(event),
-            StateTransition::ToRecruit => GameState::Recruit(RecruitState::new()),
+            StateTransition::ToKingdomEvent(event) => GameState::KingdomEvent(event),
+            StateTransition::ToRecruit => GameState::Recruit(RecruitState::generate(&self.kingdom)),
+            StateTransition::ToFoundry => GameState::Foundry(FoundryState::new()),
+            StateTransition::ToNewGame => {
+                let (mut kingdom, roster) = fresh_run(&Settings::load());
+                kingdom.ensure_current_buildings();
+                self.kingdom = kingdom;
+                self.roster = roster;
+                self.save_game();
+                GameState::default()
+            }
+            StateTransition::ToDailyChallenge => {
+                let (mut kingdom, roster) = daily::daily_run(daily::today());
+                kingdom.ensure_current_buildings();
+                self.kingdom = kingdom;
+                self.roster = roster;
+                self.message = Some(("Daily challenge begins".to_string(), 2.0));
+                GameState::default()
+            }
+            StateTransition::ToHistory => GameState::History(HistoryState::load()),
+            StateTransition::ToMemorial => GameState::Memorial(MemorialState::default()),
+            StateTransition::ToSettings => GameState::Settings(SettingsState::load()),
         };
+        if leaving_results {
+            // Mark a run that just ended as recorded before the autosave captures it
+            self.check_game_over();
+            if self.autosave_enabled {
+                self.autosave();
+            }
+        }
+    }
+
+    /// Once the expedition is over and nobody is left at the base, the run has ended
+    fn check_game_over(&mut self) {
+        if self.roster.count() == 0 && matches!(self.state, GameState::Base(_)) {
+            let mut game_over = GameOverState::new(&self.kingdom, &self.roster);
+            let seed = self.kingdom.seed;
+            if let Some(record) = game_over.take_run_record(&mut self.kingdom, seed) {
+                self.record_run(record);
+            }
+            // Left in place: it is what keeps a finished daily run from saving over the campaign
+            if let Some(challenge_day) = self.kingdom.daily_challenge {
+                game_over.daily_best = self.record_daily_score(challenge_day, &game_over);
+            }
+            self.state = GameState::GameOver(game_over);
+        }
+    }
+
+    /// Append the finished run to the run history
+    fn record_run(&mut self, record: RunRecord) {
+        if let Err(e) = RunHistory::append(record) {
+            self.message = Some((format!("Run history not updated: {}", e), 3.0));
+        }
+    }
+
+    /// Add the finished daily run to the local leaderboard and describe the day's best
+    fn record_daily_score(&mut self, challenge_day: u64, run: &GameOverState) -> Option<String> {
+        let mut leaderboard = Leaderboard::load();
+        leaderboard.record(DailyScore {
+            challenge_day,
+            days_survived: run.days_survived,
+            missions_won: run.missions_won,
+        });
+        if let Err(e) = leaderboard.save() {
+            self.message = Some((format!("Score not recorded: {}", e), 3.0));
+        }
+        leaderboard.best_for(challenge_day).map(|best| {
+            format!(
+                "Daily challenge best: {} days, {} expeditions won",
+                best.days_survived, best.missions_won
+            )
+        })
     }
 
     fn save_game(&mut self) {
+        // A daily run must not overwrite the campaign save
+        if self.kingdom.daily_challenge.is_some() {
+            self.message = Some(("Daily challenges are not saved".to_string(), 2.0));
+            return;
+        }
         if let Err(e) = ensure_save_directory() {
             self.message = Some((format!("Save failed: {}", e), 3.0));
             return;
         }
 
-        let save = SaveData::new(self.kingdom.clone(), self.roster.clone());
+        let in_progress = match &self.state {
+            GameState::Mission(state) => Some(InProgress::Mission(state.snapshot())),
+            GameState::Event(state) => Some(InProgress::Event(state.snapshot())),
+            GameState::Combat(state) => match state.snapshot() {
+                Some(snapshot) => Some(InProgress::Combat(Box::new(snapshot))),
+                None => {
+                    self.message = Some(("Wait for your turn to save".to_string(), 2.0));
+                    return;
+                }
+            },
+            _ => None,
+        };
+        let experimental = in_progress.is_some();
+
+        let mut save = SaveData::new(self.kingdom.clone(), self.roster.clone());
+        save.in_progress = in_progress;
         match save.save(&SaveData::default_path()) {
+            Ok(()) if experimental => {
+                self.message = Some((
+                    "Game Saved! (in-mission saves are experimental)".to_string(),
+                    3.0,
+                ));
+            }
             Ok(()) => {
                 self.message = Some(("Game Saved!".to_string(), 2.0));
             }
@@ -267,12 +535,29 @@ impl Game {
         }
     }
 
+    /// Quietly keep the run safe after each mission; daily challenges are never saved
+    fn autosave(&mut self) {
+        if self.kingdom.daily_challenge.is_some() {
+            return;
+        }
+        let save = SaveData::new(self.kingdom.clone(), self.roster.clone());
+        if let Err(e) = save.autosave() {
+            self.message = Some((format!("Autosave failed: {}", e), 3.0));
+        }
+    }
+
     fn load_game(&mut self) {
         match SaveData::load(&SaveData::default_path()) {
             Ok(save) => {
                 self.kingdom = save.kingdom;
                 self.roster = save.roster;
+                let transition = match save.in_progress {
+                    Some(in_progress) => in_progress.resume(),
+                    None => StateTransition::ToBase,
+                };
+                self.transition(transition);
                 self.message = Some(("Game Loaded!".to_string(), 2.0));
+                self.check_game_over();
             }
             Err(e) => {
                 self.message = Some((format!("Load failed: {}", e), 3.0));
@@ -280,3 +565,10 @@ impl Game {
         }
     }
 }
+
+/// Skip button in the top-right corner of the tutorial banner
+fn tutorial_skip_rect() -> (f32, f32, f32, f32) {
+    let x = (screen_width() + TUTORIAL_W) / 2.0 - 98.0;
+    let y = screen_height() - TUTORIAL_H - 6.0;
+    (x, y, 88.0, 24.0)
+}
@@ -0,0 +1,42 @@
+//! Daily challenge - the date seeds the run so everyone plays the same kingdom that day
+
+use crate::kingdom::{mix_seed, seed_rolls, Difficulty, KingdomState, Roster};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Days since the Unix epoch, in UTC so every player shares the same challenge
+pub fn today() -> u64 {
+    (macroquad::miniquad::date::now() / SECONDS_PER_DAY) as u64
+}
+
+/// Spread consecutive days into unrelated seeds
+pub fn daily_seed(challenge_day: u64) -> u64 {
+    mix_seed(challenge_day)
+}
+
+/// Found the day's seeded kingdom; difficulty is fixed so scores compare
+pub fn daily_run(challenge_day: u64) -> (KingdomState, Roster) {
+    let mut kingdom = KingdomState::for_difficulty(Difficulty::Normal);
+    kingdom.daily_challenge = Some(challenge_day);
+    kingdom.seed = Some(daily_seed(challenge_day));
+    seed_rolls(&kingdom, "roster");
+    (kingdom, Roster::starter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_day_gets_its_own_stable_seed() {
+        assert_eq!(daily_seed(20_000), daily_seed(20_000));
+        assert_ne!(daily_seed(20_000), daily_seed(20_001));
+    }
+
+    #[test]
+    fn the_daily_kingdom_carries_its_seed() {
+        let (kingdom, _) = daily_run(20_000);
+        assert_eq!(kingdom.seed, Some(daily_seed(20_000)));
+        assert_eq!(kingdom.daily_challenge, Some(20_000));
+    }
+}
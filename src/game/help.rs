@@ -0,0 +1,183 @@
+//! Contextual help overlay - controls and legends for whichever state is active
+
+use super::GameState;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const HELP_W: f32 = 720.0;
+const ROW_H: f32 = 22.0;
+/// Width of the key column before descriptions start
+const KEY_COL_W: f32 = 190.0;
+
+/// Heading for the help panel in the current state
+pub fn help_title_for(state: &GameState) -> &'static str {
+    match state {
+        GameState::Loading(_) => "LOADING",
+        GameState::Base(base) if base.is_forming_party() => "PARTY FORMATION",
+        GameState::Base(_) => "KINGDOM BASE",
+        GameState::MissionSelect(_) => "MISSION BOARD",
+        GameState::Mission(_) => "EXPEDITION MAP",
+        GameState::Combat(_) => "COMBAT",
+        GameState::Results(_) => "RESULTS",
+        GameState::Event(_) | GameState::KingdomEvent(_) => "EVENT",
+        GameState::MainMenu(_) => "MAIN MENU",
+        GameState::Recruit(_) => "RECRUITMENT",
+        GameState::Foundry(_) => "FOUNDRY",
+        GameState::GameOver(_) => "GAME OVER",
+        GameState::History(_) => "RUN HISTORY",
+        GameState::Memorial(_) => "MEMORIAL",
+        GameState::Settings(_) => "SETTINGS",
+    }
+}
+
+/// Key and legend rows relevant to the current state
+pub fn help_text_for(state: &GameState) -> Vec<(String, String)> {
+    let rows: Vec<(&str, &str)> = match state {
+        GameState::Loading(_) => vec![],
+        GameState::Base(base) if base.is_forming_party() => vec![
+            ("1-9", "Add or remove an adventurer (the leader stays)"),
+            ("Shift + 1-9", "Move a follower forward in formation"),
+            ("Enter", "Take the party to the mission board"),
+            ("Esc", "Disband the party and return to the roster"),
+        ],
+        GameState::Base(_) => vec![
+            ("1-9", "Select an adventurer, or a Buildings tab facility"),
+            ("Tab", "Cycle through the tabs"),
+            ("M", "Form a party led by the selected adventurer"),
+            ("D", "View the selected adventurer's deck"),
+            ("H / T", "Treat the selected adventurer"),
+            ("U", "Train a card for the selected adventurer"),
+            ("F", "Forge learned cards (needs a Foundry)"),
+            ("S", "Hold a Chapel sermon"),
+            ("X", "Dismiss the selected adventurer"),
+            ("R", "Recruit (needs a Guild Hall)"),
+            ("L", "List past runs"),
+            ("G", "Visit the memorial to the fallen"),
+            ("O", "Open the settings"),
+            ("Enter", "Construct the selected building"),
+            ("F5 / F9", "Save / load"),
+            ("F6 / F7", "Export / import a run"),
+        ],
+        GameState::MissionSelect(_) => vec![
+            ("Up / Down", "Select a mission"),
+            ("Enter", "Open the briefing; Enter again embarks"),
+            ("Esc", "Back to the base"),
+            ("Manageable", "The party comfortably outmatches the mission"),
+            ("Risky", "An even fight; expect wounds"),
+            ("Deadly", "The party is badly outmatched"),
+        ],
+        GameState::Mission(_) => vec![
+            ("Left / Right, 1-3", "Choose the next path"),
+            ("Space / Enter", "Travel along the chosen path"),
+            ("Esc", "Retreat; the region's threat rises"),
+            ("Wheel / Middle-drag", "Zoom / pan the map; Home resets it"),
+            ("X", "Combat"),
+            ("E", "Elite: a hardened foe with better spoils"),
+            ("?", "Event: anything could be waiting"),
+            ("+", "Rest: recover HP and shed stress"),
+            ("!", "Boss: the threat behind the expedition"),
+        ],
+        GameState::Combat(combat) => {
+            let mut rows = vec![
+                ("1-5", "Select a card; click it again to play"),
+                ("Enter", "Play the selected card"),
+                ("E", "End this member's turn; the last one asks to confirm"),
+                ("Esc", "Cancel ending the turn"),
+                ("LETHAL", "The enemy's intent would kill its target"),
+                ("Block", "Absorbs damage until the enemy acts"),
+                ("Vulnerable", "Takes 50% more damage"),
+                ("Weak", "Deals 25% less damage"),
+                ("Energy", "Spent to play cards; refreshes each turn"),
+            ];
+            if combat.simultaneous_turns {
+                rows.insert(3, ("P", "End the whole party's turn"));
+            }
+            rows
+        }
+        GameState::Results(_) => vec![("Enter", "Return to the kingdom")],
+        GameState::Event(_) | GameState::KingdomEvent(_) => vec![
+            ("Up / Down, 1-5", "Select a choice"),
+            ("Enter", "Confirm; clicking a selected choice also confirms"),
+        ],
+        GameState::MainMenu(_) => vec![
+            ("Up / Down", "Select an option"),
+            ("Enter", "Open it"),
+            ("C", "Continue the saved campaign"),
+            ("D", "Play today's daily challenge"),
+            ("L", "List past runs"),
+            ("O", "Open the settings"),
+        ],
+        GameState::Recruit(_) => vec![
+            ("Up / Down", "Select a recruit"),
+            ("Enter", "Hire for their listed cost"),
+            ("R", "Reserve or release an applicant for a later visit"),
+            ("Esc", "Back to the base"),
+        ],
+        GameState::Foundry(_) => vec![
+            ("Up / Down", "Select an adventurer"),
+            ("Left / Right", "Select one of their learned cards"),
+            ("Enter", "Forge the card for supplies"),
+            ("Esc", "Back to the base"),
+        ],
+        GameState::GameOver(_) => vec![
+            ("Enter", "Found a new kingdom"),
+            ("D", "Play today's daily challenge"),
+            ("L", "List past runs"),
+        ],
+        GameState::History(_) => vec![
+            ("Tab", "Change what the runs are sorted by"),
+            ("Esc", "Back"),
+        ],
+        GameState::Memorial(_) => vec![
+            ("Up / Down", "Scroll through the fallen"),
+            ("Esc", "Back to the base"),
+        ],
+        GameState::Settings(_) => vec![
+            ("Up / Down", "Select a setting"),
+            ("Enter / Space", "Change it; changes are saved at once"),
+            ("Esc", "Back to the base"),
+        ],
+    };
+    // The base lists its own save row; mid-mission saves carry a warning
+    let save_row = match state {
+        GameState::MissionSelect(_) => Some(("F5 / F9", "Save / load")),
+        GameState::Mission(_) | GameState::Event(_) | GameState::Combat(_) => {
+            Some(("F5 / F9", "Save / load (experimental mid-mission)"))
+        }
+        _ => None,
+    };
+    rows.into_iter()
+        .chain(save_row)
+        .chain([
+            ("F2", "Toggle colorblind mode"),
+            ("F3", "Cycle the difficulty new kingdoms start on"),
+            ("F1", "Close this help"),
+        ])
+        .map(|(key, text)| (key.to_string(), text.to_string()))
+        .collect()
+}
+
+/// Dim the screen and list the current state's help rows
+pub fn draw_help(state: &GameState) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 170),
+    );
+
+    let rows = help_text_for(state);
+    let h = 70.0 + rows.len() as f32 * ROW_H;
+    let x = (screen_width() - HELP_W) / 2.0;
+    let y = ((screen_height() - h) / 2.0).max(12.0);
+    draw_rectangle(x, y, HELP_W, h, Color::from_rgba(18, 18, 24, 245));
+    draw_rectangle_lines(x, y, HELP_W, h, 2.0, GOLD);
+    draw_ui_text(help_title_for(state), x + 20.0, y + 32.0, 22.0, GOLD);
+
+    for (i, (key, text)) in rows.iter().enumerate() {
+        let row_y = y + 62.0 + i as f32 * ROW_H;
+        draw_ui_text(key, x + 20.0, row_y, 16.0, WHITE);
+        draw_ui_text(text, x + 20.0 + KEY_COL_W, row_y, 16.0, LIGHTGRAY);
+    }
+}
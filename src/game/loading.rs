@@ -0,0 +1,161 @@
+//! Startup loading screen - textures stream in a few per frame behind a progress bar
+
+use macroquad::prelude::*;
+use macroquad_toolkit::assets::{load_texture_from_pack_or_file, AssetPack};
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+use std::collections::{HashMap, VecDeque};
+
+/// Textures loaded before yielding the frame
+const TEXTURES_PER_FRAME: usize = 4;
+const BAR_W: f32 = 480.0;
+const BAR_H: f32 = 18.0;
+
+/// Queue of texture paths still to load, drained across frames
+pub struct LoadingState {
+    queue: VecDeque<String>,
+    total: usize,
+    asset_pack: Option<AssetPack>,
+}
+
+impl LoadingState {
+    pub fn new(asset_pack: Option<AssetPack>) -> Self {
+        let queue: VecDeque<String> = texture_paths().into();
+        Self {
+            total: queue.len(),
+            queue,
+            asset_pack,
+        }
+    }
+
+    /// Load the next batch of textures; missing files are skipped
+    pub async fn load_batch(&mut self, textures: &mut HashMap<String, Texture2D>) {
+        for _ in 0..TEXTURES_PER_FRAME {
+            let Some(path) = self.queue.pop_front() else {
+                return;
+            };
+            if let Ok(tex) =
+                load_texture_from_pack_or_file(self.asset_pack.as_ref(), &path, FilterMode::Linear)
+                    .await
+            {
+                textures.insert(path, tex);
+            }
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Share of the queue already loaded, 0.0 to 1.0
+    fn progress(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.total - self.queue.len()) as f32 / self.total as f32
+    }
+
+    pub fn draw(&self) {
+        let x = (screen_width() - BAR_W) / 2.0;
+        let y = screen_height() / 2.0;
+        let title = "FRONTIER KINGDOM";
+        let title_w = measure_ui_text(title, None, 32, 1.0).width;
+        draw_ui_text(
+            title,
+            (screen_width() - title_w) / 2.0,
+            y - 40.0,
+            32.0,
+            GOLD,
+        );
+
+        draw_rectangle(x, y, BAR_W, BAR_H, Color::from_rgba(12, 12, 16, 255));
+        draw_rectangle(x, y, BAR_W * self.progress(), BAR_H, GOLD);
+        draw_rectangle_lines(x, y, BAR_W, BAR_H, 2.0, GRAY);
+
+        let label = format!(
+            "Loading assets {}/{}",
+            self.total - self.queue.len(),
+            self.total
+        );
+        let label_w = measure_ui_text(&label, None, 16, 1.0).width;
+        draw_ui_text(
+            &label,
+            (screen_width() - label_w) / 2.0,
+            y + BAR_H + 26.0,
+            16.0,
+            LIGHTGRAY,
+        );
+    }
+}
+
+/// Every texture the game may draw, in load order
+fn texture_paths() -> Vec<String> {
+    macro_rules! json_paths {
+        ($path:literal, $field:expr) => {{
+            #[cfg(target_arch = "wasm32")]
+            let content = include_str!(concat!("../../", $path));
+
+            #[cfg(not(target_arch = "wasm32"))]
+            let content = match std::fs::read_to_string($path) {
+                Ok(c) => c,
+                Err(_) => include_str!(concat!("../../", $path)).to_string(),
+            };
+
+            paths_from_json(&content, $field)
+        }};
+    }
+
+    // Cards and enemies name their own images
+    let mut paths = json_paths!("assets/cards.json", "image_path");
+    paths.extend(json_paths!("assets/enemies.json", "image_path"));
+
+    // Character images (adventurers are generated, not from JSON)
+    let char_images = [
+        "soldier_male",
+        "soldier_female",
+        "scout_male",
+        "scout_female",
+        "healer_male",
+        "healer_female",
+        "mystic_male",
+        "mystic_female",
+    ];
+    paths.extend(
+        char_images
+            .iter()
+            .map(|name| format!("assets/images/characters/{}.png", name)),
+    );
+
+    // Region images
+    let region_images = ["dark_woods", "ruined_outpost", "sunken_valley"];
+    paths.extend(
+        region_images
+            .iter()
+            .map(|name| format!("assets/images/regions/{}.png", name)),
+    );
+
+    // UI backdrops
+    let ui_images = ["command_table"];
+    paths.extend(
+        ui_images
+            .iter()
+            .map(|name| format!("assets/images/ui/{}.png", name)),
+    );
+
+    // Several cards can share one image
+    let mut seen = std::collections::HashSet::new();
+    paths.retain(|path| seen.insert(path.clone()));
+    paths
+}
+
+/// Image paths named by `field_name` in a JSON array of objects
+fn paths_from_json(json_str: &str, field_name: &str) -> Vec<String> {
+    serde_json::from_str::<Vec<serde_json::Value>>(json_str)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.get(field_name).and_then(|v| v.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -1,7 +1,23 @@
 //! Adventurer - persistent characters that remember
 
+use super::stress::{gain_stress, heart_attack_damage, StressOutcome};
 use serde::{Deserialize, Serialize};
 
+/// Fatigue gained for each mission an adventurer goes on
+pub const FATIGUE_PER_MISSION: i32 = 25;
+/// Fatigue shed for each day of rest at the base
+pub const FATIGUE_RECOVERY_PER_DAY: i32 = 15;
+/// Ceiling on accumulated fatigue
+pub const MAX_FATIGUE: i32 = 100;
+/// Fatigue at which an adventurer starts fights below full health
+pub const FATIGUE_HP_THRESHOLD: i32 = 50;
+/// Percent of max HP lost in combat while at or above the fatigue threshold
+pub const FATIGUE_HP_PENALTY_PERCENT: i32 = 20;
+/// XP needed to leave a level, per level already reached
+const XP_PER_LEVEL: i32 = 20;
+/// Max HP gained with each new level
+const LEVEL_MAX_HP: i32 = 3;
+
 /// An adventurer in the kingdom's roster
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Adventurer {
@@ -19,6 +35,9 @@ pub struct Adventurer {
     pub resolve_state: Option<ResolveState>,
     #[serde(default)]
     pub heart_attacks: u32,
+    /// Wear from back-to-back missions, shed by resting at the base
+    #[serde(default)]
+    pub fatigue: i32,
     pub level: i32,
     pub xp: i32,
 
@@ -36,12 +55,23 @@ pub struct Adventurer {
     pub missions_completed: u32,
     pub kills: u32,
     pub image_path: Option<String>,
+    /// Title earned through milestones, e.g. "the Brave"
+    #[serde(default)]
+    pub epithet: Option<String>,
 }
 
 fn default_gender() -> Gender {
     Gender::Male
 }
 
+/// Max HP available in combat once fatigue is taken into account
+pub fn fatigued_max_hp(max_hp: i32, fatigue: i32) -> i32 {
+    if fatigue < FATIGUE_HP_THRESHOLD {
+        return max_hp;
+    }
+    (max_hp * (100 - FATIGUE_HP_PENALTY_PERCENT) / 100).max(1)
+}
+
 impl Adventurer {
     pub fn new(name: &str, class: AdventurerClass, gender: Gender) -> Self {
         let base_hp = match class {
@@ -80,6 +110,7 @@ impl Adventurer {
             stress: 0,
             resolve_state: None,
             heart_attacks: 0,
+            fatigue: 0,
             level: 1,
             xp: 0,
             traits: vec![],
@@ -91,13 +122,13 @@ impl Adventurer {
             missions_completed: 0,
             kills: 0,
             image_path,
+            epithet: None,
         }
     }
 
     /// Check if adventurer is too stressed to deploy
-    #[allow(dead_code)]
     pub fn is_stressed(&self) -> bool {
-        self.stress >= 50
+        self.stress >= crate::data::balance().fearful_stress
     }
 
     /// Check if adventurer is injured
@@ -106,132 +137,180 @@ impl Adventurer {
         !self.injuries.is_empty()
     }
 
-    /// Apply stress, potentially triggering trauma
-    #[allow(dead_code)]
-    pub fn add_stress(&mut self, amount: i32) -> Option<Trauma> {
-        let before = self.stress;
-        self.stress = (self.stress + amount).max(0);
-        let reached_max_stress = self.stress >= 200;
-
-        if amount <= 0 {
-            return None;
+    /// Apply stress, potentially triggering trauma or a resolve breakthrough
+    pub fn add_stress(&mut self, amount: i32) -> StressOutcome {
+        let change = gain_stress(
+            &mut self.stress,
+            &mut self.traumas,
+            &mut self.resolve_state,
+            amount,
+        );
+        if change.heart_attack {
+            self.heart_attacks += 1;
+            self.hp -= heart_attack_damage(self.max_hp);
         }
-
-        if before < 100 && self.stress >= 100 && self.resolve_state.is_none() {
-            self.resolve_check();
-        }
-
-        let trauma = self.trauma_for_current_stress();
-
-        if reached_max_stress || self.stress >= 200 {
-            self.heart_attack();
+        if matches!(change.outcome, StressOutcome::Breakthrough)
+            && !self.traits.iter().any(|t| t.id == "steadfast")
+        {
+            self.traits.push(Trait {
+                id: "steadfast".to_string(),
+                name: "Steadfast".to_string(),
+                description: "Passed a Resolve Check; stress relief is more effective.".to_string(),
+                is_positive: true,
+            });
         }
-
-        trauma
+        change.outcome
     }
 
     /// Apply stress and return human-readable consequences for the result screen.
     pub fn apply_stress_gain(&mut self, amount: i32) -> Vec<String> {
+        let max_stress = crate::data::balance().max_stress;
         let before = self.stress;
+        let heart_attacks = self.heart_attacks;
         let mut messages = Vec::new();
-        let trauma = self.add_stress(amount);
 
-        if let Some(trauma) = trauma {
-            messages.push(format!("{} gained trauma: {}", self.name, trauma.name()));
+        match self.add_stress(amount) {
+            StressOutcome::Breakthrough => messages.push(format!(
+                "{} had a resolve breakthrough: stress fell to {} and they fight Virtuous",
+                self.name, self.stress
+            )),
+            StressOutcome::Trauma(trauma) => {
+                messages.push(format!("{} gained trauma: {}", self.name, trauma.name()));
+            }
+            StressOutcome::Steady => {}
         }
 
-        if before < 100 && self.resolve_state.is_some() {
-            match self.resolve_state {
-                Some(ResolveState::Virtuous) => messages.push(format!(
-                    "{} passed a Resolve Check and became Virtuous",
-                    self.name
-                )),
-                Some(ResolveState::Afflicted) => messages.push(format!(
-                    "{} failed a Resolve Check and became Afflicted",
-                    self.name
-                )),
-                None => {}
-            }
+        if before < max_stress
+            && self.stress >= max_stress
+            && self.resolve_state == Some(ResolveState::Afflicted)
+        {
+            messages.push(format!(
+                "{} failed a Resolve Check and became Afflicted",
+                self.name
+            ));
         }
 
-        if self.heart_attacks > 0 && self.stress >= 100 {
+        if self.heart_attacks > heart_attacks {
             messages.push(format!("{} suffered a Heart Attack", self.name));
         }
 
         messages
     }
 
-    fn trauma_for_current_stress(&mut self) -> Option<Trauma> {
-        if self.stress >= 100 && !self.has_trauma(TraumaType::Broken) {
-            let trauma = Trauma::new(TraumaType::Broken);
-            self.traumas.push(trauma.clone());
-            Some(trauma)
-        } else if self.stress >= 75 && !self.has_trauma(TraumaType::Paranoid) {
-            let trauma = Trauma::new(TraumaType::Paranoid);
-            self.traumas.push(trauma.clone());
-            Some(trauma)
-        } else if self.stress >= 50 && !self.has_trauma(TraumaType::Fearful) {
-            let trauma = Trauma::new(TraumaType::Fearful);
-            self.traumas.push(trauma.clone());
-            Some(trauma)
-        } else {
-            None
+    /// Reduce stress (at base, costs resources)
+    pub fn reduce_stress(&mut self, amount: i32) {
+        let calm = self.resolve_state == Some(ResolveState::Virtuous)
+            || self.traits.iter().any(|t| t.id == "steady_nerves");
+        let effective = if calm { amount + 5 } else { amount };
+        self.stress = (self.stress - effective).max(0);
+        if self.stress < crate::data::balance().fearful_stress {
+            self.resolve_state = None;
         }
     }
 
-    fn resolve_check(&mut self) {
-        if macroquad_toolkit::rng::chance(0.25) {
-            self.resolve_state = Some(ResolveState::Virtuous);
-            self.stress = 80;
-            self.traits.push(Trait {
-                id: "steadfast".to_string(),
-                name: "Steadfast".to_string(),
-                description: "Passed a Resolve Check; stress relief is more effective.".to_string(),
-                is_positive: true,
-            });
-        } else {
-            self.resolve_state = Some(ResolveState::Afflicted);
-            if !self.has_trauma(TraumaType::Broken) {
-                self.traumas.push(Trauma::new(TraumaType::Broken));
-            }
-            self.stress = 100;
+    /// Roll to shed the mildest trauma, returning it if recovered
+    pub fn attempt_trauma_recovery(&mut self) -> Option<Trauma> {
+        if self.traumas.is_empty()
+            || !macroquad_toolkit::rng::chance(crate::data::balance().chapel_trauma_recovery_chance)
+        {
+            return None;
         }
+        let idx = self
+            .traumas
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, t)| t.severity)
+            .map(|(i, _)| i)?;
+        Some(self.traumas.remove(idx))
     }
 
-    fn heart_attack(&mut self) {
-        self.heart_attacks += 1;
-        self.hp -= (self.max_hp / 2).max(1);
-        self.stress = 100;
+    /// Day-by-day recovery: calm adventurers slowly shed low-severity traumas
+    pub fn recover_over_day(&mut self) -> Option<Trauma> {
+        if self.is_stressed() {
+            return None;
+        }
+        let idx = self.traumas.iter().position(|t| t.severity <= 1)?;
+        if !macroquad_toolkit::rng::chance(crate::data::balance().daily_trauma_recovery_chance) {
+            return None;
+        }
+        Some(self.traumas.remove(idx))
     }
 
-    fn has_trauma(&self, trauma_type: TraumaType) -> bool {
-        self.traumas.iter().any(|t| t.trauma_type == trauma_type)
+    /// Count every injury a day closer to healed, returning those that closed
+    pub fn heal_injuries_over_day(&mut self) -> Vec<Injury> {
+        let mut healed = Vec::new();
+        self.injuries.retain_mut(|injury| {
+            injury.healing_days -= 1;
+            if injury.healing_days > 0 {
+                return true;
+            }
+            healed.push(injury.clone());
+            false
+        });
+        healed
     }
 
-    /// Reduce stress (at base, costs resources)
-    pub fn reduce_stress(&mut self, amount: i32) {
-        let effective = if self.resolve_state == Some(ResolveState::Virtuous) {
-            amount + 5
-        } else {
-            amount
-        };
-        self.stress = (self.stress - effective).max(0);
-        if self.stress < 50 {
-            self.resolve_state = None;
+    /// Wear from a mission, capped at `MAX_FATIGUE`
+    pub fn add_fatigue(&mut self, amount: i32) {
+        self.fatigue = (self.fatigue + amount).clamp(0, MAX_FATIGUE);
+    }
+
+    /// Bank XP, rolling over into as many level-ups as it covers; returns the levels gained
+    pub fn gain_xp(&mut self, amount: i32) -> i32 {
+        self.xp += amount.max(0);
+        let mut gained = 0;
+        while self.xp >= self.level * XP_PER_LEVEL {
+            self.xp -= self.level * XP_PER_LEVEL;
+            self.level += 1;
+            self.max_hp += LEVEL_MAX_HP;
+            self.hp = (self.hp + LEVEL_MAX_HP).min(self.max_hp);
+            gained += 1;
         }
+        gained
+    }
+
+    /// A day of rest sheds some fatigue
+    pub fn rest(&mut self) {
+        self.fatigue = (self.fatigue - FATIGUE_RECOVERY_PER_DAY).max(0);
     }
 
     /// Heal HP (at infirmary)
     pub fn heal(&mut self, amount: i32) {
         self.hp = (self.hp + amount).min(self.max_hp);
     }
+
+    /// Name with any earned epithet
+    pub fn title(&self) -> String {
+        match &self.epithet {
+            Some(epithet) => format!("{} {}", self.name, epithet),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Take the most renowned epithet earned so far, announcing a new one
+    pub fn update_epithet(&mut self) -> Option<String> {
+        let earned = crate::data::names::epithet_for(self)?;
+        if self.epithet.as_ref() == Some(&earned) {
+            return None;
+        }
+        self.epithet = Some(earned);
+        Some(format!("{} is now known as {}", self.name, self.title()))
+    }
 }
 
 /// Simple UUID generator (timestamp-based for uniqueness)
 fn uuid_simple() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    // Adventurers created in the same frame share a timestamp
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
     let now = macroquad::time::get_time();
     // Convert to something resembling nanos/unique string
-    format!("adv_{}", (now * 1_000_000.0) as u64)
+    format!(
+        "adv_{}_{}",
+        (now * 1_000_000.0) as u64,
+        SEQUENCE.fetch_add(1, Ordering::Relaxed)
+    )
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -264,6 +343,44 @@ pub struct Trait {
     pub is_positive: bool,
 }
 
+impl Trait {
+    pub fn hardy() -> Self {
+        Self {
+            id: "hardy".to_string(),
+            name: "Hardy".to_string(),
+            description: "Tougher than most; +5 max HP.".to_string(),
+            is_positive: true,
+        }
+    }
+
+    pub fn steady_nerves() -> Self {
+        Self {
+            id: "steady_nerves".to_string(),
+            name: "Steady Nerves".to_string(),
+            description: "Arrives calm, shedding stress more easily.".to_string(),
+            is_positive: true,
+        }
+    }
+
+    pub fn frail() -> Self {
+        Self {
+            id: "frail".to_string(),
+            name: "Frail".to_string(),
+            description: "Half-starved; -5 max HP.".to_string(),
+            is_positive: false,
+        }
+    }
+
+    pub fn haunted() -> Self {
+        Self {
+            id: "haunted".to_string(),
+            name: "Haunted".to_string(),
+            description: "Arrives already carrying stress.".to_string(),
+            is_positive: false,
+        }
+    }
+}
+
 /// Physical injuries from combat
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Injury {
@@ -286,7 +403,22 @@ impl Injury {
         }
     }
 
-    #[allow(dead_code)]
+    /// Card tag this injury hampers and the energy it adds to such cards
+    pub fn cost_surcharge(&self) -> Option<(&'static str, i32)> {
+        match self.id.as_str() {
+            "wounded_leg" => Some(("movement", 1)),
+            _ => None,
+        }
+    }
+
+    /// Damage this injury takes off each of the bearer's attacks
+    pub fn damage_penalty(&self) -> i32 {
+        match self.id.as_str() {
+            "broken_arm" => 2,
+            _ => 0,
+        }
+    }
+
     pub fn broken_arm() -> Self {
         Self {
             id: "broken_arm".to_string(),
@@ -322,11 +454,20 @@ impl Trauma {
             TraumaType::Hopeless => "Hopeless",
         }
     }
+    /// What the trauma costs the adventurer in combat
+    pub fn penalty(&self) -> &'static str {
+        match self.trauma_type {
+            TraumaType::Fearful => "May freeze and skip turns",
+            TraumaType::Paranoid => "Block cards cost +1 energy",
+            TraumaType::Broken => "All cards cost +1 energy",
+            TraumaType::Hopeless => "Cannot reduce stress in combat",
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum TraumaType {
-    Fearful,  // Chance to skip turn
+    Fearful,  // Chance to skip turn, and to fumble attacks
     Paranoid, // Block cards cost +1
     Broken,   // All cards cost +1
     Hopeless, // Cannot reduce stress in combat
@@ -342,6 +483,7 @@ pub enum StatusType {
     Block,      // Absorbs damage (Value amount), duration usually 1 turn
     Poison,     // Take Value damage per turn
     Burn,       // Take Value damage per turn
+    Bleed,      // Take Value damage per turn, through any block
 }
 
 impl StatusType {
@@ -354,8 +496,34 @@ impl StatusType {
                 | StatusType::Stun
                 | StatusType::Poison
                 | StatusType::Burn
+                | StatusType::Bleed
         )
     }
+
+    /// How a second application of this status combines with one already on the unit
+    pub fn stacking(&self) -> Stacking {
+        match self {
+            StatusType::Vulnerable | StatusType::Weak => Stacking::Refresh,
+            StatusType::Stun => Stacking::SumDuration,
+            StatusType::Strength
+            | StatusType::Regen
+            | StatusType::Block
+            | StatusType::Poison
+            | StatusType::Burn
+            | StatusType::Bleed => Stacking::SumValue,
+        }
+    }
+}
+
+/// Stacking rule for re-applying a status of the same type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stacking {
+    /// Duration resets to the longer of the two; value keeps the stronger
+    Refresh,
+    /// Values add up; duration resets to the longer of the two
+    SumValue,
+    /// Durations add up; value keeps the stronger
+    SumDuration,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -2,6 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Highest level a facility can be raised to
+const MAX_BUILDING_LEVEL: i32 = 3;
+
 /// A building in the kingdom base
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Building {
@@ -15,6 +18,18 @@ pub struct Building {
 }
 
 impl Building {
+    /// Built, below the level cap, and not the Citadel, whose construction ends the campaign
+    pub fn can_upgrade(&self) -> bool {
+        self.built && self.level < MAX_BUILDING_LEVEL && self.id != "citadel"
+    }
+
+    /// Gold and supplies for the next step: the build cost, then that cost times the
+    /// level being reached
+    pub fn next_cost(&self) -> (i32, i32) {
+        let scale = if self.built { self.level + 1 } else { 1 };
+        (self.cost_gold * scale, self.cost_supplies * scale)
+    }
+
     pub fn all_starter() -> Vec<Self> {
         vec![
             Self::infirmary(),
@@ -99,3 +114,28 @@ impl Building {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgrades_cost_more_each_level_up_to_the_cap() {
+        let mut infirmary = Building::infirmary();
+        assert!(!infirmary.can_upgrade());
+        assert_eq!(infirmary.next_cost(), (50, 20));
+
+        infirmary.built = true;
+        infirmary.level = 1;
+        assert!(infirmary.can_upgrade());
+        assert_eq!(infirmary.next_cost(), (100, 40));
+
+        infirmary.level = MAX_BUILDING_LEVEL;
+        assert!(!infirmary.can_upgrade());
+
+        let mut citadel = Building::citadel();
+        citadel.built = true;
+        citadel.level = 1;
+        assert!(!citadel.can_upgrade());
+    }
+}
@@ -0,0 +1,161 @@
+//! Daily kingdom events - decisions that find the kingdom between expeditions
+//!
+//! These reuse the mission `Event` types. Outcomes are read at kingdom scope:
+//! `Gold`, `Supplies` and `Morale` hit the treasury and `Stress` falls on the
+//! adventurer the event is about.
+
+use super::{KingdomState, Roster};
+use crate::missions::events::{Event, EventChoice, EventOutcome};
+
+/// Odds that a new day brings a kingdom event
+const DAILY_EVENT_CHANCE: f32 = 0.3;
+/// Security at which the watch can drive off raiders unaided
+const RAID_SECURITY_THRESHOLD: i32 = 40;
+/// Ambush odds for a kingdom with no security at all
+const HOMEWARD_AMBUSH_CHANCE: f32 = 0.35;
+/// Most of a harmful event that security can ever prevent
+const MAX_SECURITY_MITIGATION: f32 = 0.6;
+
+/// Share of a harmful event's chance and severity that security prevents (0.0-0.6)
+pub fn security_mitigation(security: i32) -> f32 {
+    (security.clamp(0, 100) as f32 / 100.0) * MAX_SECURITY_MITIGATION
+}
+
+/// Odds that a victorious party is ambushed on the road home; falls as security rises
+pub fn ambush_chance(security: i32) -> f32 {
+    HOMEWARD_AMBUSH_CHANCE * (1.0 - security.clamp(0, 100) as f32 / 100.0)
+}
+
+/// Scale a loss down by the kingdom's security mitigation
+fn mitigated(loss: i32, mitigation: f32) -> i32 {
+    (loss as f32 * (1.0 - mitigation)).round() as i32
+}
+
+/// Maybe roll today's kingdom event, with the adventurer it concerns (if any)
+pub fn roll_daily_event(
+    kingdom: &KingdomState,
+    roster: &Roster,
+) -> Option<(Event, Option<String>)> {
+    if !macroquad_toolkit::rng::chance(DAILY_EVENT_CHANCE) {
+        return None;
+    }
+
+    let mitigation = security_mitigation(kingdom.stats.security);
+    let harmful = match macroquad_toolkit::rng::gen_range(0, 3) {
+        0 => (bandit_raid(kingdom.stats.security, mitigation), None),
+        1 => return Some((merchant_caravan(kingdom.stats.gold), None)),
+        _ => {
+            let sick = macroquad_toolkit::rng::choose(&roster.adventurers)?;
+            (fever(&sick.name, mitigation), Some(sick.id.clone()))
+        }
+    };
+
+    // A vigilant kingdom heads off some harmful events entirely
+    if macroquad_toolkit::rng::chance(mitigation) {
+        return None;
+    }
+    Some(harmful)
+}
+
+/// Raiders at the gate; a well-kept watch turns them away for free
+fn bandit_raid(security: i32, mitigation: f32) -> Event {
+    let tribute = mitigated(25, mitigation);
+    let watch = if security >= RAID_SECURITY_THRESHOLD {
+        EventChoice {
+            text: "Call out the watch (they can hold)".to_string(),
+            outcomes: vec![EventOutcome::Morale(3)],
+        }
+    } else {
+        let losses = mitigated(40, mitigation);
+        let morale = mitigated(5, mitigation);
+        EventChoice {
+            text: format!(
+                "Call out the watch (-{} gold, -{} morale: too few guards)",
+                losses, morale
+            ),
+            outcomes: vec![EventOutcome::Gold(-losses), EventOutcome::Morale(-morale)],
+        }
+    };
+
+    Event {
+        id: "bandit_raid".to_string(),
+        title: "Bandit Raid".to_string(),
+        description:
+            "Raiders ride on the outer farms, demanding tribute before they burn the granaries."
+                .to_string(),
+        choices: vec![
+            EventChoice {
+                text: format!("Pay the tribute (-{} gold)", tribute),
+                outcomes: vec![EventOutcome::Gold(-tribute)],
+            },
+            watch,
+        ],
+    }
+}
+
+/// A trader offers supplies for coin
+fn merchant_caravan(gold: i32) -> Event {
+    let mut choices = Vec::new();
+    if gold >= 20 {
+        choices.push(EventChoice {
+            text: "Buy provisions (-20 gold, +30 supplies)".to_string(),
+            outcomes: vec![EventOutcome::Gold(-20), EventOutcome::Supplies(30)],
+        });
+    }
+    choices.push(EventChoice {
+        text: "Send them on their way".to_string(),
+        outcomes: vec![EventOutcome::Nothing],
+    });
+
+    Event {
+        id: "merchant_caravan".to_string(),
+        title: "Merchant Caravan".to_string(),
+        description: "A caravan rolls through the gate, wagons heavy with grain and salted meat."
+            .to_string(),
+        choices,
+    }
+}
+
+/// An adventurer falls sick at the base
+fn fever(name: &str, mitigation: f32) -> Event {
+    let stress = mitigated(15, mitigation);
+    Event {
+        id: "fever".to_string(),
+        title: "Fever in the Barracks".to_string(),
+        description: format!(
+            "{} has taken ill. Left alone, the fever will gnaw at their nerve.",
+            name
+        ),
+        choices: vec![
+            EventChoice {
+                text: "Spend medicine on them (-10 supplies)".to_string(),
+                outcomes: vec![EventOutcome::Supplies(-10)],
+            },
+            EventChoice {
+                text: format!("Let it run its course ({} +{} stress)", name, stress),
+                outcomes: vec![EventOutcome::Stress(stress)],
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn security_softens_losses() {
+        assert_eq!(security_mitigation(0), 0.0);
+        assert!(security_mitigation(100) <= MAX_SECURITY_MITIGATION);
+        assert!(security_mitigation(80) > security_mitigation(30));
+        assert_eq!(mitigated(40, security_mitigation(0)), 40);
+        assert!(mitigated(40, security_mitigation(100)) < 40);
+    }
+
+    #[test]
+    fn ambushes_fade_with_security() {
+        assert_eq!(ambush_chance(0), HOMEWARD_AMBUSH_CHANCE);
+        assert!(ambush_chance(60) < ambush_chance(20));
+        assert_eq!(ambush_chance(100), 0.0);
+    }
+}
@@ -2,17 +2,28 @@
 
 mod adventurer;
 mod buildings;
+mod difficulty;
+pub mod events;
 mod party;
+mod pronouns;
 mod roster;
+mod seeded_rolls;
 mod stats;
+mod stress;
+mod tutorial;
 mod unlock;
 
 pub use adventurer::{
-    Adventurer, AdventurerClass, Gender, Injury, ResolveState, StatusEffect, StatusType, Trauma,
-    TraumaType,
+    fatigued_max_hp, Adventurer, AdventurerClass, Gender, Injury, ResolveState, Stacking,
+    StatusEffect, StatusType, Trait, Trauma, TraumaType, FATIGUE_PER_MISSION, MAX_FATIGUE,
 };
 pub use buildings::Building;
-pub use party::{Party, PartyMemberState, MAX_PARTY_SIZE};
-pub use roster::Roster;
-pub use stats::KingdomState;
+pub use difficulty::Difficulty;
+pub use party::{FormationRow, Party, PartyMemberState, MAX_PARTY_SIZE};
+pub use pronouns::fill_pronouns;
+pub use roster::{Roster, BOND_BLOCK, RIVAL_STRESS};
+pub use seeded_rolls::{mix_seed, seed_rolls};
+pub use stats::{KingdomState, KingdomStats};
+pub use stress::{gain_stress, heart_attack_damage, StressOutcome};
+pub use tutorial::TutorialStep;
 pub use unlock::UnlockRequirement;
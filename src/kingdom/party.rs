@@ -1,10 +1,39 @@
 //! Party management - groups of adventurers that go on missions together
 
-use super::adventurer::{Adventurer, ResolveState, Trauma};
+use super::adventurer::{Adventurer, Gender, Injury, ResolveState, Trait, Trauma};
 use serde::{Deserialize, Serialize};
 
 /// Maximum party size
 pub const MAX_PARTY_SIZE: usize = 4;
+/// Number of formation slots that make up the front row
+pub const FRONT_ROW_SIZE: usize = 2;
+
+/// Which row of the formation a party member stands in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormationRow {
+    /// Takes the brunt of enemy melee attacks
+    Front,
+    /// Safer, but reachable by ranged and hexing enemies
+    Back,
+}
+
+impl FormationRow {
+    /// Row for a formation slot
+    pub fn for_position(position: usize) -> Self {
+        if position < FRONT_ROW_SIZE {
+            FormationRow::Front
+        } else {
+            FormationRow::Back
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FormationRow::Front => "FRONT",
+            FormationRow::Back => "BACK",
+        }
+    }
+}
 
 /// A party of adventurers ready to embark on a mission
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -66,6 +95,27 @@ impl Party {
         }
     }
 
+    /// Remove a member other than the leader, who can only leave by disbanding the party
+    pub fn remove_follower(&mut self, id: &str) -> bool {
+        self.leader_id() != Some(id) && self.remove_member(id)
+    }
+
+    /// Move a follower one slot toward the front of the formation; the leader keeps slot 0
+    pub fn move_member_forward(&mut self, id: &str) -> bool {
+        match self.member_ids.iter().position(|m| m == id) {
+            Some(pos) if pos > 1 => {
+                self.member_ids.swap(pos, pos - 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Formation slot of a member, if in the party
+    pub fn position_of(&self, id: &str) -> Option<usize> {
+        self.member_ids.iter().position(|m| m == id)
+    }
+
     /// Check if an adventurer is in the party
     pub fn contains(&self, id: &str) -> bool {
         self.member_ids.iter().any(|m| m == id)
@@ -79,18 +129,32 @@ impl Default for Party {
 }
 
 /// Snapshot of a party member's state for use in missions/combat
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PartyMemberState {
     pub id: String,
     pub name: String,
+    pub gender: Gender,
     pub hp: i32,
     pub max_hp: i32,
     pub stress: i32,
+    /// Fatigue carried into the mission (see `fatigued_max_hp`)
+    pub fatigue: i32,
+    pub level: i32,
     pub image_path: Option<String>,
     pub class_name: String,
     pub deck_additions: Vec<String>,
+    pub traits: Vec<Trait>,
+    pub injuries: Vec<Injury>,
     pub traumas: Vec<Trauma>,
     pub resolve_state: Option<ResolveState>,
+    /// Formation slot (0 is the leader, front row first)
+    pub position: usize,
+    /// XP earned in fights so far this expedition
+    pub xp_earned: i32,
+    /// Enemies this member finished off this expedition
+    pub kills: u32,
+    /// Party members this one shares a bond with (see `BOND_BLOCK`)
+    pub bonded_partners: usize,
 }
 
 impl PartyMemberState {
@@ -99,14 +163,83 @@ impl PartyMemberState {
         Self {
             id: adv.id.clone(),
             name: adv.name.clone(),
+            gender: adv.gender.clone(),
             hp: adv.hp,
             max_hp: adv.max_hp,
             stress: adv.stress,
+            fatigue: adv.fatigue,
+            level: adv.level,
             image_path: adv.image_path.clone(),
             class_name: format!("{:?}", adv.class),
             deck_additions: adv.deck_additions.clone(),
+            traits: adv.traits.clone(),
+            injuries: adv.injuries.clone(),
             traumas: adv.traumas.clone(),
             resolve_state: adv.resolve_state.clone(),
+            position: 0,
+            xp_earned: 0,
+            kills: 0,
+            bonded_partners: 0,
+        }
+    }
+
+    /// Formation row this member stands in
+    pub fn row(&self) -> FormationRow {
+        FormationRow::for_position(self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn party_of(ids: &[&str]) -> Party {
+        let mut party = Party::with_leader(ids[0]);
+        for id in &ids[1..] {
+            party.add_member(id);
         }
+        party
+    }
+
+    #[test]
+    fn party_stops_growing_at_max_size() {
+        let mut party = party_of(&["a", "b", "c", "d"]);
+        assert_eq!(party.size(), MAX_PARTY_SIZE);
+        assert!(party.is_full());
+        assert!(!party.add_member("e"));
+        assert!(!party.contains("e"));
+    }
+
+    #[test]
+    fn party_rejects_duplicate_members() {
+        let mut party = party_of(&["a", "b"]);
+        assert!(!party.add_member("b"));
+        assert!(!party.add_member("a"));
+        assert_eq!(party.size(), 2);
+    }
+
+    #[test]
+    fn leader_cannot_be_removed_as_a_follower() {
+        let mut party = party_of(&["a", "b"]);
+        assert!(!party.remove_follower("a"));
+        assert_eq!(party.leader_id(), Some("a"));
+        assert!(party.remove_follower("b"));
+        assert!(!party.contains("b"));
+    }
+
+    #[test]
+    fn leader_is_always_the_first_slot() {
+        let mut party = party_of(&["a", "b", "c"]);
+        assert!(party.remove_member("a"));
+        assert_eq!(party.leader_id(), Some("b"));
+        assert!(party.move_member_forward("c"));
+        assert_eq!(party.leader_id(), Some("b"));
+        assert_eq!(party.position_of("c"), Some(1));
+        assert!(!party.move_member_forward("c"));
+        assert!(!party.move_member_forward("b"));
+        assert!(party.remove_member("b"));
+        assert_eq!(party.leader_id(), Some("c"));
+        assert!(party.remove_member("c"));
+        assert_eq!(party.leader_id(), None);
     }
 }
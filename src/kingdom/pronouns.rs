@@ -0,0 +1,56 @@
+//! Pronoun templating - fill `{they}`-style tokens in flavor text for an adventurer's gender
+
+use super::adventurer::Gender;
+
+/// Tokens writers can use, with their male, female, and unknown-gender replacements
+const PRONOUNS: &[(&str, &str, &str, &str)] = &[
+    ("{they}", "he", "she", "they"),
+    ("{They}", "He", "She", "They"),
+    ("{them}", "him", "her", "them"),
+    ("{Them}", "Him", "Her", "Them"),
+    ("{their}", "his", "her", "their"),
+    ("{Their}", "His", "Her", "Their"),
+    ("{theirs}", "his", "hers", "theirs"),
+    ("{Theirs}", "His", "Hers", "Theirs"),
+    ("{themself}", "himself", "herself", "themself"),
+];
+
+/// Replace pronoun tokens in `text` with the pronouns for `gender`; no gender reads as they/them
+pub fn fill_pronouns(text: &str, gender: Option<&Gender>) -> String {
+    if !text.contains('{') {
+        return text.to_string();
+    }
+    let mut filled = text.to_string();
+    for (token, male, female, neutral) in PRONOUNS {
+        let pronoun = match gender {
+            Some(Gender::Male) => male,
+            Some(Gender::Female) => female,
+            None => neutral,
+        };
+        filled = filled.replace(token, pronoun);
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_tokens_and_leaves_plain_text_alone() {
+        let text = "{They} steadied {their} nerve; the choice was {theirs}.";
+        assert_eq!(
+            fill_pronouns(text, Some(&Gender::Female)),
+            "She steadied her nerve; the choice was hers."
+        );
+        assert_eq!(
+            fill_pronouns(text, Some(&Gender::Male)),
+            "He steadied his nerve; the choice was his."
+        );
+        assert_eq!(
+            fill_pronouns(text, None),
+            "They steadied their nerve; the choice was theirs."
+        );
+        assert_eq!(fill_pronouns("No tokens here", None), "No tokens here");
+    }
+}
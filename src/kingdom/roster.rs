@@ -1,13 +1,32 @@
 //! Roster management - the adventurer pool
 
 use super::adventurer::{Adventurer, AdventurerClass};
+use crate::data::names;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Affinity at or above which two adventurers are bonded
+const BONDED_AFFINITY: i32 = 20;
+/// Affinity at or below which two adventurers are rivals
+const RIVAL_AFFINITY: i32 = -20;
+/// Block each bonded partner in the party grants at the start of a member's turn
+pub const BOND_BLOCK: i32 = 1;
+/// Stress each rival in the party adds when a mission ends
+pub const RIVAL_STRESS: i32 = 4;
+/// Affinity gained by every pair that comes home together
+const SHARED_MISSION_AFFINITY: i32 = 6;
+/// Affinity lost by every surviving pair for each partner who fell
+const FALLEN_PARTNER_AFFINITY: i32 = 8;
+const MAX_AFFINITY: i32 = 100;
 
 /// The kingdom's adventurer roster
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Roster {
     pub adventurers: Vec<Adventurer>,
     pub graveyard: Vec<Adventurer>, // Fallen heroes
+    /// Relationship between two adventurers, keyed by their ids in sorted order
+    #[serde(default, with = "affinity_pairs")]
+    pub affinities: HashMap<(String, String), i32>,
 }
 
 impl Roster {
@@ -15,6 +34,7 @@ impl Roster {
         Self {
             adventurers: vec![],
             graveyard: vec![],
+            affinities: HashMap::new(),
         }
     }
 
@@ -22,18 +42,18 @@ impl Roster {
     pub fn starter() -> Self {
         let mut roster = Self::new();
 
-        // The initial unideal roster (per GDD)
         // The initial unideal roster (per GDD)
         roster.adventurers.push(Adventurer::new(
-            "Marcus",
+            &names::with_surname("Marcus"),
             AdventurerClass::Soldier,
             crate::kingdom::Gender::Male,
         ));
         roster.adventurers.push(Adventurer::new(
-            "Elena",
+            &names::with_surname("Elena"),
             AdventurerClass::Scout,
             crate::kingdom::Gender::Female,
         ));
+        // A brother of the order goes by his title, not a family name
         roster.adventurers.push(Adventurer::new(
             "Brother Aldric",
             AdventurerClass::Healer,
@@ -72,16 +92,104 @@ impl Roster {
         if let Some(pos) = self.adventurers.iter().position(|a| a.id == id) {
             let fallen = self.adventurers.remove(pos);
             self.graveyard.push(fallen);
+            self.affinities.retain(|(a, b), _| a != id && b != id);
+        }
+    }
+
+    /// Affinity between two adventurers; strangers start at 0
+    pub fn affinity(&self, a: &str, b: &str) -> i32 {
+        self.affinities.get(&pair_key(a, b)).copied().unwrap_or(0)
+    }
+
+    /// Shift the affinity between two adventurers, returning the new value
+    pub fn adjust_affinity(&mut self, a: &str, b: &str, delta: i32) -> i32 {
+        let value = self.affinities.entry(pair_key(a, b)).or_insert(0);
+        *value = (*value + delta).clamp(-MAX_AFFINITY, MAX_AFFINITY);
+        *value
+    }
+
+    /// How many of `party` are bonded to `id`
+    pub fn bonded_count(&self, id: &str, party: &[&str]) -> usize {
+        party
+            .iter()
+            .filter(|other| **other != id && self.affinity(id, other) >= BONDED_AFFINITY)
+            .count()
+    }
+
+    /// How many of `party` are rivals of `id`
+    pub fn rival_count(&self, id: &str, party: &[&str]) -> usize {
+        party
+            .iter()
+            .filter(|other| **other != id && self.affinity(id, other) <= RIVAL_AFFINITY)
+            .count()
+    }
+
+    /// Shift affinities after a mission: surviving together draws the party closer,
+    /// each fallen partner drives the survivors apart. Returns bonds formed or broken.
+    pub fn record_shared_mission(&mut self, survivors: &[&str], fallen: usize) -> Vec<String> {
+        let delta = SHARED_MISSION_AFFINITY - FALLEN_PARTNER_AFFINITY * fallen as i32;
+        let mut messages = Vec::new();
+        for (i, a) in survivors.iter().enumerate() {
+            for b in &survivors[i + 1..] {
+                let before = self.affinity(a, b);
+                let after = self.adjust_affinity(a, b, delta);
+                let names = self.pair_names(a, b);
+                if before < BONDED_AFFINITY && after >= BONDED_AFFINITY {
+                    messages.push(format!("{} have grown close", names));
+                } else if before > RIVAL_AFFINITY && after <= RIVAL_AFFINITY {
+                    messages.push(format!("{} have become rivals", names));
+                }
+            }
         }
+        messages
+    }
+
+    fn pair_names(&self, a: &str, b: &str) -> String {
+        let name = |id: &str| self.get(id).map_or(id.to_string(), |adv| adv.name.clone());
+        format!("{} and {}", name(a), name(b))
     }
 
-    /// Add a new adventurer (recruited, hired, etc.)
-    pub fn add(&mut self, adventurer: Adventurer) {
+    /// Add a new adventurer (recruited, hired, etc.); false if the roster is full
+    pub fn add(&mut self, adventurer: Adventurer, max_size: usize) -> bool {
+        if self.is_full(max_size) {
+            return false;
+        }
         self.adventurers.push(adventurer);
+        true
+    }
+
+    /// Whether the roster has reached its capacity
+    pub fn is_full(&self, max_size: usize) -> bool {
+        self.adventurers.len() >= max_size
+    }
+
+    /// Send an adventurer away for good
+    pub fn dismiss(&mut self, id: &str) -> Option<Adventurer> {
+        let pos = self.adventurers.iter().position(|a| a.id == id)?;
+        Some(self.adventurers.remove(pos))
+    }
+
+    /// Pass a day at the base: everyone rests off some fatigue and wounds knit, returning
+    /// any trauma recoveries and healed injuries
+    pub fn advance_day(&mut self) -> Vec<String> {
+        let mut messages = Vec::new();
+        for adv in &mut self.adventurers {
+            adv.rest();
+            for injury in adv.heal_injuries_over_day() {
+                messages.push(format!("{}'s {} has healed.", adv.name, injury.name));
+            }
+            if let Some(trauma) = adv.recover_over_day() {
+                messages.push(format!(
+                    "{} has recovered from being {}.",
+                    adv.name,
+                    trauma.name()
+                ));
+            }
+        }
+        messages
     }
 
     /// Count living adventurers
-    #[allow(dead_code)]
     pub fn count(&self) -> usize {
         self.adventurers.len()
     }
@@ -92,3 +200,35 @@ impl Roster {
         self.graveyard.len()
     }
 }
+
+/// Order-independent key for a pair of adventurer ids
+fn pair_key(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+/// JSON object keys must be strings, so affinities are saved as `[a, b, value]` triples
+mod affinity_pairs {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::collections::HashMap;
+
+    pub fn serialize<S: Serializer>(
+        affinities: &HashMap<(String, String), i32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(affinities.iter().map(|((a, b), value)| (a, b, value)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<(String, String), i32>, D::Error> {
+        let triples = Vec::<(String, String, i32)>::deserialize(deserializer)?;
+        Ok(triples
+            .into_iter()
+            .map(|(a, b, value)| ((a, b), value))
+            .collect())
+    }
+}
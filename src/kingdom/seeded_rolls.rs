@@ -0,0 +1,46 @@
+//! Seeded rolls for daily challenge runs - the same seed, day and purpose always roll alike,
+//! whatever the player clicked before
+
+use super::KingdomState;
+
+/// Spread nearby inputs into unrelated seeds (splitmix64 finalizer)
+pub fn mix_seed(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Seed for one roll of a seeded run: the run's seed, the kingdom day, and what is rolled
+fn roll_seed(seed: u64, day: u32, purpose: &str) -> u64 {
+    let purpose = purpose
+        .bytes()
+        .fold(0xCBF2_9CE4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3)
+        });
+    mix_seed(seed ^ mix_seed(day as u64) ^ purpose)
+}
+
+/// Restart the game RNG for a roll of a seeded run, e.g. "recruits" or a mission id, so
+/// the rolls that follow are shared by everyone playing that day. Campaigns are untouched
+pub fn seed_rolls(kingdom: &KingdomState, purpose: &str) {
+    if let Some(seed) = kingdom.seed {
+        macroquad::rand::srand(roll_seed(seed, kingdom.day, purpose));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolls_depend_only_on_seed_day_and_purpose() {
+        assert_eq!(roll_seed(7, 3, "recruits"), roll_seed(7, 3, "recruits"));
+        assert_ne!(roll_seed(7, 3, "recruits"), roll_seed(7, 4, "recruits"));
+        assert_ne!(
+            roll_seed(7, 3, "recruits"),
+            roll_seed(7, 3, "first_mission")
+        );
+        assert_ne!(roll_seed(7, 3, "recruits"), roll_seed(8, 3, "recruits"));
+    }
+}
@@ -1,6 +1,34 @@
 //! Kingdom stats - the core tension system
 
+use super::adventurer::Adventurer;
+use super::difficulty::Difficulty;
+use super::roster::Roster;
+use super::tutorial::TutorialStep;
+use crate::missions::region::Region;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Threat a region gains when an expedition there is abandoned
+pub const RETREAT_REGION_THREAT: i32 = 5;
+/// Adventurers the kingdom can house before any Guild Hall
+const BASE_ROSTER_SIZE: usize = 4;
+/// Extra roster room per Guild Hall level
+const ROSTER_SIZE_PER_GUILD_LEVEL: usize = 2;
+/// Supplies each adventurer on the roster eats per day
+const UPKEEP_PER_ADVENTURER: i32 = 2;
+/// Morale lost on a day the roster's upkeep cannot be met
+const UPKEEP_SHORTFALL_MORALE: i32 = 5;
+/// Threat every region regains each day it is left alone
+const DAILY_REGION_THREAT: i32 = 1;
+/// Stress the day's first Chapel sermon lifts from every adventurer
+const SERMON_BASE_RELIEF: i32 = 15;
+/// Relief lost with each further sermon the same day
+const SERMON_RELIEF_DECAY: i32 = 5;
+
+/// Highest gold, supplies, knowledge, or influence can climb
+pub const STAT_CEILING: i32 = 9_999;
+/// Morale and security run from 0 to this
+pub const PERCENT_STAT_MAX: i32 = 100;
 
 /// Core kingdom stats that pull against each other
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -20,18 +48,89 @@ pub struct KingdomStats {
 }
 
 impl KingdomStats {
-    pub fn new() -> Self {
+    /// Starting resources for a new kingdom; harder presets start leaner
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        let (gold, security, morale, supplies, knowledge, influence) = match difficulty {
+            Difficulty::Easy => (160, 40, 60, 85, 15, 25),
+            Difficulty::Normal => (120, 30, 50, 65, 10, 20),
+            Difficulty::Hard => (90, 25, 45, 50, 5, 15),
+            Difficulty::Nightmare => (60, 20, 40, 35, 0, 10),
+        };
         Self {
-            gold: 120,
-            security: 30,
-            morale: 50,
-            supplies: 65,
-            knowledge: 10,
-            influence: 20,
+            gold,
+            security,
+            morale,
+            supplies,
+            knowledge,
+            influence,
+        }
+    }
+
+    /// Spend gold if the treasury covers it; false leaves gold untouched
+    pub fn spend_gold(&mut self, amount: i32) -> bool {
+        spend(&mut self.gold, amount)
+    }
+
+    /// Spend supplies if the stores cover them; false leaves supplies untouched
+    pub fn spend_supplies(&mut self, amount: i32) -> bool {
+        spend(&mut self.supplies, amount)
+    }
+
+    /// Spend knowledge if enough is banked; false leaves knowledge untouched
+    pub fn spend_knowledge(&mut self, amount: i32) -> bool {
+        spend(&mut self.knowledge, amount)
+    }
+
+    /// Spend gold and supplies together, or neither
+    pub fn spend_gold_and_supplies(&mut self, gold: i32, supplies: i32) -> bool {
+        if gold < 0 || supplies < 0 || self.gold < gold || self.supplies < supplies {
+            return false;
         }
+        self.gold -= gold;
+        self.supplies -= supplies;
+        true
+    }
+
+    /// Gain gold, or lose it with a negative amount, within 0 and `STAT_CEILING`
+    pub fn add_gold(&mut self, amount: i32) {
+        adjust(&mut self.gold, amount, STAT_CEILING);
+    }
+
+    pub fn add_supplies(&mut self, amount: i32) {
+        adjust(&mut self.supplies, amount, STAT_CEILING);
+    }
+
+    pub fn add_knowledge(&mut self, amount: i32) {
+        adjust(&mut self.knowledge, amount, STAT_CEILING);
+    }
+
+    pub fn add_influence(&mut self, amount: i32) {
+        adjust(&mut self.influence, amount, STAT_CEILING);
+    }
+
+    /// Raise or lower morale within 0 and `PERCENT_STAT_MAX`
+    pub fn add_morale(&mut self, amount: i32) {
+        adjust(&mut self.morale, amount, PERCENT_STAT_MAX);
+    }
+
+    /// Raise or lower security within 0 and `PERCENT_STAT_MAX`
+    pub fn add_security(&mut self, amount: i32) {
+        adjust(&mut self.security, amount, PERCENT_STAT_MAX);
     }
 }
 
+fn spend(stat: &mut i32, amount: i32) -> bool {
+    if amount < 0 || *stat < amount {
+        return false;
+    }
+    *stat -= amount;
+    true
+}
+
+fn adjust(stat: &mut i32, amount: i32, max: i32) {
+    *stat = stat.saturating_add(amount).clamp(0, max);
+}
+
 /// Full kingdom state
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct KingdomState {
@@ -50,27 +149,82 @@ pub struct KingdomState {
     /// IDs of completed missions (for unlock requirements)
     #[serde(default)]
     pub completed_missions: Vec<String>,
+    /// Per-region threat and knowledge
+    #[serde(default = "default_regions")]
+    pub regions: Vec<Region>,
+    /// Mission id -> first day it can be attempted again after a retreat
+    #[serde(default)]
+    pub mission_cooldowns: HashMap<String, u32>,
+    /// Day of the most recent Chapel sermon
+    #[serde(default)]
+    pub sermon_day: u32,
+    /// Sermons held on `sermon_day`
+    #[serde(default)]
+    pub sermons_held: u32,
+    /// First-run hint currently shown
+    #[serde(default = "default_tutorial_step")]
+    pub tutorial_step: TutorialStep,
+    /// Preset the kingdom was founded on
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Applicants held back until the treasury can cover their fee
+    #[serde(default)]
+    pub reserved_recruits: Vec<Adventurer>,
+    /// Challenge day this run was seeded from; None for an ordinary campaign
+    #[serde(default)]
+    pub daily_challenge: Option<u64>,
+    /// RNG seed of a daily challenge run; its recruits and mission maps are rolled from
+    /// this and the day (see `seed_rolls`)
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Set once this run's game over has been written to the run history
+    #[serde(default)]
+    pub run_recorded: bool,
 }
 
 fn default_threat_level() -> i32 {
     1
 }
 
+/// Saves from before the tutorial existed belong to players who no longer need it
+fn default_tutorial_step() -> TutorialStep {
+    TutorialStep::Done
+}
+
+fn default_regions() -> Vec<Region> {
+    Region::load_all().unwrap_or_else(|_| vec![Region::dark_woods()])
+}
+
 impl Default for KingdomState {
     fn default() -> Self {
+        Self::for_difficulty(Difficulty::Normal)
+    }
+}
+
+impl KingdomState {
+    /// A freshly founded kingdom on the given preset
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
         Self {
-            stats: KingdomStats::new(),
+            stats: KingdomStats::for_difficulty(difficulty),
             day: 1,
             buildings: crate::kingdom::Building::all_starter(),
             threat_level: default_threat_level(),
             last_event: None,
             game_won: false,
             completed_missions: vec![],
+            regions: default_regions(),
+            mission_cooldowns: HashMap::new(),
+            sermon_day: 0,
+            sermons_held: 0,
+            tutorial_step: TutorialStep::SelectAdventurer,
+            difficulty,
+            reserved_recruits: vec![],
+            daily_challenge: None,
+            seed: None,
+            run_recorded: false,
         }
     }
-}
 
-impl KingdomState {
     /// Add newly introduced buildings to old saves without disturbing existing progress.
     pub fn ensure_current_buildings(&mut self) {
         for building in crate::kingdom::Building::all_starter() {
@@ -90,6 +244,43 @@ impl KingdomState {
             .any(|b| b.id == building_id && b.built)
     }
 
+    /// Level of a built facility; 0 while it stands unbuilt
+    pub fn building_level(&self, building_id: &str) -> i32 {
+        self.buildings
+            .iter()
+            .find(|b| b.id == building_id && b.built)
+            .map_or(0, |b| b.level.max(0))
+    }
+
+    /// How many adventurers the roster can hold; scales with Guild Hall level
+    pub fn max_roster_size(&self) -> usize {
+        let guild_level = self
+            .buildings
+            .iter()
+            .find(|b| b.id == "guild_hall" && b.built)
+            .map_or(0, |b| b.level.max(0) as usize);
+        BASE_ROSTER_SIZE + guild_level * ROSTER_SIZE_PER_GUILD_LEVEL
+    }
+
+    /// Stress the next sermon would lift; each one today is weaker, and a new day resets it
+    pub fn sermon_relief(&self) -> i32 {
+        let held_today = if self.sermon_day == self.day {
+            self.sermons_held as i32
+        } else {
+            0
+        };
+        (SERMON_BASE_RELIEF - held_today * SERMON_RELIEF_DECAY).max(0)
+    }
+
+    /// Count a sermon against today's diminishing returns
+    pub fn record_sermon(&mut self) {
+        if self.sermon_day != self.day {
+            self.sermon_day = self.day;
+            self.sermons_held = 0;
+        }
+        self.sermons_held += 1;
+    }
+
     pub fn threat_difficulty_bonus(&self) -> i32 {
         (self.threat_level / 25).max(0)
     }
@@ -104,6 +295,54 @@ impl KingdomState {
         }
     }
 
+    pub fn region(&self, region_id: &str) -> Option<&Region> {
+        self.regions.iter().find(|r| r.id == region_id)
+    }
+
+    pub fn region_mut(&mut self, region_id: &str) -> Option<&mut Region> {
+        self.regions.iter_mut().find(|r| r.id == region_id)
+    }
+
+    /// True while a mission is still off-limits after a retreat
+    pub fn mission_on_cooldown(&self, mission_id: &str) -> bool {
+        self.mission_cooldowns
+            .get(mission_id)
+            .is_some_and(|ready_day| self.day < *ready_day)
+    }
+
+    /// Abandoning an expedition emboldens its region and closes the contract until tomorrow
+    pub fn record_retreat(&mut self, mission_id: &str, region_id: &str) {
+        if let Some(region) = self.region_mut(region_id) {
+            region.destabilize(RETREAT_REGION_THREAT);
+        }
+        self.mission_cooldowns
+            .insert(mission_id.to_string(), self.day + 1);
+    }
+
+    /// Close the day: the roster eats its upkeep, regions stir, and adventurers rest and
+    /// heal. Returns what happened, for the results screen
+    pub fn advance_day(&mut self, roster: &mut Roster) -> Vec<String> {
+        self.day += 1;
+        let mut messages = Vec::new();
+
+        let upkeep = UPKEEP_PER_ADVENTURER * roster.count() as i32;
+        if self.stats.spend_supplies(upkeep) {
+            messages.push(format!("The roster ate {} supplies.", upkeep));
+        } else {
+            let left = self.stats.supplies;
+            self.stats.spend_supplies(left);
+            self.stats.add_morale(-UPKEEP_SHORTFALL_MORALE);
+            messages.push("Supplies ran out; the roster went hungry and morale fell.".to_string());
+        }
+
+        for region in &mut self.regions {
+            region.destabilize(DAILY_REGION_THREAT);
+        }
+
+        messages.extend(roster.advance_day());
+        messages
+    }
+
     pub fn advance_threat(&mut self, victory: bool) {
         let built_count = self.buildings.iter().filter(|b| b.built).count() as i32;
         let growth = if victory { 3 } else { 8 } + (built_count / 2);
@@ -132,3 +371,90 @@ impl KingdomState {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{AdventurerClass, Gender, Injury};
+
+    #[test]
+    fn a_day_costs_upkeep_stirs_regions_and_knits_wounds() {
+        let mut kingdom = KingdomState::default();
+        kingdom.stats.supplies = 10;
+        let threat = kingdom.regions[0].threat_level;
+        let mut roster = Roster::new();
+        for name in ["Ada", "Bram"] {
+            let mut adv = Adventurer::new(name, AdventurerClass::Scout, Gender::Female);
+            adv.injuries.push(Injury::wounded_leg());
+            roster.adventurers.push(adv);
+        }
+        roster.adventurers[0].injuries[0].healing_days = 1;
+
+        let day = kingdom.day;
+        let messages = kingdom.advance_day(&mut roster);
+        assert_eq!(kingdom.day, day + 1);
+        assert_eq!(kingdom.stats.supplies, 10 - 2 * UPKEEP_PER_ADVENTURER);
+        assert_eq!(
+            kingdom.regions[0].threat_level,
+            threat + DAILY_REGION_THREAT
+        );
+        assert!(roster.adventurers[0].injuries.is_empty());
+        assert_eq!(roster.adventurers[1].injuries[0].healing_days, 2);
+        assert!(messages
+            .iter()
+            .any(|m| m == "Ada's Wounded Leg has healed."));
+    }
+
+    #[test]
+    fn unpaid_upkeep_empties_the_stores_and_costs_morale() {
+        let mut kingdom = KingdomState::default();
+        kingdom.stats.supplies = 1;
+        let morale = kingdom.stats.morale;
+        let mut roster = Roster::new();
+        roster.adventurers.push(Adventurer::new(
+            "Ada",
+            AdventurerClass::Scout,
+            Gender::Female,
+        ));
+        kingdom.advance_day(&mut roster);
+        assert_eq!(kingdom.stats.supplies, 0);
+        assert_eq!(
+            kingdom.stats.morale,
+            (morale - UPKEEP_SHORTFALL_MORALE).max(0)
+        );
+    }
+
+    #[test]
+    fn overspending_fails_without_touching_stats() {
+        let mut stats = KingdomStats::for_difficulty(Difficulty::Normal);
+        let gold = stats.gold;
+        assert!(!stats.spend_gold(gold + 1));
+        assert_eq!(stats.gold, gold);
+        assert!(!stats.spend_gold_and_supplies(1, stats.supplies + 1));
+        assert_eq!(stats.gold, gold);
+        assert!(stats.spend_gold(gold));
+        assert_eq!(stats.gold, 0);
+    }
+
+    #[test]
+    fn gains_and_losses_stay_in_range() {
+        let mut stats = KingdomStats::for_difficulty(Difficulty::Normal);
+        stats.add_morale(500);
+        assert_eq!(stats.morale, PERCENT_STAT_MAX);
+        stats.add_security(-500);
+        assert_eq!(stats.security, 0);
+        stats.add_gold(-500);
+        assert_eq!(stats.gold, 0);
+        stats.add_supplies(i32::MAX);
+        assert_eq!(stats.supplies, STAT_CEILING);
+    }
+
+    #[test]
+    fn nightmare_starts_leaner_than_normal() {
+        let normal = KingdomStats::for_difficulty(Difficulty::Normal);
+        let nightmare = KingdomStats::for_difficulty(Difficulty::Nightmare);
+        assert!(nightmare.gold < normal.gold);
+        assert!(nightmare.supplies < normal.supplies);
+        assert_eq!(KingdomState::default().stats.gold, normal.gold);
+    }
+}
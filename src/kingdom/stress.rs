@@ -0,0 +1,135 @@
+//! Stress ladder - traumas, resolve checks and heart attacks, shared by adventurers at the
+//! base and party members in a fight
+
+use super::{ResolveState, Trauma, TraumaType};
+
+/// What a single stress gain did to an adventurer
+#[derive(Clone, Debug)]
+pub enum StressOutcome {
+    /// No threshold was crossed
+    Steady,
+    /// A new trauma took hold
+    Trauma(Trauma),
+    /// Resolve held at the breaking point: stress reset and a combat buff granted
+    Breakthrough,
+}
+
+/// Everything one stress gain set off; callers add their own consequences on top
+#[derive(Clone, Debug)]
+pub struct StressChange {
+    pub outcome: StressOutcome,
+    /// Stress hit the heart attack line and was knocked back to max stress
+    pub heart_attack: bool,
+    /// The resolve check at max stress failed
+    pub afflicted: bool,
+}
+
+/// HP a heart attack costs
+pub fn heart_attack_damage(max_hp: i32) -> i32 {
+    (max_hp / 2).max(1)
+}
+
+/// Add stress and climb the ladder: a heart attack past its line first, then the resolve
+/// check on reaching max stress, then the worst trauma the new stress calls for
+pub fn gain_stress(
+    stress: &mut i32,
+    traumas: &mut Vec<Trauma>,
+    resolve_state: &mut Option<ResolveState>,
+    amount: i32,
+) -> StressChange {
+    gain_stress_with_roll(stress, traumas, resolve_state, amount, || {
+        macroquad_toolkit::rng::chance(crate::data::balance().resolve_breakthrough_chance)
+    })
+}
+
+fn gain_stress_with_roll(
+    stress: &mut i32,
+    traumas: &mut Vec<Trauma>,
+    resolve_state: &mut Option<ResolveState>,
+    amount: i32,
+    breakthrough_roll: impl FnOnce() -> bool,
+) -> StressChange {
+    let balance = crate::data::balance();
+    let before = *stress;
+    *stress = (*stress + amount).max(0);
+    let mut change = StressChange {
+        outcome: StressOutcome::Steady,
+        heart_attack: false,
+        afflicted: false,
+    };
+    if amount <= 0 {
+        return change;
+    }
+
+    // A spike past the heart attack line strikes before any resolve roll can save them
+    if *stress >= balance.heart_attack_stress {
+        *stress = balance.max_stress;
+        change.heart_attack = true;
+    }
+
+    if before < balance.max_stress && *stress >= balance.max_stress && resolve_state.is_none() {
+        if breakthrough_roll() {
+            *resolve_state = Some(ResolveState::Virtuous);
+            *stress = balance.breakthrough_stress;
+            change.outcome = StressOutcome::Breakthrough;
+            return change;
+        }
+        *resolve_state = Some(ResolveState::Afflicted);
+        *stress = balance.max_stress;
+        change.afflicted = true;
+    }
+
+    let ladder = [
+        (balance.max_stress, TraumaType::Broken),
+        (balance.paranoid_stress, TraumaType::Paranoid),
+        (balance.fearful_stress, TraumaType::Fearful),
+    ];
+    if let Some((_, trauma_type)) = ladder.into_iter().find(|(threshold, trauma_type)| {
+        *stress >= *threshold && !traumas.iter().any(|t| t.trauma_type == *trauma_type)
+    }) {
+        let trauma = Trauma::new(trauma_type);
+        traumas.push(trauma.clone());
+        change.outcome = StressOutcome::Trauma(trauma);
+    }
+    change
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_resolve_check_reports_broken() {
+        let balance = crate::data::balance();
+        let mut stress = balance.max_stress - 1;
+        let mut traumas = vec![];
+        let mut resolve = None;
+        let change = gain_stress_with_roll(&mut stress, &mut traumas, &mut resolve, 5, || false);
+        assert!(change.afflicted);
+        assert!(matches!(
+            change.outcome,
+            StressOutcome::Trauma(ref trauma) if trauma.trauma_type == TraumaType::Broken
+        ));
+        assert_eq!(resolve, Some(ResolveState::Afflicted));
+        assert_eq!(stress, balance.max_stress);
+    }
+
+    #[test]
+    fn heart_attack_lands_even_when_resolve_holds() {
+        let balance = crate::data::balance();
+        let mut stress = balance.max_stress - 1;
+        let mut traumas = vec![];
+        let mut resolve = None;
+        let change = gain_stress_with_roll(
+            &mut stress,
+            &mut traumas,
+            &mut resolve,
+            balance.heart_attack_stress,
+            || true,
+        );
+        assert!(change.heart_attack);
+        assert!(matches!(change.outcome, StressOutcome::Breakthrough));
+        assert_eq!(stress, balance.breakthrough_stress);
+        assert!(traumas.is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+//! First-run tutorial progress - one hint per step of the first expedition
+
+use serde::{Deserialize, Serialize};
+
+/// How far a new player has come through their first expedition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TutorialStep {
+    /// Pick someone on the base screen and start forming a party
+    SelectAdventurer,
+    /// Fill out the party and head for the mission board
+    FormParty,
+    /// Choose a mission and embark through the briefing
+    ChooseMission,
+    /// Walk the mission map
+    Travel,
+    /// First fight
+    Fight,
+    /// Read the results and return home
+    Debrief,
+    /// Finished or dismissed; no more hints
+    Done,
+}
+
+impl TutorialStep {
+    /// Hint shown while this step is current
+    pub fn hint(self) -> &'static str {
+        match self {
+            TutorialStep::SelectAdventurer => {
+                "Select an adventurer with 1-9, then press M to form a party. Tab switches tabs."
+            }
+            TutorialStep::FormParty => {
+                "Add members with 1-9 (Shift moves one forward), then Enter to pick a mission."
+            }
+            TutorialStep::ChooseMission => {
+                "Up/Down picks a mission. Enter opens the briefing; Enter again embarks."
+            }
+            TutorialStep::Travel => {
+                "Left/Right picks the next node, Enter or Space travels. Space advances alone."
+            }
+            TutorialStep::Fight => {
+                "1-5 selects a card, Enter plays it. E ends this member's turn, P the party's."
+            }
+            TutorialStep::Debrief => {
+                "Rewards, stress, and losses land here. Enter returns to the kingdom."
+            }
+            TutorialStep::Done => "",
+        }
+    }
+
+    pub fn is_done(self) -> bool {
+        self == TutorialStep::Done
+    }
+}
@@ -50,3 +50,25 @@ impl UnlockRequirement {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::missions::Mission;
+
+    #[test]
+    fn completing_a_mission_unlocks_the_ones_gated_on_it() {
+        let missions = crate::load_asset!("missions.json", Vec<Mission>).unwrap();
+        let clear_outpost = missions.iter().find(|m| m.id == "clear_outpost").unwrap();
+        let mut kingdom = KingdomState::default();
+        assert!(!clear_outpost.unlock_requirement.is_met(&kingdom));
+
+        kingdom.record_mission_complete("scout_outpost");
+        kingdom.record_mission_complete("scout_outpost");
+        assert_eq!(
+            kingdom.completed_missions,
+            vec!["scout_outpost".to_string()]
+        );
+        assert!(clear_outpost.unlock_requirement.is_met(&kingdom));
+    }
+}
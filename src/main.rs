@@ -30,11 +30,17 @@ fn window_conf() -> Conf {
 
 #[macroquad::main(window_conf)]
 async fn main() {
-    let mut game = Game::new().await;
+    // `--daily` plays today's shared seeded run instead of the saved campaign
+    let mut game = if std::env::args().any(|arg| arg == "--daily") {
+        Game::new_daily().await
+    } else {
+        Game::new().await
+    };
 
     loop {
         clear_background(Color::from_rgba(20, 20, 25, 255));
 
+        game.update_loading().await;
         game.update();
         game.draw();
 
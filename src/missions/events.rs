@@ -37,6 +37,10 @@ pub enum EventOutcome {
     RevealTrait,
     /// Skip the next node
     SkipNode,
+    /// Gain or lose kingdom gold (kingdom events)
+    Gold(i32),
+    /// Shift kingdom morale (kingdom events)
+    Morale(i32),
     /// Nothing happens
     Nothing,
 }
@@ -47,7 +51,7 @@ impl Event {
         Self {
             id: "twisted_path".to_string(),
             title: "The Twisted Path".to_string(),
-            description: "The trail splits. One path is overgrown but direct. The other is clear but winds deeper into the forest.".to_string(),
+            description: "The trail splits. One path is overgrown but direct. The other is clear but winds deeper into the forest. Your leader looks to the party, the choice {theirs} to make.".to_string(),
             choices: vec![
                 EventChoice {
                     text: "Take the overgrown path (+5 stress, skip node)".to_string(),
@@ -70,7 +74,7 @@ impl Event {
         Self {
             id: "ancient_marker".to_string(),
             title: "Ancient Marker".to_string(),
-            description: "A weathered stone marker stands at the crossroads. Strange symbols cover its surface.".to_string(),
+            description: "A weathered stone marker stands at the crossroads. Strange symbols cover its surface, and your leader cannot shake the feeling they are watching {them}.".to_string(),
             choices: vec![
                 EventChoice {
                     text: "Study the marker (+5 stress, reveal trait)".to_string(),
@@ -89,7 +93,7 @@ impl Event {
         Self {
             id: "forest_shrine".to_string(),
             title: "Forest Shrine".to_string(),
-            description: "A small shrine to forgotten gods. The air feels calmer here.".to_string(),
+            description: "A small shrine to forgotten gods. The air feels calmer here, and your leader lowers {their} guard for the first time in days.".to_string(),
             choices: vec![
                 EventChoice {
                     text: "Rest briefly (-10 stress)".to_string(),
@@ -19,11 +19,18 @@ pub enum MissionType {
     Investigate,
 }
 
+/// Odds that a mid-route combat node is upgraded to the expedition's elite
+const ELITE_CHANCE: f32 = 0.35;
+/// Extra enemy difficulty for elite nodes
+const ELITE_DIFFICULTY_BONUS: i32 = 1;
+
 /// Type of encounter at a mission node
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum NodeType {
     /// Combat encounter
     Combat,
+    /// Tougher-than-normal combat with better spoils (at most one per expedition)
+    Elite,
     /// Narrative event with choices
     Event,
     /// Safe rest point
@@ -33,7 +40,7 @@ pub enum NodeType {
 }
 
 /// A node in a mission map (supports branching paths)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MapNode {
     /// Unique ID within this mission
     pub id: usize,
@@ -45,6 +52,8 @@ pub struct MapNode {
     pub layer: usize,
     /// Position within the layer (0 = left, higher = right)
     pub position: usize,
+    /// Enemy rolled for fight nodes when the map is generated
+    pub enemy_id: Option<String>,
 }
 
 /// A mission available to undertake
@@ -75,6 +84,13 @@ pub struct Mission {
     /// Requirement to unlock this mission
     #[serde(default)]
     pub unlock_requirement: UnlockRequirement,
+
+    /// Added to each member's energy per turn in this mission's fights
+    #[serde(default)]
+    pub energy_modifier: i32,
+    /// Added to the hand drawn each turn in this mission's fights; harder missions may draw fewer
+    #[serde(default)]
+    pub hand_size_modifier: i32,
 }
 
 fn default_reward_gold() -> i32 {
@@ -98,6 +114,8 @@ impl Mission {
             reward_influence: 0,
             base_stress: 8,
             unlock_requirement: UnlockRequirement::None,
+            energy_modifier: 0,
+            hand_size_modifier: 0,
         }
     }
 
@@ -117,6 +135,8 @@ impl Mission {
             reward_influence: 10,
             base_stress: 15,
             unlock_requirement: UnlockRequirement::None,
+            energy_modifier: 0,
+            hand_size_modifier: 0,
         }
     }
 
@@ -182,6 +202,14 @@ impl Mission {
         }
     }
 
+    /// Enemy difficulty for a fight at the given node type.
+    pub fn node_difficulty(&self, node_type: &NodeType) -> i32 {
+        match node_type {
+            NodeType::Elite => self.combat_difficulty() + ELITE_DIFFICULTY_BONUS,
+            _ => self.combat_difficulty(),
+        }
+    }
+
     /// Clone this mission with global threat pressure applied.
     pub fn scaled_for_kingdom(&self, kingdom: &crate::kingdom::KingdomState) -> Self {
         let mut mission = self.clone();
@@ -207,6 +235,8 @@ impl Mission {
 
         // Track node indices at each layer for connecting
         let mut layer_nodes: Vec<Vec<usize>> = Vec::new();
+        let mut elite_placed = false;
+        let enemies = crate::data::enemies::EnemyData::load_all().unwrap_or_default();
 
         for layer in 0..num_layers {
             // Determine how many nodes in this layer
@@ -234,7 +264,15 @@ impl Mission {
                     // Random based on mission type
                     let roll: f32 = macroquad_toolkit::rng::rand();
                     if roll < combat_chance {
-                        NodeType::Combat
+                        if !elite_placed
+                            && layer >= 2
+                            && macroquad_toolkit::rng::chance(ELITE_CHANCE)
+                        {
+                            elite_placed = true;
+                            NodeType::Elite
+                        } else {
+                            NodeType::Combat
+                        }
                     } else if layer % 3 == 0 && macroquad_toolkit::rng::chance(0.3) {
                         NodeType::Rest
                     } else {
@@ -242,12 +280,22 @@ impl Mission {
                     }
                 };
 
+                // Fights are decided now so the encounter can be previewed
+                let enemy_id = match node_type {
+                    NodeType::Combat | NodeType::Elite | NodeType::Boss => {
+                        let difficulty = self.node_difficulty(&node_type);
+                        crate::data::roll_enemy_id(&enemies, &self.region_id, difficulty)
+                    }
+                    NodeType::Event | NodeType::Rest => None,
+                };
+
                 let node = MapNode {
                     id: node_id,
                     node_type,
                     connections: Vec::new(), // Will be filled in next pass
                     layer,
                     position: pos,
+                    enemy_id,
                 };
 
                 layer_node_indices.push(node_id);
@@ -304,7 +352,12 @@ impl Mission {
 /// Load missions from the JSON asset file
 pub fn load_missions() -> Vec<Mission> {
     match crate::load_asset!("missions.json", Vec<Mission>) {
-        Ok(missions) => missions,
+        Ok(missions) => {
+            for warning in super::rewards::reward_warnings(&missions) {
+                eprintln!("Warning: missions.json: {}", warning);
+            }
+            missions
+        }
         Err(e) => {
             eprintln!("Warning: Could not load missions.json: {}", e);
             // Fallback to hardcoded missions
@@ -3,6 +3,8 @@
 pub mod events;
 pub mod mission;
 pub mod region;
+pub mod rewards;
+pub mod victory;
 
 pub use mission::{load_missions, MapNode, Mission, MissionType, NodeType};
 // Region and events are used internally via full paths
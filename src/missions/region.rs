@@ -22,18 +22,26 @@ pub struct Region {
     pub unlocked: bool,
 
     /// Has the first expedition been completed?
+    #[serde(default)]
     pub discovered: bool,
 
     /// The defining horror or faction
     pub faction: String,
 
     /// Unknown traits revealed through exploration
+    #[serde(default)]
     pub traits_revealed: Vec<String>,
+    #[serde(default, alias = "traits")]
     pub traits_hidden: Vec<String>,
 }
 
 #[allow(dead_code)]
 impl Region {
+    /// Load all regions from the regions.json asset file
+    pub fn load_all() -> Result<Vec<Region>, String> {
+        crate::load_asset!("regions.json", Vec<Region>)
+    }
+
     /// Create the starting Dark Woods region
     pub fn dark_woods() -> Self {
         Self {
@@ -0,0 +1,101 @@
+//! Reward curve - what a mission of a given type and difficulty should pay
+//!
+//! Authored missions are checked against the curve when they load, so the economy
+//! stays coherent as content grows.
+
+use super::{Mission, MissionType};
+
+/// Total reward value at difficulty 0, and what each difficulty step adds
+const BASE_VALUE: i32 = 20;
+const VALUE_PER_DIFFICULTY: i32 = 30;
+
+/// How far (beyond the curve value itself, with this floor) a single reward may stray
+const COMPONENT_SLACK: i32 = 10;
+
+/// Share of the total value (percent) paid as supplies, knowledge, influence and gold
+fn reward_weights(mission_type: &MissionType) -> (i32, i32, i32, i32) {
+    match mission_type {
+        MissionType::Scout => (20, 30, 5, 45),
+        MissionType::Suppress => (25, 5, 15, 55),
+        MissionType::Secure => (30, 5, 20, 45),
+        MissionType::Investigate => (5, 35, 10, 50),
+    }
+}
+
+/// Expected (supplies, knowledge, influence, gold) for a mission on the curve
+pub fn scale_rewards(difficulty: i32, mission_type: MissionType) -> (i32, i32, i32, i32) {
+    let value = BASE_VALUE + VALUE_PER_DIFFICULTY * difficulty.max(1);
+    let (supplies, knowledge, influence, gold) = reward_weights(&mission_type);
+    (
+        value * supplies / 100,
+        value * knowledge / 100,
+        value * influence / 100,
+        value * gold / 100,
+    )
+}
+
+/// Warnings for authored missions whose rewards stray wildly from the curve: a total
+/// under half or over double the expected value, or any one reward far above it
+pub fn reward_warnings(missions: &[Mission]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for mission in missions {
+        let (supplies, knowledge, influence, gold) =
+            scale_rewards(mission.difficulty, mission.mission_type.clone());
+        let expected_total = supplies + knowledge + influence + gold;
+        let total = mission.reward_supplies
+            + mission.reward_knowledge
+            + mission.reward_influence
+            + mission.reward_gold;
+        if total * 2 < expected_total || total > expected_total * 2 {
+            warnings.push(format!(
+                "{} pays {} in total; the curve expects about {}",
+                mission.id, total, expected_total
+            ));
+        }
+
+        for (label, authored, expected) in [
+            ("supplies", mission.reward_supplies, supplies),
+            ("knowledge", mission.reward_knowledge, knowledge),
+            ("influence", mission.reward_influence, influence),
+            ("gold", mission.reward_gold, gold),
+        ] {
+            if authored - expected > expected.max(COMPONENT_SLACK) {
+                warnings.push(format!(
+                    "{} pays {} {}; the curve expects about {}",
+                    mission.id, authored, label, expected
+                ));
+            }
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewards_grow_with_difficulty() {
+        let easy = scale_rewards(1, MissionType::Scout);
+        let hard = scale_rewards(3, MissionType::Scout);
+        assert!(hard.0 > easy.0 && hard.1 > easy.1 && hard.3 > easy.3);
+        assert_eq!(scale_rewards(0, MissionType::Scout), easy);
+    }
+
+    #[test]
+    fn authored_missions_follow_the_curve() {
+        let missions = crate::load_asset!("missions.json", Vec<Mission>).unwrap();
+        assert_eq!(reward_warnings(&missions), Vec::<String>::new());
+    }
+
+    #[test]
+    fn wild_rewards_are_flagged() {
+        let mission = Mission {
+            reward_gold: 500,
+            ..Mission::first_mission()
+        };
+        let warnings = reward_warnings(&[mission]);
+        assert!(warnings.iter().any(|w| w.contains("500 gold")));
+        assert!(warnings.iter().any(|w| w.contains("in total")));
+    }
+}
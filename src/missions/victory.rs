@@ -0,0 +1,179 @@
+//! Victory conditions - what each mission type asks of the party beyond reaching the end
+
+use super::{MapNode, Mission, MissionType, NodeType};
+use crate::kingdom::PartyMemberState;
+
+/// What a mission needs for full success; falling short still wins, but only in part
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VictoryCondition {
+    /// Reaching the final node is enough
+    ReachEnd,
+    /// Slay the boss and win this many other fights on the way
+    SlayBoss { fights: usize },
+    /// Chart this many events or rest sites between the first and last layers
+    Explore { sites: usize },
+    /// Bring the leader home alive
+    LeaderSurvives,
+}
+
+impl VictoryCondition {
+    /// Objective line for the mission map header
+    pub fn describe(&self) -> String {
+        match self {
+            VictoryCondition::ReachEnd => "Reach the end of the route".to_string(),
+            VictoryCondition::SlayBoss { fights } => {
+                format!("Slay the boss and win {} other fight(s)", fights)
+            }
+            VictoryCondition::Explore { sites } => {
+                format!("Chart {} event or rest site(s) along the way", sites)
+            }
+            VictoryCondition::LeaderSurvives => "Bring the leader home alive".to_string(),
+        }
+    }
+
+    /// Why the condition was not met by the nodes visited and the party's final states;
+    /// None on full success
+    pub fn shortfall(&self, visited: &[&MapNode], party: &[PartyMemberState]) -> Option<String> {
+        let last_layer = visited.iter().map(|node| node.layer).max().unwrap_or(0);
+        let count = |wanted: &[NodeType]| {
+            visited
+                .iter()
+                .filter(|node| node.layer > 0 && node.layer < last_layer)
+                .filter(|node| wanted.contains(&node.node_type))
+                .count()
+        };
+        match self {
+            VictoryCondition::ReachEnd => None,
+            VictoryCondition::SlayBoss { fights } => {
+                let boss_slain = visited.iter().any(|node| node.node_type == NodeType::Boss);
+                let won = count(&[NodeType::Combat, NodeType::Elite]);
+                if !boss_slain {
+                    Some("The boss was never faced".to_string())
+                } else if won < *fights {
+                    Some(format!("Only {} of {} fights were won", won, fights))
+                } else {
+                    None
+                }
+            }
+            VictoryCondition::Explore { sites } => {
+                let charted = count(&[NodeType::Event, NodeType::Rest]);
+                (charted < *sites)
+                    .then(|| format!("Only {} of {} sites were charted", charted, sites))
+            }
+            VictoryCondition::LeaderSurvives => party
+                .first()
+                .filter(|leader| leader.hp <= 0)
+                .map(|leader| format!("{} did not come home", leader.name)),
+        }
+    }
+}
+
+impl Mission {
+    /// Victory condition for this mission's type; longer routes ask for more
+    pub fn victory_condition(&self) -> VictoryCondition {
+        let middle_layers = self.length.saturating_sub(2);
+        match self.mission_type {
+            MissionType::Suppress => VictoryCondition::SlayBoss {
+                fights: (middle_layers / 2).max(1),
+            },
+            MissionType::Scout => VictoryCondition::Explore {
+                sites: (middle_layers / 2).max(1),
+            },
+            MissionType::Secure => VictoryCondition::LeaderSurvives,
+            MissionType::Investigate => VictoryCondition::ReachEnd,
+        }
+    }
+
+    /// Regional threat a won mission removes; fighting and holding ground steady a region
+    pub fn stabilization(&self) -> i32 {
+        match self.mission_type {
+            MissionType::Suppress => 10,
+            MissionType::Secure => 8,
+            MissionType::Scout | MissionType::Investigate => 0,
+        }
+    }
+
+    /// Check this mission's victory condition against a finished route
+    pub fn victory_shortfall(
+        &self,
+        map_nodes: &[MapNode],
+        visited_nodes: &[usize],
+        party: &[PartyMemberState],
+    ) -> Option<String> {
+        let visited: Vec<&MapNode> = map_nodes
+            .iter()
+            .filter(|node| visited_nodes.contains(&node.id))
+            .collect();
+        self.victory_condition().shortfall(&visited, party)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: usize, layer: usize, node_type: NodeType) -> MapNode {
+        MapNode {
+            id,
+            node_type,
+            connections: vec![],
+            layer,
+            position: 0,
+            enemy_id: None,
+        }
+    }
+
+    fn route(types: &[NodeType]) -> (Vec<MapNode>, Vec<usize>) {
+        let nodes: Vec<MapNode> = types
+            .iter()
+            .enumerate()
+            .map(|(i, node_type)| node(i, i, node_type.clone()))
+            .collect();
+        let visited = (0..nodes.len()).collect();
+        (nodes, visited)
+    }
+
+    #[test]
+    fn suppress_needs_fights_beyond_the_boss() {
+        let mission = Mission::suppress_beasts();
+        assert_eq!(
+            mission.victory_condition(),
+            VictoryCondition::SlayBoss { fights: 2 }
+        );
+        let (nodes, visited) = route(&[
+            NodeType::Event,
+            NodeType::Combat,
+            NodeType::Rest,
+            NodeType::Event,
+            NodeType::Elite,
+            NodeType::Boss,
+        ]);
+        assert!(mission.victory_shortfall(&nodes, &visited, &[]).is_none());
+        let (nodes, visited) = route(&[
+            NodeType::Event,
+            NodeType::Combat,
+            NodeType::Rest,
+            NodeType::Event,
+            NodeType::Event,
+            NodeType::Boss,
+        ]);
+        assert!(mission.victory_shortfall(&nodes, &visited, &[]).is_some());
+    }
+
+    #[test]
+    fn scouts_only_count_sites_between_the_ends() {
+        let mission = Mission::first_mission();
+        assert_eq!(
+            mission.victory_condition(),
+            VictoryCondition::Explore { sites: 1 }
+        );
+        let (nodes, visited) = route(&[
+            NodeType::Event,
+            NodeType::Combat,
+            NodeType::Combat,
+            NodeType::Combat,
+            NodeType::Event,
+        ]);
+        assert!(mission.victory_shortfall(&nodes, &visited, &[]).is_some());
+    }
+}
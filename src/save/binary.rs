@@ -0,0 +1,102 @@
+//! Compressed binary save format
+//!
+//! A magic header followed by deflate-compressed JSON. Keeps the serde
+//! plumbing of the JSON format while shrinking large rosters and graveyards.
+
+use super::SaveData;
+
+/// Header marking a binary save; JSON saves always start with `{`
+pub const MAGIC: &[u8; 4] = b"FKSB";
+/// Binary layout version, bumped if the framing changes
+const FORMAT_VERSION: u8 = 1;
+/// Deflate level - saves are small, so favour size over speed
+const COMPRESSION_LEVEL: u8 = 9;
+
+/// Whether the bytes carry the binary save header
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Encode a save as header + compressed JSON
+pub fn encode(save: &SaveData) -> Result<Vec<u8>, String> {
+    let json = serde_json::to_vec(save).map_err(|e| format!("Encode failed: {}", e))?;
+    let mut bytes = MAGIC.to_vec();
+    bytes.push(FORMAT_VERSION);
+    bytes.extend(miniz_oxide::deflate::compress_to_vec(
+        &json,
+        COMPRESSION_LEVEL,
+    ));
+    Ok(bytes)
+}
+
+/// Decode bytes produced by `encode` into raw save JSON, left for `SaveData::from_value`
+/// to version-check and migrate
+pub fn decode(bytes: &[u8]) -> Result<serde_json::Value, String> {
+    if !is_binary(bytes) {
+        return Err("Missing binary save header".to_string());
+    }
+    match bytes.get(MAGIC.len()) {
+        Some(&FORMAT_VERSION) => {}
+        Some(version) => return Err(format!("Unknown binary save format {}", version)),
+        None => return Err("Truncated binary save".to_string()),
+    }
+
+    let json = miniz_oxide::inflate::decompress_to_vec(&bytes[MAGIC.len() + 1..])
+        .map_err(|e| format!("Corrupt binary save: {:?}", e))?;
+    serde_json::from_slice(&json).map_err(|e| format!("Corrupt binary save: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{KingdomState, Roster};
+
+    #[test]
+    fn binary_round_trip_is_smaller_than_json() {
+        let kingdom = KingdomState {
+            day: 12,
+            ..Default::default()
+        };
+        let save = SaveData::new(kingdom, Roster::default());
+        let bytes = encode(&save).unwrap();
+        assert!(is_binary(&bytes));
+        assert!(bytes.len() < save.export_string().unwrap().len());
+        assert_eq!(decode(&bytes).unwrap()["kingdom"]["day"], 12);
+    }
+
+    #[test]
+    fn detects_format_from_header() {
+        let save = SaveData::new(KingdomState::default(), Roster::default());
+        let json = save.export_string().unwrap();
+        let bytes = encode(&save).unwrap();
+        assert!(SaveData::from_bytes(json.as_bytes()).is_ok());
+        assert!(SaveData::from_bytes(&bytes).is_ok());
+        assert!(decode(json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn binary_setting_picks_the_save_format() {
+        let save = SaveData::new(KingdomState::default(), Roster::default());
+        assert!(is_binary(&save.to_bytes(true).unwrap()));
+        assert!(!is_binary(&save.to_bytes(false).unwrap()));
+    }
+
+    #[test]
+    fn older_binary_saves_are_migrated() {
+        let mut raw =
+            serde_json::to_value(SaveData::new(KingdomState::default(), Roster::default()))
+                .unwrap();
+        raw["version"] = serde_json::json!(1);
+        raw.as_object_mut().unwrap().remove("in_progress");
+        let json = serde_json::to_vec(&raw).unwrap();
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(FORMAT_VERSION);
+        bytes.extend(miniz_oxide::deflate::compress_to_vec(
+            &json,
+            COMPRESSION_LEVEL,
+        ));
+
+        let save = SaveData::from_bytes(&bytes).expect("v1 binary save should migrate");
+        assert_eq!(save.version, crate::save::SAVE_VERSION);
+    }
+}
@@ -0,0 +1,94 @@
+//! Daily challenge scores - a local record of how each day's shared run went
+
+use macroquad_toolkit::persistence::{load_json_key, save_json_key};
+use serde::{Deserialize, Serialize};
+
+use super::GAME_NAME;
+
+const LEADERBOARD_FILE_NAME: &str = "frontier_kingdom_daily_scores.json";
+/// Scores kept; the oldest days are dropped first
+const MAX_SCORES: usize = 60;
+
+/// How far one daily challenge run got
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyScore {
+    /// Days since the Unix epoch the challenge was seeded from
+    pub challenge_day: u64,
+    pub days_survived: u32,
+    pub missions_won: u32,
+}
+
+impl DailyScore {
+    /// Survival counts first, expeditions break ties
+    fn rank(&self) -> (u32, u32) {
+        (self.days_survived, self.missions_won)
+    }
+}
+
+/// Every recorded daily run, oldest first
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Leaderboard {
+    #[serde(default)]
+    pub scores: Vec<DailyScore>,
+}
+
+impl Leaderboard {
+    /// Load recorded scores, starting empty if none are readable
+    pub fn load() -> Self {
+        load_json_key(GAME_NAME, LEADERBOARD_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        save_json_key(GAME_NAME, LEADERBOARD_FILE_NAME, self)
+    }
+
+    /// Add a finished run, forgetting the oldest once the board is full
+    pub fn record(&mut self, score: DailyScore) {
+        self.scores.push(score);
+        if self.scores.len() > MAX_SCORES {
+            let excess = self.scores.len() - MAX_SCORES;
+            self.scores.drain(..excess);
+        }
+    }
+
+    /// Best run recorded for the given challenge day
+    pub fn best_for(&self, challenge_day: u64) -> Option<&DailyScore> {
+        self.scores
+            .iter()
+            .filter(|s| s.challenge_day == challenge_day)
+            .max_by_key(|s| s.rank())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(challenge_day: u64, days_survived: u32, missions_won: u32) -> DailyScore {
+        DailyScore {
+            challenge_day,
+            days_survived,
+            missions_won,
+        }
+    }
+
+    #[test]
+    fn best_score_is_picked_from_the_same_day_only() {
+        let mut board = Leaderboard::default();
+        board.record(score(10, 4, 1));
+        board.record(score(10, 4, 3));
+        board.record(score(11, 30, 9));
+        assert_eq!(board.best_for(10), Some(&score(10, 4, 3)));
+        assert_eq!(board.best_for(12), None);
+    }
+
+    #[test]
+    fn oldest_scores_are_dropped_when_full() {
+        let mut board = Leaderboard::default();
+        for day in 0..(MAX_SCORES as u64 + 5) {
+            board.record(score(day, 1, 0));
+        }
+        assert_eq!(board.scores.len(), MAX_SCORES);
+        assert_eq!(board.scores[0].challenge_day, 5);
+    }
+}
@@ -0,0 +1,65 @@
+//! Save migration - upgrade older save files one version at a time
+//!
+//! Each step rewrites the raw JSON from one version to the next, so a save from any
+//! older build reaches the current layout before it is deserialized.
+
+use super::{SaveData, SAVE_VERSION};
+use serde_json::{json, Value};
+
+/// One upgrade step: the version it reads, and the rewrite to the version after it
+type Migration = (u32, fn(&mut Value) -> Result<(), String>);
+
+/// Steps in version order. Version 2 added in-mission saves; a version 1 save lacks
+/// `in_progress`, which `#[serde(default)]` reads as a save made at the base, so no
+/// rewrite is needed and version 1 saves are only restamped
+const MIGRATIONS: [Migration; 0] = [];
+
+/// Upgrade a raw save written at `from_version` to the current version and parse it
+pub fn migrate(mut raw: Value, from_version: u32) -> Result<SaveData, String> {
+    if from_version > SAVE_VERSION {
+        return Err(format!(
+            "Save file version {} is newer than supported version {}",
+            from_version, SAVE_VERSION
+        ));
+    }
+    for (version, step) in MIGRATIONS {
+        if version >= from_version {
+            step(&mut raw)?;
+        }
+    }
+    if let Some(save) = raw.as_object_mut() {
+        save.insert("version".to_string(), json!(SAVE_VERSION));
+    }
+    serde_json::from_value(raw).map_err(|e| format!("Not a valid save: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{KingdomState, Roster};
+
+    /// A current save stripped back to what a version 1 build wrote
+    fn v1_save() -> Value {
+        let kingdom = KingdomState {
+            day: 12,
+            ..Default::default()
+        };
+        let mut raw = serde_json::to_value(SaveData::new(kingdom, Roster::default())).unwrap();
+        raw["version"] = json!(1);
+        raw.as_object_mut().unwrap().remove("in_progress");
+        raw
+    }
+
+    #[test]
+    fn version_one_saves_load_into_the_current_layout() {
+        let save = migrate(v1_save(), 1).expect("v1 save should migrate");
+        assert_eq!(save.version, SAVE_VERSION);
+        assert_eq!(save.kingdom.day, 12);
+        assert!(save.in_progress.is_none());
+    }
+
+    #[test]
+    fn newer_saves_are_refused() {
+        assert!(migrate(v1_save(), SAVE_VERSION + 1).is_err());
+    }
+}
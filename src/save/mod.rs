@@ -1,15 +1,38 @@
 //! Save and load system
 //!
-//! Human-readable JSON saves with version tracking.
+//! Human-readable JSON saves with version tracking, or compressed binary ones when chosen
+//! in settings and built with the `binary-saves` feature.
 
-use macroquad_toolkit::persistence::{json_key_exists, load_json_key, save_json_key};
+use macroquad_toolkit::persistence::json_key_exists;
+#[cfg(target_arch = "wasm32")]
+use macroquad_toolkit::persistence::{load_json_key, save_json_key};
 use serde::{Deserialize, Serialize};
 
 use crate::kingdom::{KingdomState, Roster};
+use crate::state::InProgress;
 
-/// Version for save file compatibility
-const SAVE_VERSION: u32 = 1;
+#[cfg(feature = "binary-saves")]
+mod binary;
+mod leaderboard;
+mod migrate;
+mod run_history;
+mod settings;
+
+pub use leaderboard::{DailyScore, Leaderboard};
+pub use run_history::{RunHistory, RunRecord};
+pub use settings::Settings;
+
+use migrate::migrate;
+
+/// Version for save file compatibility; older saves are upgraded by `migrate`
+const SAVE_VERSION: u32 = 2;
 const SAVE_FILE_NAME: &str = "frontier_kingdom_save.json";
+/// Last good save, kept so a failed write or corrupt primary is recoverable
+const BACKUP_FILE_NAME: &str = "frontier_kingdom_save.json.bak";
+/// Staging slot written and verified before the primary is replaced
+const TEMP_FILE_NAME: &str = "frontier_kingdom_save.json.tmp";
+/// Slot written after every mission result, apart from manual saves
+const AUTOSAVE_FILE_NAME: &str = "frontier_kingdom_autosave.json";
 const GAME_NAME: &str = "frontier_kingdom";
 
 /// Complete save data structure
@@ -23,6 +46,12 @@ pub struct SaveData {
     pub regions_explored: Vec<String>,
     pub total_missions: u32,
     pub total_deaths: u32,
+    /// Mission, event, or fight the save was made in; None for a save at the base
+    #[serde(default)]
+    pub in_progress: Option<InProgress>,
+    /// When the save was written, in seconds since the Unix epoch
+    #[serde(default)]
+    pub saved_at: u64,
 }
 
 // Enable toolkit persistence methods
@@ -39,32 +68,201 @@ impl SaveData {
             regions_explored: vec![],
             total_missions: 0,
             total_deaths: 0,
+            in_progress: None,
+            saved_at: macroquad::miniquad::date::now() as u64,
         }
     }
 
-    /// Save to a file
+    /// Save to a file, keeping the previous save as a backup. The new save is staged in a
+    /// temp file and renamed over the primary, so a crash mid-write never leaves it half written
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn save(&self, _path: &str) -> Result<(), String> {
-        save_json_key(GAME_NAME, SAVE_FILE_NAME, self)
+        let temp = slot_path(TEMP_FILE_NAME)?;
+        write_file(&temp, &self.to_bytes(Settings::load().binary_saves)?)?;
+        Self::from_value(raw_save(&read_file(&temp)?)?)?;
+
+        // Only a readable primary replaces the backup
+        let primary = slot_path(SAVE_FILE_NAME)?;
+        if let Ok(previous) = read_file(&primary) {
+            if raw_save(&previous).and_then(Self::from_value).is_ok() {
+                write_file(&slot_path(BACKUP_FILE_NAME)?, &previous)?;
+            }
+        }
+
+        std::fs::rename(&temp, &primary).map_err(|e| format!("Save failed: {}", e))
+    }
+
+    /// Save to browser storage, keeping the previous save as a backup
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self, _path: &str) -> Result<(), String> {
+        // Stage the new save and make sure it reads back before touching the old one
+        save_json_key(GAME_NAME, TEMP_FILE_NAME, self)?;
+        let staged: SaveData = load_json_key(GAME_NAME, TEMP_FILE_NAME)?;
+
+        // Only a readable primary replaces the backup
+        if let Ok(previous) = load_json_key::<SaveData>(GAME_NAME, SAVE_FILE_NAME) {
+            save_json_key(GAME_NAME, BACKUP_FILE_NAME, &previous)?;
+        }
+
+        save_json_key(GAME_NAME, SAVE_FILE_NAME, &staged)
     }
 
-    /// Load from a file
+    /// Load from a file, falling back to the backup if the primary is unreadable
     pub fn load(_path: &str) -> Result<Self, String> {
-        let save: SaveData = load_json_key(GAME_NAME, SAVE_FILE_NAME)?;
+        Self::load_with_backup(|| read_slot(SAVE_FILE_NAME), || read_slot(BACKUP_FILE_NAME))
+    }
+
+    /// Write the autosave slot; manual saves and their backup are left alone
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn autosave(&self) -> Result<(), String> {
+        let bytes = self.to_bytes(Settings::load().binary_saves)?;
+        write_file(&slot_path(AUTOSAVE_FILE_NAME)?, &bytes)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn autosave(&self) -> Result<(), String> {
+        save_json_key(GAME_NAME, AUTOSAVE_FILE_NAME, self)
+    }
+
+    /// Load whichever of the manual save and the autosave was written last
+    pub fn load_latest(path: &str) -> Result<Self, String> {
+        Self::newest(
+            Self::load(path),
+            read_slot(AUTOSAVE_FILE_NAME).and_then(Self::from_value),
+        )
+    }
+
+    /// This save as file bytes: compressed binary when chosen and built in, JSON otherwise
+    #[cfg(not(target_arch = "wasm32"))]
+    fn to_bytes(&self, binary: bool) -> Result<Vec<u8>, String> {
+        #[cfg(feature = "binary-saves")]
+        if binary {
+            return binary::encode(self);
+        }
+        #[cfg(not(feature = "binary-saves"))]
+        if binary {
+            eprintln!("Binary saves are not built in; saving JSON");
+        }
+        Ok(self.export_string()?.into_bytes())
+    }
+
+    /// The later of two saves, or whichever one loaded; the manual save's error wins
+    fn newest(manual: Result<Self, String>, auto: Result<Self, String>) -> Result<Self, String> {
+        match (manual, auto) {
+            (Ok(manual), Ok(auto)) if auto.saved_at > manual.saved_at => Ok(auto),
+            (Ok(manual), _) => Ok(manual),
+            (Err(_), Ok(auto)) => Ok(auto),
+            (Err(e), Err(_)) => Err(e),
+        }
+    }
 
-        // Version check
-        if save.version > SAVE_VERSION {
-            return Err(format!(
+    /// Try the primary save, then the backup; the primary's error wins if both fail
+    fn load_with_backup(
+        primary: impl FnOnce() -> Result<serde_json::Value, String>,
+        backup: impl FnOnce() -> Result<serde_json::Value, String>,
+    ) -> Result<Self, String> {
+        match primary().and_then(Self::from_value) {
+            Ok(save) => Ok(save),
+            Err(primary_err) => {
+                let save = backup()
+                    .and_then(Self::from_value)
+                    .map_err(|_| primary_err)?;
+                eprintln!("Primary save unreadable; loaded backup");
+                Ok(save)
+            }
+        }
+    }
+
+    /// Read the version of a raw save and migrate it up to the current layout
+    fn from_value(raw: serde_json::Value) -> Result<Self, String> {
+        let version = raw
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .filter(|version| *version > 0)
+            .ok_or_else(|| "Save file has no version".to_string())?;
+        let version = u32::try_from(version).map_err(|_| {
+            format!(
                 "Save file version {} is newer than supported version {}",
-                save.version, SAVE_VERSION
-            ));
+                version, SAVE_VERSION
+            )
+        })?;
+        migrate(raw, version)
+    }
+
+    /// Pretty JSON of this save, for sharing or moving a run
+    pub fn export_string(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Export failed: {}", e))
+    }
+
+    /// Parse and validate an exported save
+    pub fn import_string(json: &str) -> Result<Self, String> {
+        let raw: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Not a valid save: {}", e))?;
+        Self::validate(Self::from_value(raw)?)
+    }
+
+    /// Reject saves that parse but could not have come from a real run
+    fn validate(save: SaveData) -> Result<Self, String> {
+        let mut ids = std::collections::HashSet::new();
+        for adv in &save.roster.adventurers {
+            if !ids.insert(adv.id.as_str()) {
+                return Err(format!("Duplicate adventurer id '{}'", adv.id));
+            }
+            if adv.max_hp <= 0 || adv.hp < 0 || adv.hp > adv.max_hp {
+                return Err(format!(
+                    "{} has impossible HP {}/{}",
+                    adv.name, adv.hp, adv.max_hp
+                ));
+            }
         }
 
         Ok(save)
     }
 
-    /// Check if a save exists
+    /// Write an export to a file path; `.bin` paths use the binary format when enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_to_path(&self, path: &str) -> Result<(), String> {
+        #[cfg(feature = "binary-saves")]
+        if path.ends_with(".bin") {
+            return self.save_binary(path);
+        }
+
+        std::fs::write(path, self.export_string()?).map_err(|e| format!("Export failed: {}", e))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn export_to_path(&self, _path: &str) -> Result<(), String> {
+        Err("File export is not available in the browser".to_string())
+    }
+
+    /// Read and validate an export from a file path, in either format
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn import_from_path(path: &str) -> Result<Self, String> {
+        let bytes = std::fs::read(path).map_err(|e| format!("Import failed: {}", e))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a save file, detecting binary saves by their magic header
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        Self::validate(Self::from_value(raw_save(bytes)?)?)
+    }
+
+    /// Write a compressed binary save to a file path
+    #[cfg(all(feature = "binary-saves", not(target_arch = "wasm32")))]
+    pub fn save_binary(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, binary::encode(self)?).map_err(|e| format!("Save failed: {}", e))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn import_from_path(_path: &str) -> Result<Self, String> {
+        Err("File import is not available in the browser".to_string())
+    }
+
+    /// Check if a save (its backup, or an autosave) exists
     pub fn exists(_path: &str) -> bool {
         json_key_exists(GAME_NAME, SAVE_FILE_NAME)
+            || json_key_exists(GAME_NAME, BACKUP_FILE_NAME)
+            || json_key_exists(GAME_NAME, AUTOSAVE_FILE_NAME)
     }
 
     /// Default save path (kept for compatibility with callers, though we use `get_app_data_path` internally now)
@@ -73,8 +271,165 @@ impl SaveData {
     }
 }
 
+/// Where a save slot lives on disk, alongside the toolkit's other JSON keys
+#[cfg(not(target_arch = "wasm32"))]
+fn slot_path(file_name: &str) -> Result<std::path::PathBuf, String> {
+    macroquad_toolkit::persistence::get_app_data_path(GAME_NAME)
+        .map(|dir| dir.join(file_name))
+        .ok_or_else(|| "No save directory available".to_string())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_file(path: &std::path::Path, bytes: &[u8]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("Save failed: {}", e))?;
+    }
+    std::fs::write(path, bytes).map_err(|e| format!("Save failed: {}", e))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_file(path: &std::path::Path) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| format!("Load failed: {}", e))
+}
+
+/// Raw save JSON from a save slot, in either format
+#[cfg(not(target_arch = "wasm32"))]
+fn read_slot(file_name: &str) -> Result<serde_json::Value, String> {
+    raw_save(&read_file(&slot_path(file_name)?)?)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_slot(file_name: &str) -> Result<serde_json::Value, String> {
+    load_json_key(GAME_NAME, file_name)
+}
+
+/// Raw save JSON from file bytes, in either format, ready for `SaveData::from_value`
+fn raw_save(bytes: &[u8]) -> Result<serde_json::Value, String> {
+    #[cfg(feature = "binary-saves")]
+    if binary::is_binary(bytes) {
+        return binary::decode(bytes);
+    }
+
+    serde_json::from_slice(bytes).map_err(|e| format!("Not a valid save: {}", e))
+}
+
 /// Create saves directory if needed
 /// (Deprecated: toolkit handles directory creation)
 pub fn ensure_save_directory() -> Result<(), String> {
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Result<serde_json::Value, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn corrupt_primary_loads_from_backup() {
+        let kingdom = KingdomState {
+            day: 42,
+            ..Default::default()
+        };
+        let backup = serde_json::to_string(&SaveData::new(kingdom, Roster::default())).unwrap();
+
+        let loaded =
+            SaveData::load_with_backup(|| parse("{\"version\": 1, \"kingd"), || parse(&backup))
+                .expect("backup should load");
+        assert_eq!(loaded.kingdom.day, 42);
+    }
+
+    #[test]
+    fn export_round_trips_through_import() {
+        let kingdom = KingdomState {
+            day: 7,
+            ..Default::default()
+        };
+        let exported = SaveData::new(kingdom, Roster::default())
+            .export_string()
+            .unwrap();
+        let imported = SaveData::import_string(&exported).expect("export should import");
+        assert_eq!(imported.kingdom.day, 7);
+    }
+
+    #[test]
+    fn affinities_round_trip_through_export() {
+        let mut roster = Roster::default();
+        roster.adjust_affinity("elena", "marcus", 24);
+        let exported = SaveData::new(KingdomState::default(), roster)
+            .export_string()
+            .unwrap();
+        let imported = SaveData::import_string(&exported).expect("export should import");
+        assert_eq!(imported.roster.affinity("marcus", "elena"), 24);
+    }
+
+    #[test]
+    fn saves_from_before_the_tutorial_skip_it() {
+        let mut save =
+            serde_json::to_value(SaveData::new(KingdomState::default(), Roster::default()))
+                .unwrap();
+        save["kingdom"]
+            .as_object_mut()
+            .unwrap()
+            .remove("tutorial_step");
+        let loaded: SaveData = serde_json::from_value(save).unwrap();
+        assert!(loaded.kingdom.tutorial_step.is_done());
+        assert!(!KingdomState::default().tutorial_step.is_done());
+    }
+
+    #[test]
+    fn saves_without_a_snapshot_resume_at_the_base() {
+        let mut save =
+            serde_json::to_value(SaveData::new(KingdomState::default(), Roster::default()))
+                .unwrap();
+        save.as_object_mut().unwrap().remove("in_progress");
+        let loaded: SaveData = serde_json::from_value(save).unwrap();
+        assert!(loaded.in_progress.is_none());
+    }
+
+    #[test]
+    fn import_rejects_newer_versions_and_garbage() {
+        let mut save = SaveData::new(KingdomState::default(), Roster::default());
+        save.version = SAVE_VERSION + 1;
+        let newer = save.export_string().unwrap();
+        assert!(SaveData::import_string(&newer).is_err());
+        assert!(SaveData::import_string("{\"version\": 1}").is_err());
+
+        // Versions past u32 must not wrap around to an old, loadable one
+        let mut raw = serde_json::to_value(&save).unwrap();
+        for version in [0, u64::from(u32::MAX) + 2] {
+            raw["version"] = serde_json::json!(version);
+            assert!(SaveData::import_string(&raw.to_string()).is_err());
+        }
+    }
+
+    #[test]
+    fn the_later_of_manual_and_autosave_wins() {
+        let save_at = |day, saved_at| {
+            let kingdom = KingdomState {
+                day,
+                ..Default::default()
+            };
+            Ok::<_, String>(SaveData {
+                saved_at,
+                ..SaveData::new(kingdom, Roster::default())
+            })
+        };
+        let newest = |manual, auto| SaveData::newest(manual, auto).unwrap().kingdom.day;
+        assert_eq!(newest(save_at(3, 100), save_at(4, 200)), 4);
+        assert_eq!(newest(save_at(3, 300), save_at(4, 200)), 3);
+        assert_eq!(newest(Err("gone".to_string()), save_at(4, 200)), 4);
+        assert_eq!(newest(save_at(3, 100), Err("gone".to_string())), 3);
+    }
+
+    #[test]
+    fn missing_backup_reports_primary_error() {
+        let result = SaveData::load_with_backup(
+            || Err("primary broken".to_string()),
+            || Err("no backup".to_string()),
+        );
+        assert_eq!(result.unwrap_err(), "primary broken");
+    }
+}
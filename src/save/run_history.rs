@@ -0,0 +1,42 @@
+//! Run history - one summary per finished run, kept apart from the save so it outlives it
+
+use macroquad_toolkit::persistence::{load_json_key, save_json_key};
+use serde::{Deserialize, Serialize};
+
+use super::GAME_NAME;
+use crate::kingdom::Difficulty;
+
+const RUN_HISTORY_FILE_NAME: &str = "runs.json";
+
+/// How one run ended
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// RNG seed for daily challenge runs; campaigns are unseeded
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub difficulty: Difficulty,
+    pub days_survived: u32,
+    pub missions_completed: u32,
+    pub heroes_lost: u32,
+}
+
+/// Every finished run, oldest first
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RunHistory {
+    #[serde(default)]
+    pub runs: Vec<RunRecord>,
+}
+
+impl RunHistory {
+    /// Load past runs, starting empty if none are readable
+    pub fn load() -> Self {
+        load_json_key(GAME_NAME, RUN_HISTORY_FILE_NAME).unwrap_or_default()
+    }
+
+    /// Add a finished run to the history on disk
+    pub fn append(record: RunRecord) -> Result<(), String> {
+        let mut history = Self::load();
+        history.runs.push(record);
+        save_json_key(GAME_NAME, RUN_HISTORY_FILE_NAME, &history)
+    }
+}
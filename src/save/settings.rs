@@ -0,0 +1,46 @@
+//! Player settings - kept apart from the run so they survive new games and imports
+
+use macroquad_toolkit::persistence::{load_json_key, save_json_key};
+use serde::{Deserialize, Serialize};
+
+use super::GAME_NAME;
+use crate::kingdom::Difficulty;
+
+const SETTINGS_FILE_NAME: &str = "frontier_kingdom_settings.json";
+
+/// Accessibility, display, and new-game preferences
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Settings {
+    /// Add symbols and labels beside color-coded statuses and map nodes
+    #[serde(default)]
+    pub colorblind: bool,
+    /// Preset used when a kingdom is founded because no save exists
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Debugging aid: write each fight's full combat log to disk when it ends
+    #[serde(default)]
+    pub dump_combat_log: bool,
+    /// Let traumas gained mid-fight pass without stopping combat on an overlay
+    #[serde(default)]
+    pub skip_trauma_pause: bool,
+    /// Write saves as compressed binary instead of JSON (needs the `binary-saves` feature)
+    #[serde(default)]
+    pub binary_saves: bool,
+    /// Show the combat hand cheapest first instead of in draw order
+    #[serde(default)]
+    pub sort_hand_by_cost: bool,
+    /// Handicap: one extra energy each turn against elite enemies
+    #[serde(default)]
+    pub elite_energy_bonus: bool,
+}
+
+impl Settings {
+    /// Load saved settings, falling back to defaults if none are readable
+    pub fn load() -> Self {
+        load_json_key(GAME_NAME, SETTINGS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        save_json_key(GAME_NAME, SETTINGS_FILE_NAME, self)
+    }
+}
@@ -0,0 +1,87 @@
+//! Deck viewer filters - narrow the card grid by category and group it by cost.
+
+use super::*;
+use crate::combat::{Card, CardCategory};
+
+/// Height reserved above each cost group for its heading.
+pub(super) const COST_HEADING_H: f32 = 24.0;
+
+/// Which cards the deck overlay shows and how the grid is laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeckFilter {
+    /// Only show this category; `None` shows the whole deck.
+    pub category: Option<CardCategory>,
+    /// Start a new grid row for each energy cost, cheapest first.
+    pub group_by_cost: bool,
+}
+
+impl DeckFilter {
+    /// Button labels in the order of `deck_filter_button_rect`.
+    pub(super) fn button_labels(&self) -> [&'static str; 5] {
+        [
+            "All",
+            CardCategory::Attack.label(),
+            CardCategory::Skill.label(),
+            CardCategory::Power.label(),
+            if self.group_by_cost {
+                "Group: Cost"
+            } else {
+                "Group: Off"
+            },
+        ]
+    }
+
+    /// Whether the button at `i` reflects the current filter.
+    pub(super) fn button_active(&self, i: usize) -> bool {
+        match i {
+            0 => self.category.is_none(),
+            4 => self.group_by_cost,
+            _ => self.category == CardCategory::ALL.get(i - 1).copied(),
+        }
+    }
+
+    pub(super) fn update(&mut self) {
+        for i in 0..5 {
+            let (x, y, w, h) = deck_filter_button_rect(i);
+            let pressed = i < 4 && number_key(i).is_some_and(is_key_pressed);
+            if pressed || crate::ui::was_clicked(x, y, w, h) {
+                self.press(i);
+            }
+        }
+        if is_key_pressed(KeyCode::G) {
+            self.press(4);
+        }
+    }
+
+    fn press(&mut self, i: usize) {
+        match i {
+            0 => self.category = None,
+            4 => self.group_by_cost = !self.group_by_cost,
+            _ => self.category = CardCategory::ALL.get(i - 1).copied(),
+        }
+    }
+
+    /// Split the deck into grid sections; one unlabeled section unless grouping by cost.
+    pub(super) fn arrange(&self, deck: Vec<Card>) -> Vec<(Option<i32>, Vec<Card>)> {
+        let mut cards: Vec<Card> = deck
+            .into_iter()
+            .filter(|card| {
+                self.category
+                    .is_none_or(|category| card.category() == category)
+            })
+            .collect();
+        if !self.group_by_cost {
+            return vec![(None, cards)];
+        }
+
+        cards.sort_by_key(|card| card.cost);
+        let mut groups: Vec<(Option<i32>, Vec<Card>)> = Vec::new();
+        for card in cards {
+            match groups.last_mut() {
+                Some((Some(cost), group)) if *cost == card.cost => group.push(card),
+                _ => groups.push((Some(card.cost), vec![card])),
+            }
+        }
+        groups
+    }
+}
@@ -0,0 +1,130 @@
+//! Hit rectangles and key mappings shared by base update and draw.
+
+use super::*;
+
+pub(super) fn number_key(i: usize) -> Option<KeyCode> {
+    match i {
+        0 => Some(KeyCode::Key1),
+        1 => Some(KeyCode::Key2),
+        2 => Some(KeyCode::Key3),
+        3 => Some(KeyCode::Key4),
+        4 => Some(KeyCode::Key5),
+        5 => Some(KeyCode::Key6),
+        6 => Some(KeyCode::Key7),
+        7 => Some(KeyCode::Key8),
+        8 => Some(KeyCode::Key9),
+        _ => None,
+    }
+}
+
+pub(super) fn tab_width(tab: BaseTab) -> f32 {
+    match tab {
+        BaseTab::DeckTraining => 126.0,
+        BaseTab::Graveyard => 92.0,
+        BaseTab::Buildings => 88.0,
+        _ => 78.0,
+    }
+}
+
+pub(super) fn action_buttons() -> [&'static str; 8] {
+    [
+        "Embark",
+        "Roster",
+        "Facilities",
+        "Treat",
+        "Recruit",
+        "Decks",
+        "Dismiss",
+        "Sermon",
+    ]
+}
+
+pub(super) fn action_enabled(
+    action: &str,
+    kingdom: &KingdomState,
+    roster: &Roster,
+    selected_adventurer: Option<usize>,
+) -> bool {
+    match action {
+        "Embark" => !roster.adventurers.is_empty(),
+        "Treat" | "Decks" => selected_adventurer
+            .and_then(|idx| roster.adventurers.get(idx))
+            .is_some(),
+        "Recruit" => kingdom.has_building("guild_hall"),
+        "Sermon" => {
+            kingdom.has_building("chapel")
+                && kingdom.sermon_relief() > 0
+                && kingdom.stats.supplies >= SERMON_SUPPLY_COST
+        }
+        "Dismiss" => selected_adventurer.is_some() && roster.adventurers.len() > 1,
+        _ => true,
+    }
+}
+
+pub(super) fn adventurer_row_rect(i: usize) -> (f32, f32, f32, f32) {
+    (285.0, MAIN_Y + 50.0 + (i as f32 * 34.0) - 22.0, 470.0, 30.0)
+}
+
+pub(super) fn roster_adventurer_row_rect(i: usize) -> (f32, f32, f32, f32) {
+    (34.0, MAIN_Y + 52.0 + (i as f32 * 34.0) - 22.0, 690.0, 30.0)
+}
+
+pub(super) fn adventurer_row_hit_rect(active_tab: BaseTab, i: usize) -> (f32, f32, f32, f32) {
+    if active_tab == BaseTab::Roster {
+        roster_adventurer_row_rect(i)
+    } else {
+        adventurer_row_rect(i)
+    }
+}
+
+pub(super) fn detail_back_button_rect() -> (f32, f32, f32, f32) {
+    (screen_width() - 168.0, DETAIL_Y + 4.0, 126.0, 30.0)
+}
+
+pub(super) fn party_mission_button_rect() -> (f32, f32, f32, f32) {
+    (48.0, MAIN_Y + 420.0, 190.0, 34.0)
+}
+
+pub(super) fn party_back_button_rect() -> (f32, f32, f32, f32) {
+    (254.0, MAIN_Y + 420.0, 150.0, 34.0)
+}
+
+pub(super) fn modal_rect() -> (f32, f32, f32, f32) {
+    let w = 460.0;
+    let h = 190.0;
+    (
+        (screen_width() - w) / 2.0,
+        (screen_height() - h) / 2.0,
+        w,
+        h,
+    )
+}
+
+pub(super) fn modal_confirm_button_rect() -> (f32, f32, f32, f32) {
+    let (x, y, _, h) = modal_rect();
+    (x + 24.0, y + h - 50.0, 180.0, 34.0)
+}
+
+pub(super) fn modal_cancel_button_rect() -> (f32, f32, f32, f32) {
+    let (x, y, w, h) = modal_rect();
+    (x + w - 204.0, y + h - 50.0, 180.0, 34.0)
+}
+
+pub(super) fn deck_filter_button_rect(i: usize) -> (f32, f32, f32, f32) {
+    let w = if i == 4 { 128.0 } else { 92.0 };
+    (62.0 + (i as f32 * 102.0), 104.0, w, 30.0)
+}
+
+pub(super) fn facility_card_rect(i: usize) -> (f32, f32, f32, f32) {
+    let cols = 3;
+    let card_w = (screen_width() - 84.0) / cols as f32;
+    let card_h = 92.0;
+    let col = i % cols;
+    let row = i / cols;
+    (
+        SIDE_PAD + 14.0 + (col as f32 * (card_w + 12.0)),
+        MAIN_Y + 48.0 + (row as f32 * (card_h + 12.0)),
+        card_w,
+        card_h,
+    )
+}
@@ -0,0 +1,719 @@
+//! Panel and detail drawing helpers for the base screen.
+
+use super::layout::*;
+use super::style::*;
+use super::*;
+use crate::kingdom::MAX_FATIGUE;
+
+pub(super) fn draw_command_table_background(
+    textures: &std::collections::HashMap<String, Texture2D>,
+) {
+    clear_background(Color::from_rgba(14, 10, 8, 255));
+    if let Some(tex) = textures.get(UI_BG_PATH) {
+        draw_texture_ex(
+            tex,
+            0.0,
+            0.0,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(screen_width(), screen_height())),
+                ..Default::default()
+            },
+        );
+    } else {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), table_color());
+        draw_circle(190.0, 120.0, 86.0, Color::from_rgba(120, 70, 28, 50));
+        draw_circle(
+            screen_width() - 160.0,
+            82.0,
+            72.0,
+            Color::from_rgba(150, 92, 34, 45),
+        );
+    }
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(8, 6, 5, 148),
+    );
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(40, 24, 10, 68),
+    );
+}
+
+pub(super) fn draw_header(kingdom: &KingdomState, active_tab: BaseTab) {
+    let morale = morale_label(kingdom.stats.morale);
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        HEADER_H,
+        Color::from_rgba(10, 7, 6, 218),
+    );
+    draw_line(0.0, HEADER_H, screen_width(), HEADER_H, 2.0, border_color());
+    draw_ui_text("FRONTIER KINGDOM", SIDE_PAD, 38.0, 34.0, title_color());
+    draw_ui_text(
+        &format!(
+            "Day {}    Threat {}    Morale: {}    {}",
+            kingdom.day,
+            kingdom.threat_level,
+            morale,
+            active_tab.label()
+        ),
+        430.0,
+        36.0,
+        20.0,
+        muted_text_color(),
+    );
+}
+
+pub(super) fn draw_tabs(active_tab: BaseTab) {
+    let mut x = SIDE_PAD;
+    for tab in BaseTab::ALL {
+        let w = tab_width(tab);
+        let selected = tab == active_tab;
+        draw_rectangle(
+            x,
+            62.0,
+            w,
+            28.0,
+            if selected {
+                Color::from_rgba(97, 66, 27, 235)
+            } else {
+                Color::from_rgba(26, 23, 21, 210)
+            },
+        );
+        draw_rectangle_lines(
+            x,
+            62.0,
+            w,
+            28.0,
+            if selected { 2.0 } else { 1.0 },
+            if selected {
+                candle_color()
+            } else {
+                border_color()
+            },
+        );
+        draw_ui_text(
+            tab.label(),
+            x + 10.0,
+            82.0,
+            16.0,
+            if selected {
+                title_color()
+            } else {
+                muted_text_color()
+            },
+        );
+        x += w + 8.0;
+    }
+}
+
+pub(super) fn draw_resources_panel(kingdom: &KingdomState, x: f32, y: f32, w: f32, h: f32) {
+    panel(x, y, w, h, "RESOURCES");
+    let stats = &kingdom.stats;
+    let rows = [
+        ("Gold", stats.gold, candle_color()),
+        ("Supplies", stats.supplies, parchment_color()),
+        ("Security", stats.security, info_color()),
+        ("Morale", stats.morale, morale_color(stats.morale)),
+        ("Knowledge", stats.knowledge, mystery_color()),
+        ("Influence", stats.influence, muted_text_color()),
+    ];
+    let mut row_y = y + 52.0;
+    for (label, value, color) in rows {
+        draw_ui_text(label, x + 18.0, row_y, 17.0, muted_text_color());
+        draw_ui_text(&value.to_string(), x + w - 70.0, row_y, 20.0, color);
+        row_y += 30.0;
+    }
+}
+
+pub(super) fn draw_adventurer_summary(
+    roster: &Roster,
+    selected: Option<usize>,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+    leader_id: Option<&str>,
+) {
+    panel(x, y, w, h, "PARTY / ADVENTURERS");
+    for (i, adv) in roster.adventurers.iter().enumerate().take(5) {
+        draw_adventurer_row(i, adv, selected == Some(i), x + 20.0, y + 50.0);
+        if leader_id == Some(adv.id.as_str()) {
+            draw_ui_text(
+                "Leader",
+                x + w - 90.0,
+                y + 74.0 + (i as f32 * 34.0),
+                15.0,
+                candle_color(),
+            );
+        }
+    }
+}
+
+pub(super) fn draw_goals_panel(kingdom: &KingdomState, x: f32, y: f32, w: f32, h: f32) {
+    panel(x, y, w, h, "CURRENT GOALS");
+    let mut row_y = y + 48.0;
+    for (quest, done) in kingdom.quest_log().into_iter().take(5) {
+        let color = if done { ready_color() } else { text_color() };
+        let marker = if done { "[x]" } else { "[ ]" };
+        draw_ui_text(
+            &format!("{} {}", marker, quest),
+            x + 18.0,
+            row_y,
+            16.0,
+            color,
+        );
+        row_y += 28.0;
+    }
+    if let Some(event) = &kingdom.last_event {
+        draw_ui_text("Alert", x + 18.0, y + h - 58.0, 16.0, danger_color());
+        draw_wrapped_text(
+            event,
+            x + 18.0,
+            y + h - 35.0,
+            w - 36.0,
+            14.0,
+            muted_text_color(),
+        );
+    }
+}
+
+pub(super) fn draw_readiness_summary(kingdom: &KingdomState, roster: &Roster, x: f32, y: f32) {
+    let ready = roster
+        .adventurers
+        .iter()
+        .filter(|adv| readiness_label(adv) == "Ready")
+        .count();
+    draw_ui_text(
+        &format!("Ready heroes: {}", ready),
+        x,
+        y,
+        20.0,
+        ready_color(),
+    );
+    draw_ui_text(
+        &format!("Supplies available: {}", kingdom.stats.supplies),
+        x,
+        y + 34.0,
+        18.0,
+        parchment_color(),
+    );
+    draw_wrapped_text(
+        "Send ready heroes, rest the shaken, and keep enough supplies for treatment after the mission.",
+        x,
+        y + 72.0,
+        screen_width() - x - 56.0,
+        16.0,
+        muted_text_color(),
+    );
+}
+
+pub(super) fn draw_adventurer_row(
+    i: usize,
+    adv: &Adventurer,
+    selected: bool,
+    x: f32,
+    start_y: f32,
+) {
+    let y = start_y + (i as f32 * 34.0);
+    let bg_x = x - 10.0;
+    let bg_y = y - 22.0;
+    let bg_w = if x < 100.0 { 690.0 } else { 470.0 };
+    let row_h = 30.0;
+    draw_rectangle(
+        bg_x,
+        bg_y,
+        bg_w,
+        row_h,
+        if selected {
+            Color::from_rgba(83, 58, 29, 225)
+        } else {
+            Color::from_rgba(22, 20, 18, 175)
+        },
+    );
+    if selected {
+        draw_rectangle_lines(bg_x, bg_y, bg_w, row_h, 2.0, candle_color());
+    }
+    draw_ui_text(
+        &format!("[{}] {}", i + 1, adv.name),
+        x,
+        y,
+        18.0,
+        text_color(),
+    );
+    draw_ui_text(
+        &format!("{:?} Lv {}", adv.class, adv.level),
+        x + 220.0,
+        y,
+        15.0,
+        muted_text_color(),
+    );
+    draw_ui_text(
+        readiness_label(adv),
+        x + 330.0,
+        y,
+        16.0,
+        readiness_color(adv),
+    );
+    let bar_w = if x < 100.0 { 160.0 } else { 36.0 };
+    draw_vital_bars(adv, bg_x + bg_w - bar_w - 8.0, bg_y + 4.0, bar_w);
+}
+
+/// HP, stress, and fatigue as three thin stacked bars
+fn draw_vital_bars(adv: &Adventurer, x: f32, y: f32, w: f32) {
+    let bars = [
+        (adv.hp, adv.max_hp, ready_color()),
+        (adv.stress, 100, mystery_color()),
+        (adv.fatigue, MAX_FATIGUE, candle_color()),
+    ];
+    for (i, (value, max, color)) in bars.into_iter().enumerate() {
+        let bar_y = y + i as f32 * 8.0;
+        let fill = (value as f32 / max.max(1) as f32).clamp(0.0, 1.0);
+        draw_rectangle(x, bar_y, w, 6.0, Color::from_rgba(10, 9, 8, 220));
+        draw_rectangle(x, bar_y, w * fill, 6.0, color);
+    }
+}
+
+pub(super) fn draw_facility_card(i: usize, building: &Building, selected: bool, can_build: bool) {
+    let (x, y, w, h) = facility_card_rect(i);
+    draw_rectangle(
+        x,
+        y,
+        w,
+        h,
+        if selected {
+            Color::from_rgba(77, 52, 26, 230)
+        } else {
+            Color::from_rgba(23, 21, 19, 218)
+        },
+    );
+    draw_rectangle_lines(
+        x,
+        y,
+        w,
+        h,
+        if selected { 2.0 } else { 1.0 },
+        if selected {
+            candle_color()
+        } else {
+            border_color()
+        },
+    );
+    draw_ui_text(
+        &building.name.to_uppercase(),
+        x + 14.0,
+        y + 28.0,
+        18.0,
+        title_color(),
+    );
+    draw_ui_text(
+        facility_purpose(&building.id),
+        x + 14.0,
+        y + 52.0,
+        13.0,
+        muted_text_color(),
+    );
+    let status = if building.built {
+        format!("Status: Built Lv{}", building.level)
+    } else {
+        format!(
+            "Cost: {}g / {}s",
+            building.cost_gold, building.cost_supplies
+        )
+    };
+    draw_ui_text(
+        &status,
+        x + 14.0,
+        y + h - 20.0,
+        15.0,
+        if building.built {
+            ready_color()
+        } else if can_build {
+            candle_color()
+        } else {
+            danger_color()
+        },
+    );
+    let (action, action_color) = if !building.built {
+        ("[Build]", candle_color())
+    } else if building.can_upgrade() {
+        ("[Upgrade]", candle_color())
+    } else {
+        ("Active", muted_text_color())
+    };
+    draw_ui_text(action, x + w - 82.0, y + h - 20.0, 15.0, action_color);
+}
+
+/// Filter-style button that stays lit while its option is on.
+pub(super) fn draw_toggle_button(label: &str, x: f32, y: f32, w: f32, h: f32, active: bool) {
+    draw_rectangle(
+        x,
+        y,
+        w,
+        h,
+        if active {
+            Color::from_rgba(97, 66, 27, 235)
+        } else {
+            Color::from_rgba(26, 23, 21, 210)
+        },
+    );
+    draw_rectangle_lines(
+        x,
+        y,
+        w,
+        h,
+        if active { 2.0 } else { 1.0 },
+        if active {
+            candle_color()
+        } else {
+            border_color()
+        },
+    );
+    let tw = measure_ui_text(label, None, 16, 1.0).width;
+    draw_ui_text(
+        label,
+        x + (w - tw) / 2.0,
+        y + 20.0,
+        16.0,
+        if active {
+            title_color()
+        } else {
+            muted_text_color()
+        },
+    );
+}
+
+pub(super) fn draw_action_button(label: &str, x: f32, y: f32, w: f32, h: f32, enabled: bool) {
+    let hovered = crate::ui::is_mouse_over(x, y, w, h);
+    let fill = if !enabled {
+        Color::from_rgba(31, 28, 25, 200)
+    } else if hovered {
+        Color::from_rgba(114, 78, 32, 245)
+    } else {
+        Color::from_rgba(74, 52, 28, 235)
+    };
+    draw_rectangle(x, y, w, h, fill);
+    draw_rectangle_lines(
+        x,
+        y,
+        w,
+        h,
+        1.0,
+        if enabled {
+            candle_color()
+        } else {
+            border_color()
+        },
+    );
+    let tw = measure_ui_text(label, None, 16, 1.0).width;
+    draw_ui_text(
+        label,
+        x + (w - tw) / 2.0,
+        y + 21.0,
+        16.0,
+        if enabled {
+            text_color()
+        } else {
+            muted_text_color()
+        },
+    );
+}
+
+pub(super) fn draw_adventurer_details(adv: &Adventurer) {
+    draw_ui_text(
+        &adv.title().to_uppercase(),
+        48.0,
+        DETAIL_Y + 48.0,
+        28.0,
+        title_color(),
+    );
+    draw_ui_text(
+        &format!("{:?} - Level {}", adv.class, adv.level),
+        48.0,
+        DETAIL_Y + 78.0,
+        18.0,
+        muted_text_color(),
+    );
+    let injuries = if adv.injuries.is_empty() {
+        "None".to_string()
+    } else {
+        adv.injuries
+            .iter()
+            .map(|injury| injury.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let trauma = if adv.traumas.is_empty() {
+        "None".to_string()
+    } else {
+        adv.traumas
+            .iter()
+            .map(|trauma| trauma.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    draw_ui_text(
+        &format!(
+            "HP {}/{}    Stress {}    Fatigue {}    Injuries: {}    Trauma: {}    Deck: {} cards",
+            adv.hp,
+            adv.max_hp,
+            adv.stress,
+            adv.fatigue,
+            injuries,
+            trauma,
+            deck_size(adv)
+        ),
+        48.0,
+        DETAIL_Y + 112.0,
+        18.0,
+        text_color(),
+    );
+    draw_ui_text("Best Use", 48.0, DETAIL_Y + 150.0, 18.0, candle_color());
+    draw_wrapped_text(
+        class_guidance(adv),
+        145.0,
+        DETAIL_Y + 150.0,
+        screen_width() - 190.0,
+        16.0,
+        muted_text_color(),
+    );
+    draw_ui_text(
+        "Actions: [View Deck] [Assign to Party] [Treat] [Train]",
+        48.0,
+        DETAIL_Y + 196.0,
+        17.0,
+        ready_color(),
+    );
+}
+
+pub(super) fn draw_building_details(building: &Building, kingdom: &KingdomState, can_build: bool) {
+    draw_ui_text(
+        &building.name.to_uppercase(),
+        48.0,
+        DETAIL_Y + 48.0,
+        28.0,
+        title_color(),
+    );
+    draw_ui_text(
+        if building.built {
+            "Constructed"
+        } else {
+            "Not Constructed"
+        },
+        48.0,
+        DETAIL_Y + 78.0,
+        18.0,
+        if building.built {
+            ready_color()
+        } else {
+            danger_color()
+        },
+    );
+    draw_ui_text("Purpose", 48.0, DETAIL_Y + 116.0, 18.0, candle_color());
+    draw_wrapped_text(
+        facility_purpose(&building.id),
+        145.0,
+        DETAIL_Y + 116.0,
+        screen_width() - 190.0,
+        16.0,
+        text_color(),
+    );
+    let (gold, supplies) = building.next_cost();
+    let affordability = if can_build {
+        "Enough resources."
+    } else if kingdom.stats.gold < gold {
+        "Need more gold."
+    } else {
+        "Need more supplies."
+    };
+    let cost_or_use = if building.can_upgrade() {
+        format!(
+            "{} Upgrade to Lv{} for {} Gold, {} Supplies. {}",
+            facility_unlocks(&building.id),
+            building.level + 1,
+            gold,
+            supplies,
+            affordability
+        )
+    } else if building.built {
+        facility_unlocks(&building.id).to_string()
+    } else {
+        format!(
+            "Build Cost: {} Gold, {} Supplies. {}",
+            gold, supplies, affordability
+        )
+    };
+    draw_wrapped_text(
+        &cost_or_use,
+        48.0,
+        DETAIL_Y + 158.0,
+        screen_width() - 96.0,
+        16.0,
+        muted_text_color(),
+    );
+    draw_ui_text(
+        if building.can_upgrade() {
+            "Action: [Upgrade Facility]"
+        } else if building.built {
+            "Actions: facility active"
+        } else {
+            "Action: [Build Facility]"
+        },
+        48.0,
+        DETAIL_Y + 206.0,
+        17.0,
+        if can_build || building.built {
+            ready_color()
+        } else {
+            danger_color()
+        },
+    );
+}
+
+pub(super) fn draw_goals_detail(kingdom: &KingdomState) {
+    draw_ui_text(
+        "CURRENT FRONTIER CHARTER",
+        48.0,
+        DETAIL_Y + 48.0,
+        26.0,
+        title_color(),
+    );
+    let mut y = DETAIL_Y + 84.0;
+    for (quest, done) in kingdom.quest_log() {
+        draw_ui_text(
+            &format!("{} {}", if done { "[x]" } else { "[ ]" }, quest),
+            48.0,
+            y,
+            18.0,
+            if done { ready_color() } else { text_color() },
+        );
+        y += 28.0;
+    }
+}
+
+pub(super) fn draw_graveyard_detail(roster: &Roster) {
+    draw_ui_text(
+        "LOSSES AND SCARS",
+        48.0,
+        DETAIL_Y + 48.0,
+        26.0,
+        title_color(),
+    );
+    let text = if roster.graveyard.is_empty() {
+        "The graveyard is empty, but the ledger has space."
+    } else {
+        "The dead stay here. Their absence should shape the next expedition. Press G to visit the memorial."
+    };
+    draw_wrapped_text(
+        text,
+        48.0,
+        DETAIL_Y + 86.0,
+        screen_width() - 96.0,
+        18.0,
+        muted_text_color(),
+    );
+}
+
+pub(super) fn draw_training_detail(adv: &Adventurer, kingdom: &KingdomState) {
+    draw_ui_text(
+        "DECK / TRAINING",
+        48.0,
+        DETAIL_Y + 48.0,
+        26.0,
+        title_color(),
+    );
+    draw_ui_text(
+        &format!("{} currently has {} cards.", adv.name, deck_size(adv)),
+        48.0,
+        DETAIL_Y + 84.0,
+        18.0,
+        text_color(),
+    );
+    draw_wrapped_text(
+        if kingdom.has_building("foundry") {
+            "Spend Knowledge to add the next class card. Training should feel like trading scarce insight for survival."
+        } else {
+            "The Foundry is required before stronger cards can be learned."
+        },
+        48.0,
+        DETAIL_Y + 122.0,
+        screen_width() - 96.0,
+        17.0,
+        muted_text_color(),
+    );
+}
+
+pub(super) fn draw_card_frame(
+    card: &crate::combat::Card,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+    selected: bool,
+) {
+    let accent = card_accent(card);
+    draw_rectangle(x, y, w, h, Color::from_rgba(19, 17, 15, 245));
+    draw_rectangle_lines(x, y, w, h, if selected { 3.0 } else { 2.0 }, accent);
+    draw_rectangle(
+        x + 6.0,
+        y + 6.0,
+        w - 12.0,
+        24.0,
+        Color::from_rgba(38, 33, 27, 240),
+    );
+    draw_ui_text(
+        &format!("{}", card.cost),
+        x + 12.0,
+        y + 24.0,
+        18.0,
+        candle_color(),
+    );
+    draw_ui_text(
+        card_type(card),
+        x + w - 62.0,
+        y + 24.0,
+        14.0,
+        muted_text_color(),
+    );
+    draw_rectangle(
+        x + 8.0,
+        y + 36.0,
+        w - 16.0,
+        h * 0.42,
+        Color::from_rgba(43, 40, 38, 255),
+    );
+    draw_ui_text(&card.name, x + 10.0, y + h - 52.0, 15.0, text_color());
+    draw_wrapped_text(
+        &card.description,
+        x + 10.0,
+        y + h - 32.0,
+        w - 20.0,
+        12.0,
+        muted_text_color(),
+    );
+}
+
+pub(super) fn draw_shortcuts() {
+    draw_ui_text(
+        "Shortcuts: 1-9 Select - Tab Tabs - M Party - D Deck - H/T Treat - F5 Save - F9 Load - F1 Help",
+        SIDE_PAD,
+        screen_height() - 18.0,
+        14.0,
+        muted_text_color(),
+    );
+}
+
+pub(super) fn panel(x: f32, y: f32, w: f32, h: f32, title: &str) {
+    draw_rectangle(x, y, w, h, Color::from_rgba(16, 13, 11, 218));
+    draw_rectangle_lines(x, y, w, h, 1.0, border_color());
+    draw_rectangle(x, y, w, 32.0, Color::from_rgba(43, 30, 17, 205));
+    draw_ui_text(title, x + 14.0, y + 23.0, 16.0, candle_color());
+}
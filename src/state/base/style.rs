@@ -0,0 +1,191 @@
+//! Labels, colors, and text helpers for the base screen.
+
+use super::*;
+
+pub(super) fn draw_wrapped_text(
+    text: &str,
+    x: f32,
+    y: f32,
+    max_width: f32,
+    font_size: f32,
+    color: Color,
+) {
+    let mut current = String::new();
+    let mut line_y = y;
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", current, word)
+        };
+        if measure_ui_text(&candidate, None, font_size as u16, 1.0).width > max_width
+            && !current.is_empty()
+        {
+            draw_ui_text(&current, x, line_y, font_size, color);
+            current = word.to_string();
+            line_y += font_size + 5.0;
+        } else {
+            current = candidate;
+        }
+    }
+    if !current.is_empty() {
+        draw_ui_text(&current, x, line_y, font_size, color);
+    }
+}
+
+pub(super) fn deck_size(adv: &Adventurer) -> usize {
+    let class_name = format!("{:?}", adv.class);
+    crate::combat::Card::load_deck_for_class(&class_name, &adv.deck_additions)
+        .0
+        .len()
+}
+
+pub(super) fn readiness_label(adv: &Adventurer) -> &'static str {
+    if adv.hp <= adv.max_hp / 3 {
+        "Needs Rest"
+    } else if adv.stress >= 75 {
+        "Fracturing"
+    } else if adv.stress >= 50 {
+        "Stressed"
+    } else if !adv.injuries.is_empty() {
+        "Wounded"
+    } else {
+        "Ready"
+    }
+}
+
+pub(super) fn readiness_color(adv: &Adventurer) -> Color {
+    match readiness_label(adv) {
+        "Ready" => ready_color(),
+        "Stressed" | "Wounded" => candle_color(),
+        _ => danger_color(),
+    }
+}
+
+pub(super) fn morale_label(value: i32) -> &'static str {
+    match value {
+        v if v >= 75 => "Steady",
+        v if v >= 45 => "Fragile",
+        v if v >= 20 => "Shaky",
+        _ => "Breaking",
+    }
+}
+
+pub(super) fn morale_color(value: i32) -> Color {
+    if value >= 60 {
+        ready_color()
+    } else if value >= 30 {
+        candle_color()
+    } else {
+        danger_color()
+    }
+}
+
+pub(super) fn facility_purpose(id: &str) -> &'static str {
+    match id {
+        "infirmary" => "Heal injuries before they become permanent.",
+        "chapel" => "Reduce stress and prevent resolve collapse.",
+        "foundry" => "Improve equipment and unlock stronger cards.",
+        "guild_hall" => "Recruit, dismiss, and train adventurers.",
+        "watchtowers" => "Raise security, lower threat, and unlock scouting missions.",
+        "citadel" => "Final objective and win condition.",
+        _ => "Frontier support facility.",
+    }
+}
+
+pub(super) fn facility_unlocks(id: &str) -> &'static str {
+    match id {
+        "infirmary" => "Unlocks: Treat Wounds and safer injury recovery.",
+        "chapel" => "Unlocks: Stress relief before resolve collapse.",
+        "foundry" => "Unlocks: Knowledge-based card training.",
+        "guild_hall" => "Unlocks: Recruitment and roster growth.",
+        "watchtowers" => "Unlocks: Ruined Outpost scouting routes.",
+        "citadel" => "Secures the campaign ending.",
+        _ => "Facility active.",
+    }
+}
+
+pub(super) fn class_guidance(adv: &Adventurer) -> &'static str {
+    match format!("{:?}", adv.class).as_str() {
+        "Soldier" => "Strong frontline fighter. Good for Suppress and Combat-heavy missions.",
+        "Scout" => "Route finder and opportunist. Good when the mission may punish slow choices.",
+        "Healer" => "Keeps the party alive and calmer. Best when wounds or stress are expected.",
+        "Mystic" => {
+            "High-impact control and burst damage. Best when dangerous enemies must be disrupted."
+        }
+        _ => "Reliable frontier hand. Match them to current wounds, stress, and route risk.",
+    }
+}
+
+pub(super) fn card_type(card: &crate::combat::Card) -> &'static str {
+    if card.is_attack() {
+        "Attack"
+    } else if card
+        .effects
+        .iter()
+        .any(|effect| matches!(effect, crate::combat::CardEffect::Heal(_)))
+    {
+        "Heal"
+    } else if card
+        .effects
+        .iter()
+        .any(|effect| matches!(effect, crate::combat::CardEffect::Block(_)))
+    {
+        "Guard"
+    } else {
+        "Skill"
+    }
+}
+
+pub(super) fn card_accent(card: &crate::combat::Card) -> Color {
+    match card_type(card) {
+        "Attack" => danger_color(),
+        "Guard" => info_color(),
+        "Heal" => ready_color(),
+        _ => candle_color(),
+    }
+}
+
+pub(super) fn table_color() -> Color {
+    Color::from_rgba(40, 25, 16, 255)
+}
+
+pub(super) fn title_color() -> Color {
+    Color::from_rgba(236, 224, 198, 255)
+}
+
+pub(super) fn text_color() -> Color {
+    Color::from_rgba(220, 212, 190, 255)
+}
+
+pub(super) fn muted_text_color() -> Color {
+    Color::from_rgba(164, 153, 130, 255)
+}
+
+pub(super) fn parchment_color() -> Color {
+    Color::from_rgba(196, 176, 130, 255)
+}
+
+pub(super) fn candle_color() -> Color {
+    Color::from_rgba(214, 154, 62, 255)
+}
+
+pub(super) fn ready_color() -> Color {
+    Color::from_rgba(112, 143, 92, 255)
+}
+
+pub(super) fn danger_color() -> Color {
+    Color::from_rgba(150, 55, 48, 255)
+}
+
+pub(super) fn info_color() -> Color {
+    Color::from_rgba(114, 136, 146, 255)
+}
+
+pub(super) fn mystery_color() -> Color {
+    Color::from_rgba(119, 86, 132, 255)
+}
+
+pub(super) fn border_color() -> Color {
+    Color::from_rgba(108, 82, 51, 210)
+}
@@ -0,0 +1,86 @@
+//! Export and import of whole runs through a typed file path.
+
+use super::*;
+use crate::save::SaveData;
+
+/// File name offered when the path prompt opens.
+const DEFAULT_EXPORT_PATH: &str = "frontier_export.json";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferMode {
+    Export,
+    Import,
+}
+
+impl TransferMode {
+    pub(super) fn title(self) -> &'static str {
+        match self {
+            TransferMode::Export => "EXPORT RUN TO FILE",
+            TransferMode::Import => "IMPORT RUN FROM FILE",
+        }
+    }
+}
+
+/// Path being typed for an export or import.
+pub struct PathPrompt {
+    pub mode: TransferMode,
+    pub path: String,
+}
+
+impl PathPrompt {
+    pub(super) fn new(mode: TransferMode) -> Self {
+        Self {
+            mode,
+            path: DEFAULT_EXPORT_PATH.to_string(),
+        }
+    }
+}
+
+impl BaseState {
+    /// Type a path; Enter runs the export/import, Esc cancels.
+    pub(super) fn update_path_prompt(&mut self, kingdom: &mut KingdomState, roster: &mut Roster) {
+        let Some(prompt) = self.path_prompt.as_mut() else {
+            return;
+        };
+
+        while let Some(c) = get_char_pressed() {
+            if !c.is_control() {
+                prompt.path.push(c);
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            prompt.path.pop();
+        }
+
+        if is_key_pressed(KeyCode::Escape) {
+            self.path_prompt = None;
+            return;
+        }
+        if !is_key_pressed(KeyCode::Enter) {
+            return;
+        }
+
+        let Some(prompt) = self.path_prompt.take() else {
+            return;
+        };
+        let path = prompt.path.trim();
+        kingdom.last_event = Some(match prompt.mode {
+            TransferMode::Export => {
+                match SaveData::new(kingdom.clone(), roster.clone()).export_to_path(path) {
+                    Ok(()) => format!("Run exported to {}", path),
+                    Err(e) => e,
+                }
+            }
+            TransferMode::Import => match SaveData::import_from_path(path) {
+                Ok(save) => {
+                    *kingdom = save.kingdom;
+                    *roster = save.roster;
+                    kingdom.ensure_current_buildings();
+                    self.selected_adventurer = Some(0);
+                    format!("Run imported from {}", path)
+                }
+                Err(e) => e,
+            },
+        });
+    }
+}
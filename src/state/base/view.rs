@@ -0,0 +1,581 @@
+//! Base screen drawing.
+
+use super::layout::*;
+use super::panels::*;
+use super::style::*;
+use super::*;
+use crate::kingdom::FormationRow;
+
+impl BaseState {
+    pub fn draw(
+        &self,
+        kingdom: &KingdomState,
+        roster: &Roster,
+        textures: &std::collections::HashMap<String, Texture2D>,
+    ) {
+        draw_command_table_background(textures);
+        draw_header(kingdom, self.active_tab);
+        draw_tabs(self.active_tab);
+
+        if self.focus == FocusArea::PartyFormation {
+            self.draw_party_formation(roster);
+        } else {
+            match self.active_tab {
+                BaseTab::Kingdom => self.draw_kingdom_dashboard(kingdom, roster),
+                BaseTab::Roster => self.draw_roster_tab(kingdom, roster),
+                BaseTab::Missions => self.draw_missions_tab(kingdom, roster),
+                BaseTab::Buildings => self.draw_buildings_tab(kingdom),
+                BaseTab::DeckTraining => self.draw_deck_training_tab(kingdom, roster),
+                BaseTab::Graveyard => self.draw_graveyard_tab(roster),
+                BaseTab::Journal => self.draw_journal_tab(kingdom),
+            }
+
+            self.draw_action_bar(kingdom, roster);
+            self.draw_detail_panel(kingdom, roster);
+        }
+
+        if self.viewing_deck {
+            self.draw_deck_overlay(roster);
+        }
+
+        if let Some(adv) = self
+            .pending_dismiss
+            .as_ref()
+            .and_then(|id| roster.adventurers.iter().find(|adv| &adv.id == id))
+        {
+            draw_dismiss_confirm(adv);
+        }
+
+        if let Some(prompt) = &self.path_prompt {
+            draw_path_prompt(prompt);
+        }
+
+        draw_shortcuts();
+    }
+
+    fn draw_kingdom_dashboard(&self, kingdom: &KingdomState, roster: &Roster) {
+        let w = screen_width();
+        let left_w = 220.0;
+        let center_w = (w - 96.0) * 0.48;
+        let right_x = SIDE_PAD + left_w + center_w + 24.0;
+        let right_w = (w - right_x - SIDE_PAD).max(260.0);
+
+        draw_resources_panel(kingdom, SIDE_PAD, MAIN_Y, left_w, MAIN_H);
+        draw_adventurer_summary(
+            roster,
+            self.selected_adventurer,
+            SIDE_PAD + left_w + 12.0,
+            MAIN_Y,
+            center_w,
+            MAIN_H,
+            self.forming_party.leader_id(),
+        );
+        draw_goals_panel(kingdom, right_x, MAIN_Y, right_w, MAIN_H);
+    }
+
+    fn draw_roster_tab(&self, kingdom: &KingdomState, roster: &Roster) {
+        panel(SIDE_PAD, MAIN_Y, 735.0, MAIN_H, "ADVENTURERS");
+        for (i, adv) in roster.adventurers.iter().enumerate().take(5) {
+            draw_adventurer_row(
+                i,
+                adv,
+                self.selected_adventurer == Some(i),
+                44.0,
+                MAIN_Y + 52.0,
+            );
+        }
+
+        panel(790.0, MAIN_Y, screen_width() - 814.0, MAIN_H, "READINESS");
+        draw_readiness_summary(kingdom, roster, 812.0, MAIN_Y + 48.0);
+    }
+
+    fn draw_missions_tab(&self, kingdom: &KingdomState, roster: &Roster) {
+        panel(SIDE_PAD, MAIN_Y, 520.0, MAIN_H, "MISSION BOARD");
+        let mut y = MAIN_Y + 50.0;
+        for (quest, done) in kingdom.quest_log() {
+            let color = if done { ready_color() } else { text_color() };
+            draw_ui_text(
+                &format!("{} {}", if done { "[x]" } else { "[ ]" }, quest),
+                48.0,
+                y,
+                18.0,
+                color,
+            );
+            y += 32.0;
+        }
+
+        panel(565.0, MAIN_Y, screen_width() - 589.0, MAIN_H, "EMBARK PREP");
+        let selected = self
+            .selected_adventurer
+            .and_then(|idx| roster.adventurers.get(idx))
+            .or_else(|| roster.adventurers.first());
+        if let Some(adv) = selected {
+            draw_ui_text(
+                "Selected Leader",
+                592.0,
+                MAIN_Y + 50.0,
+                18.0,
+                muted_text_color(),
+            );
+            draw_ui_text(&adv.name, 592.0, MAIN_Y + 82.0, 26.0, candle_color());
+            draw_ui_text(
+                &format!(
+                    "{} - HP {}/{} - Stress {} - {}",
+                    format!("{:?}", adv.class),
+                    adv.hp,
+                    adv.max_hp,
+                    adv.stress,
+                    readiness_label(adv)
+                ),
+                592.0,
+                MAIN_Y + 114.0,
+                18.0,
+                text_color(),
+            );
+            draw_wrapped_text(
+                "Embark opens the mission board with this hero as party leader. Add more exhausted hands only if the route demands it.",
+                592.0,
+                MAIN_Y + 150.0,
+                screen_width() - 630.0,
+                16.0,
+                muted_text_color(),
+            );
+        }
+    }
+
+    fn draw_buildings_tab(&self, kingdom: &KingdomState) {
+        panel(
+            SIDE_PAD,
+            MAIN_Y,
+            screen_width() - SIDE_PAD * 2.0,
+            MAIN_H,
+            "FACILITIES",
+        );
+        for (i, building) in kingdom.buildings.iter().enumerate() {
+            draw_facility_card(
+                i,
+                building,
+                self.selected_building == Some(i),
+                self.can_build(kingdom, i),
+            );
+        }
+    }
+
+    fn draw_deck_training_tab(&self, kingdom: &KingdomState, roster: &Roster) {
+        panel(SIDE_PAD, MAIN_Y, 430.0, MAIN_H, "TRAINING");
+        let selected = self
+            .selected_adventurer
+            .and_then(|idx| roster.adventurers.get(idx));
+        if let Some(adv) = selected {
+            draw_ui_text(&adv.name, 48.0, MAIN_Y + 54.0, 24.0, candle_color());
+            draw_ui_text(
+                &format!("Deck: {} cards", deck_size(adv)),
+                48.0,
+                MAIN_Y + 86.0,
+                18.0,
+                text_color(),
+            );
+            let foundry_status = if kingdom.has_building("foundry") {
+                "Foundry built. Press U to learn the next affordable card, F to forge learned ones."
+            } else {
+                "Build the Foundry before advanced card training."
+            };
+            draw_wrapped_text(
+                foundry_status,
+                48.0,
+                MAIN_Y + 120.0,
+                360.0,
+                16.0,
+                muted_text_color(),
+            );
+        }
+
+        panel(
+            480.0,
+            MAIN_Y,
+            screen_width() - 504.0,
+            MAIN_H,
+            "TRAINING NOTES",
+        );
+        draw_wrapped_text(
+            "Training is intentionally tied to Knowledge. The command table should make upgrades feel like hard choices, not automatic shopping.",
+            506.0,
+            MAIN_Y + 55.0,
+            screen_width() - 555.0,
+            18.0,
+            text_color(),
+        );
+    }
+
+    fn draw_graveyard_tab(&self, roster: &Roster) {
+        panel(
+            SIDE_PAD,
+            MAIN_Y,
+            screen_width() - SIDE_PAD * 2.0,
+            MAIN_H,
+            "GRAVEYARD / TRAUMA LOG",
+        );
+        if roster.graveyard.is_empty() {
+            draw_ui_text(
+                "No names carved into the boards yet.",
+                48.0,
+                MAIN_Y + 62.0,
+                20.0,
+                muted_text_color(),
+            );
+        } else {
+            for (i, adv) in roster.graveyard.iter().enumerate().take(5) {
+                draw_ui_text(
+                    &adv.name,
+                    48.0,
+                    MAIN_Y + 58.0 + (i as f32 * 32.0),
+                    20.0,
+                    danger_color(),
+                );
+            }
+        }
+    }
+
+    fn draw_journal_tab(&self, kingdom: &KingdomState) {
+        panel(
+            SIDE_PAD,
+            MAIN_Y,
+            screen_width() - SIDE_PAD * 2.0,
+            MAIN_H,
+            "JOURNAL",
+        );
+        let event = kingdom
+            .last_event
+            .as_deref()
+            .unwrap_or("No fresh omens from the frontier.");
+        draw_wrapped_text(
+            event,
+            48.0,
+            MAIN_Y + 58.0,
+            screen_width() - 96.0,
+            18.0,
+            text_color(),
+        );
+    }
+
+    fn draw_party_formation(&self, roster: &Roster) {
+        panel(
+            SIDE_PAD,
+            MAIN_Y,
+            screen_width() - SIDE_PAD * 2.0,
+            475.0,
+            "PARTY / ADVENTURERS",
+        );
+        draw_ui_text(
+            &format!(
+                "Choose the expedition party ({}/{})",
+                self.forming_party.size(),
+                crate::kingdom::MAX_PARTY_SIZE
+            ),
+            48.0,
+            MAIN_Y + 48.0,
+            22.0,
+            candle_color(),
+        );
+
+        for (i, adv) in roster.adventurers.iter().enumerate().take(9) {
+            let y = MAIN_Y + 90.0 + (i as f32 * 40.0);
+            let slot = self.forming_party.position_of(&adv.id);
+            let in_party = slot.is_some();
+            let marker = match slot {
+                Some(0) => format!("LEADER - {}", FormationRow::Front.label()),
+                Some(pos) => format!(
+                    "SLOT {} - {}",
+                    pos + 1,
+                    FormationRow::for_position(pos).label()
+                ),
+                None => String::new(),
+            };
+            let color = if in_party {
+                candle_color()
+            } else {
+                text_color()
+            };
+            draw_ui_text(
+                &format!(
+                    "[{}] {:<18} {:<9} HP {}/{}  Stress {}  {}",
+                    i + 1,
+                    adv.name,
+                    format!("{:?}", adv.class),
+                    adv.hp,
+                    adv.max_hp,
+                    adv.stress,
+                    marker
+                ),
+                48.0,
+                y,
+                18.0,
+                color,
+            );
+        }
+
+        draw_ui_text(
+            "[#] Assign  [Shift+#] Move Forward  [Enter] Open Mission Board  [Esc] Cancel",
+            48.0,
+            screen_height() - 38.0,
+            18.0,
+            muted_text_color(),
+        );
+
+        let (mission_x, mission_y, mission_w, mission_h) = party_mission_button_rect();
+        draw_action_button(
+            "Open Mission Board",
+            mission_x,
+            mission_y,
+            mission_w,
+            mission_h,
+            !self.forming_party.is_empty(),
+        );
+        let (back_x, back_y, back_w, back_h) = party_back_button_rect();
+        draw_action_button("Back to Roster", back_x, back_y, back_w, back_h, true);
+
+        if let Some(adv) = self
+            .pending_deploy
+            .as_ref()
+            .and_then(|id| roster.adventurers.iter().find(|adv| &adv.id == id))
+        {
+            draw_deploy_confirm(adv);
+        }
+    }
+
+    fn draw_action_bar(&self, kingdom: &KingdomState, roster: &Roster) {
+        panel(
+            SIDE_PAD,
+            ACTION_Y,
+            screen_width() - SIDE_PAD * 2.0,
+            ACTION_H,
+            "KINGDOM ACTIONS",
+        );
+        for (i, action) in action_buttons().iter().enumerate() {
+            let x = SIDE_PAD + 18.0 + (i as f32 * 138.0);
+            let enabled = action_enabled(action, kingdom, roster, self.selected_adventurer);
+            draw_action_button(action, x, ACTION_Y + 30.0, 126.0, 30.0, enabled);
+        }
+    }
+
+    fn draw_detail_panel(&self, kingdom: &KingdomState, roster: &Roster) {
+        let h = (screen_height() - DETAIL_Y - 48.0).max(185.0);
+        panel(
+            SIDE_PAD,
+            DETAIL_Y,
+            screen_width() - SIDE_PAD * 2.0,
+            h,
+            "DETAILS",
+        );
+        if self.selected_adventurer.is_some() || self.selected_building.is_some() {
+            let (x, y, w, h) = detail_back_button_rect();
+            draw_action_button("Back", x, y, w, h, true);
+        }
+
+        match self.active_tab {
+            BaseTab::Buildings => {
+                if let Some(idx) = self.selected_building {
+                    if let Some(building) = kingdom.buildings.get(idx) {
+                        draw_building_details(building, kingdom, self.can_build(kingdom, idx));
+                        return;
+                    }
+                }
+            }
+            BaseTab::Missions | BaseTab::Journal => {
+                draw_goals_detail(kingdom);
+                return;
+            }
+            BaseTab::Graveyard => {
+                draw_graveyard_detail(roster);
+                return;
+            }
+            BaseTab::DeckTraining => {
+                if let Some(adv) = self
+                    .selected_adventurer
+                    .and_then(|idx| roster.adventurers.get(idx))
+                {
+                    draw_training_detail(adv, kingdom);
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(adv) = self
+            .selected_adventurer
+            .and_then(|idx| roster.adventurers.get(idx))
+        {
+            draw_adventurer_details(adv);
+        } else {
+            draw_ui_text(
+                "Select an adventurer, facility, or goal.",
+                48.0,
+                DETAIL_Y + 56.0,
+                20.0,
+                muted_text_color(),
+            );
+        }
+    }
+
+    fn draw_deck_overlay(&self, roster: &Roster) {
+        let Some(adv) = self
+            .selected_adventurer
+            .and_then(|idx| roster.adventurers.get(idx))
+        else {
+            return;
+        };
+
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            screen_height(),
+            Color::from_rgba(4, 3, 2, 235),
+        );
+        panel(
+            34.0,
+            36.0,
+            screen_width() - 68.0,
+            screen_height() - 72.0,
+            "DECK / TRAINING",
+        );
+        draw_ui_text(
+            &format!("{}'s Deck", adv.name),
+            62.0,
+            88.0,
+            34.0,
+            candle_color(),
+        );
+        draw_ui_text(
+            "[1-4] Filter   [G] Group by cost   [Esc] Close",
+            screen_width() - 420.0,
+            86.0,
+            18.0,
+            muted_text_color(),
+        );
+        for (i, label) in self.deck_filter.button_labels().iter().enumerate() {
+            let (x, y, w, h) = deck_filter_button_rect(i);
+            draw_toggle_button(label, x, y, w, h, self.deck_filter.button_active(i));
+        }
+
+        let class_name = format!("{:?}", adv.class);
+        let (deck, _) = crate::combat::Card::load_deck_for_class(&class_name, &adv.deck_additions);
+        let start_x = 62.0;
+        let card_w = 132.0;
+        let card_h = 164.0;
+        let gap = 16.0;
+        let cols = ((screen_width() - 124.0) / (card_w + gap)).max(1.0) as usize;
+
+        let mut y = 150.0;
+        for (cost, cards) in self.deck_filter.arrange(deck) {
+            if let Some(cost) = cost {
+                draw_ui_text(
+                    &format!("Cost {}", cost),
+                    start_x,
+                    y + 16.0,
+                    17.0,
+                    candle_color(),
+                );
+                y += deck_filter::COST_HEADING_H;
+            }
+            for (i, card) in cards.iter().enumerate() {
+                let x = start_x + ((i % cols) as f32 * (card_w + gap));
+                let row_y = y + ((i / cols) as f32 * (card_h + gap));
+                draw_card_frame(card, x, row_y, card_w, card_h, false);
+            }
+            y += cards.len().div_ceil(cols) as f32 * (card_h + gap);
+        }
+    }
+}
+
+/// Warning modal shown before a traumatized adventurer joins the party
+fn draw_deploy_confirm(adv: &Adventurer) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 150),
+    );
+    let (x, y, w, h) = modal_rect();
+    panel(x, y, w, h, "DEPLOY TRAUMATIZED ADVENTURER?");
+
+    let mut line_y = y + 60.0;
+    for trauma in &adv.traumas {
+        draw_ui_text(
+            &format!("{} is {}: {}", adv.name, trauma.name(), trauma.penalty()),
+            x + 24.0,
+            line_y,
+            18.0,
+            danger_color(),
+        );
+        line_y += 24.0;
+    }
+
+    let (yes_x, yes_y, yes_w, yes_h) = modal_confirm_button_rect();
+    draw_action_button("[Y] Deploy Anyway", yes_x, yes_y, yes_w, yes_h, true);
+    let (no_x, no_y, no_w, no_h) = modal_cancel_button_rect();
+    draw_action_button("[N] Keep Home", no_x, no_y, no_w, no_h, true);
+}
+
+/// Confirmation modal before an adventurer leaves the roster for good
+fn draw_dismiss_confirm(adv: &Adventurer) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 150),
+    );
+    let (x, y, w, h) = modal_rect();
+    panel(x, y, w, h, "DISMISS ADVENTURER?");
+    draw_ui_text(
+        &format!("{} will leave the kingdom's service for good.", adv.name),
+        x + 24.0,
+        y + 64.0,
+        18.0,
+        text_color(),
+    );
+    draw_ui_text(
+        &format!("Morale -{}", DISMISS_MORALE_COST),
+        x + 24.0,
+        y + 90.0,
+        18.0,
+        danger_color(),
+    );
+
+    let (yes_x, yes_y, yes_w, yes_h) = modal_confirm_button_rect();
+    draw_action_button("[Y] Dismiss", yes_x, yes_y, yes_w, yes_h, true);
+    let (no_x, no_y, no_w, no_h) = modal_cancel_button_rect();
+    draw_action_button("[N] Keep", no_x, no_y, no_w, no_h, true);
+}
+
+/// Text entry for the export/import file path
+fn draw_path_prompt(prompt: &PathPrompt) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 150),
+    );
+    let (x, y, w, h) = modal_rect();
+    panel(x, y, w, h, prompt.mode.title());
+    draw_ui_text("File path:", x + 24.0, y + 64.0, 18.0, muted_text_color());
+    draw_rectangle(x + 24.0, y + 76.0, w - 48.0, 34.0, table_color());
+    draw_rectangle_lines(x + 24.0, y + 76.0, w - 48.0, 34.0, 1.0, border_color());
+    draw_ui_text(
+        &format!("{}_", prompt.path),
+        x + 34.0,
+        y + 99.0,
+        18.0,
+        text_color(),
+    );
+    draw_ui_text(
+        "[Enter] Confirm   [Backspace] Delete   [Esc] Cancel",
+        x + 24.0,
+        y + h - 26.0,
+        16.0,
+        candle_color(),
+    );
+}
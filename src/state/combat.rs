@@ -1,12 +1,43 @@
 //! Combat state - turn-based card combat
 
-use super::{MissionState, ResultState, StateTransition};
-use crate::combat::{Card, CombatResolver, Unit};
-use crate::data::random_enemy_for_region_and_difficulty;
-use crate::kingdom::{PartyMemberState, ResolveState, TraumaType};
-use crate::missions::{MapNode, Mission};
+use super::{ResultState, StateTransition};
+use crate::combat::{Card, CombatLog, CombatResolver, Unit};
+use crate::data::{enemy_by_id, fallback_enemy, random_enemy_data};
+use crate::kingdom::{fatigued_max_hp, FormationRow, Gender, PartyMemberState, ResolveState};
+use crate::missions::{MapNode, Mission, NodeType};
+use crate::save::Settings;
 use macroquad::prelude::*;
-use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+use serde::{Deserialize, Serialize};
+
+mod deck;
+mod end_confirm;
+mod fear;
+mod play;
+mod reinforcements;
+mod snapshot;
+mod trauma_pause;
+mod turns;
+mod victory;
+mod view;
+
+pub use deck::CardPiles;
+pub use end_confirm::PendingEnd;
+pub use snapshot::CombatSnapshot;
+pub use turns::Phase;
+pub use victory::VictorySummary;
+use view::{clicked_down, combat_card_rect, end_phase_button_rect, end_turn_button_rect};
+
+/// Whole party acts each round before the enemy; false restores one member per enemy action
+const SIMULTANEOUS_PARTY_TURNS: bool = true;
+/// Cards drawn each turn before mission modifiers
+const BASE_HAND_SIZE: usize = 5;
+/// Handicap: extra energy per turn against elites, when switched on in settings
+const ELITE_ENERGY_BONUS: i32 = 1;
+
+/// Elite enemies take this percentage of their normal max HP
+const ELITE_HP_PERCENT: i32 = 125;
+/// Elite drop chances are multiplied by this
+const ELITE_DROP_MULTIPLIER: f32 = 2.0;
 
 /// Turn-based combat state with party support
 pub struct CombatState {
@@ -14,12 +45,34 @@ pub struct CombatState {
     pub players: Vec<Unit>,
     /// Index of the currently active player
     pub current_player_idx: usize,
+    /// Where the current round stands
+    pub phase: Phase,
+    /// Party phase mode (see `SIMULTANEOUS_PARTY_TURNS`)
+    pub simultaneous_turns: bool,
+    /// Show the hand cheapest first, attacks ahead of other cards at the same cost
+    pub sort_hand: bool,
     pub enemy: Unit,
+    /// Reinforcements that have arrived, each stepping up as the foe in front falls
+    pub reserve: Vec<Unit>,
+    /// Party member the telegraphed enemy intent will land on
+    pub intent_target: usize,
     pub hand: Vec<Card>,
+    /// Each member's draw and discard piles, by party index
+    pub piles: Vec<CardPiles>,
     pub energy: i32,
     pub max_energy: i32,
+    /// Energy each member banked for their next turn, by party index
+    pub banked_energy: Vec<i32>,
+    /// Cards drawn at the start of each member's turn
+    pub hand_size: usize,
     pub turn: usize,
     pub selected_card: Option<usize>,
+    /// End action waiting on the enemy forecast to be confirmed
+    pub pending_end: Option<PendingEnd>,
+    /// Hand index already warned that it would have no effect; playing it again goes through
+    pub waste_warned: Option<usize>,
+    /// Hand index of a card just refused, with seconds left on its shake
+    pub refused_card: Option<(usize, f32)>,
     pub resolver: CombatResolver,
     /// Mission to return to after combat victory
     pub return_mission: Option<MissionContext>,
@@ -28,10 +81,22 @@ pub struct CombatState {
     pub stress_gained: Vec<i32>,
     /// Short-lived UI feedback for clicks and keyboard actions
     pub feedback: Option<(String, f32)>,
+    /// Spoils overlay shown after the enemy falls, before leaving combat
+    pub victory: Option<VictorySummary>,
+    /// Afflictions raised this fight, drained by `Game` into toasts
+    pub notifications: Vec<String>,
+    /// Trauma announcement holding the fight until dismissed
+    pub trauma_interrupt: Option<String>,
+    /// Hold the fight on that announcement; players can turn this off in settings
+    pub trauma_pause: bool,
+    /// The active member froze with fear; their turn passes on the next update
+    pub frozen_by_fear: bool,
 }
 
-/// Context needed to return to a mission after combat
-#[derive(Clone)]
+/// Context needed to return to a mission after combat. It owns the expedition's map and
+/// party, moved in from `MissionState` and moved back out on victory, so a combat round trip
+/// allocates no copies of them
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MissionContext {
     pub mission: Mission,
     pub current_node: usize,
@@ -40,6 +105,8 @@ pub struct MissionContext {
     pub map_nodes: Vec<MapNode>,
     /// Nodes that have been visited
     pub visited_nodes: Vec<usize>,
+    /// Fight on the road home after the expedition is won; victory goes straight to results
+    pub homeward_ambush: bool,
 }
 
 impl MissionContext {
@@ -52,20 +119,37 @@ impl MissionContext {
 
 impl Default for CombatState {
     fn default() -> Self {
+        let base_energy = crate::data::balance().base_energy;
         Self {
             players: vec![Unit::new_player("Adventurer", 50)],
             current_player_idx: 0,
+            phase: Phase::PlayerTurn { member: 0 },
+            simultaneous_turns: SIMULTANEOUS_PARTY_TURNS,
+            sort_hand: false,
             enemy: Unit::new_enemy("Forest Beast", 30, None),
+            reserve: vec![],
+            intent_target: 0,
             hand: Card::starter_hand(),
-            energy: 3,
-            max_energy: 3,
+            piles: vec![CardPiles::default()],
+            energy: base_energy,
+            max_energy: base_energy,
+            banked_energy: vec![],
+            hand_size: BASE_HAND_SIZE,
             turn: 1,
             selected_card: None,
+            pending_end: None,
+            waste_warned: None,
+            refused_card: None,
             resolver: CombatResolver::new(),
             return_mission: None,
             damage_taken: vec![0],
             stress_gained: vec![0],
             feedback: None,
+            victory: None,
+            notifications: vec![],
+            trauma_interrupt: None,
+            trauma_pause: true,
+            frozen_by_fear: false,
         }
     }
 }
@@ -93,65 +177,129 @@ impl CombatState {
         }
     }
 
-    /// Create combat that returns to mission on victory, using party stats
-    pub fn for_mission(context: MissionContext) -> Self {
+    /// Create combat that returns to mission on victory, using party stats and the
+    /// player's combat settings
+    pub fn for_mission(context: MissionContext, settings: &Settings) -> Self {
         // Create Unit for each party member
         let players: Vec<Unit> = context
             .party_members
             .iter()
             .map(|m| {
-                let mut unit = Unit::new_player(&m.name, m.max_hp);
-                unit.hp = m.hp;
+                // Worn-out members start the fight with less to give
+                let mut unit = Unit::new_player(&m.name, fatigued_max_hp(m.max_hp, m.fatigue));
+                unit.hp = m.hp.min(unit.max_hp);
                 unit.stress = m.stress;
                 unit.image_path = m.image_path.clone();
                 unit.traumas = m.traumas.clone();
+                unit.injuries = m.injuries.clone();
                 unit.resolve_state = m.resolve_state.clone();
+                if unit.resolve_state == Some(ResolveState::Virtuous) {
+                    unit.grant_breakthrough_buff();
+                }
                 unit
             })
             .collect();
 
         let party_size = players.len();
+        let hand_size =
+            (BASE_HAND_SIZE as i32 + context.mission.hand_size_modifier).max(1) as usize;
+
+        // Use the node's pre-rolled enemy, or roll one for region and difficulty,
+        // toughened for the size of the party. Ambushers are not the node's own enemy
+        let node = context
+            .map_nodes
+            .get(context.current_node)
+            .filter(|_| !context.homeward_ambush);
+        let elite = node.is_some_and(|node| node.node_type == NodeType::Elite);
+        let mut enemy = node
+            .and_then(|node| node.enemy_id.as_deref())
+            .and_then(enemy_by_id)
+            .or_else(|| {
+                let difficulty = context
+                    .mission
+                    .node_difficulty(node.map_or(&NodeType::Combat, |node| &node.node_type));
+                random_enemy_data(&context.mission.region_id, difficulty)
+            })
+            .map(|data| data.scaled_for_party(party_size).to_unit())
+            .unwrap_or_else(fallback_enemy);
+        if elite {
+            make_elite(&mut enemy);
+        }
+        // Telegraph the real first move rather than the constructor's placeholder
+        enemy.roll_intent(1);
+        let elite_bonus = if elite && settings.elite_energy_bonus {
+            ELITE_ENERGY_BONUS
+        } else {
+            0
+        };
+        let max_energy =
+            (crate::data::balance().base_energy + context.mission.energy_modifier + elite_bonus)
+                .max(1);
 
-        // Get the current player's class to load appropriate cards
-        let class_name = context
-            .party_members
-            .first()
-            .map(|m| m.class_name.as_str())
-            .unwrap_or("Soldier");
-        let deck_additions = context
-            .party_members
-            .first()
-            .map(|m| m.deck_additions.as_slice())
-            .unwrap_or(&[]);
-        let hand = Card::load_deck_for_class(class_name, deck_additions)
-            .into_iter()
-            .take(5)
-            .collect();
-
-        // Get random enemy based on mission region and difficulty.
-        let enemy = random_enemy_for_region_and_difficulty(
-            &context.mission.region_id,
-            context.mission.combat_difficulty(),
-        );
-
-        Self {
+        let mut state = Self {
             players,
             current_player_idx: 0,
             enemy,
-            hand,
+            hand: vec![],
+            piles: vec![],
+            energy: max_energy,
+            max_energy,
+            hand_size,
             return_mission: Some(context),
             damage_taken: vec![0; party_size],
             stress_gained: vec![0; party_size],
             ..Default::default()
+        };
+        state.use_settings(settings);
+        state.intent_target = state.target_for_intent();
+        state.begin_member_turn(0);
+        if state
+            .return_mission
+            .as_ref()
+            .is_some_and(|ctx| ctx.homeward_ambush)
+        {
+            state.set_feedback("Ambushed on the road home!".to_string());
         }
+        state
+    }
+
+    /// Take the player's combat preferences: hand order, trauma pause, and log capture
+    fn use_settings(&mut self, settings: &Settings) {
+        self.sort_hand = settings.sort_hand_by_cost;
+        self.trauma_pause = !settings.skip_trauma_pause;
+        self.resolver.log = CombatLog::with_capture(settings.dump_combat_log);
     }
 
     pub fn update(&mut self) -> Option<StateTransition> {
         self.tick_feedback();
 
-        // Card selection with number keys OR mouse click
-        for i in 0..self.hand.len().min(5) {
-            let key = match i {
+        if self.victory.is_some() {
+            return self.update_victory();
+        }
+
+        if self.trauma_interrupt.is_some() {
+            self.update_trauma_interrupt();
+            return None;
+        }
+
+        if self.frozen_by_fear {
+            self.pass_frozen_turn();
+            return self.check_outcome();
+        }
+
+        if self.phase == Phase::EnemyTurn {
+            self.run_enemy_phase();
+            return self.check_outcome();
+        }
+
+        if self.pending_end.is_some() {
+            self.update_end_confirm();
+            return None;
+        }
+
+        // Card selection with number keys OR mouse click; slots follow the displayed order
+        for (slot, i) in self.display_order().into_iter().enumerate().take(5) {
+            let key = match slot {
                 0 => KeyCode::Key1,
                 1 => KeyCode::Key2,
                 2 => KeyCode::Key3,
@@ -166,11 +314,13 @@ impl CombatState {
             }
 
             // Mouse click on card
-            let (card_x, card_y, card_width, card_height) = combat_card_rect(i, self.hand.len());
+            let (card_x, card_y, card_width, card_height) = combat_card_rect(slot, self.hand.len());
             if clicked_down(card_x, card_y, card_width, card_height) {
                 if self.selected_card == Some(i) {
                     // Clicking already selected card = play it
-                    self.try_play_selected_card();
+                    if let Err(error) = self.try_play_selected_card() {
+                        self.report_play_error(error);
+                    }
                 } else {
                     self.select_card(i);
                 }
@@ -179,68 +329,54 @@ impl CombatState {
 
         // Play selected card with Enter
         if is_key_pressed(KeyCode::Enter) {
-            self.try_play_selected_card();
+            if let Err(error) = self.try_play_selected_card() {
+                self.report_play_error(error);
+            }
         }
 
         // End turn with E key or button click (button drawn in draw())
-        if is_key_pressed(KeyCode::E) {
-            self.end_turn();
+        let (end_x, end_y, end_w, end_h) = end_turn_button_rect();
+        if is_key_pressed(KeyCode::E) || clicked_down(end_x, end_y, end_w, end_h) {
+            self.request_end(PendingEnd::Turn);
+        }
+        // End the whole party phase with P or its button
+        let (phase_x, phase_y, phase_w, phase_h) = end_phase_button_rect();
+        if self.simultaneous_turns
+            && (is_key_pressed(KeyCode::P) || clicked_down(phase_x, phase_y, phase_w, phase_h))
+        {
+            self.request_end(PendingEnd::Phase);
         }
-        // End Turn button bounds
-        let end_btn_x = screen_width() - 168.0;
-        let end_btn_y = screen_height() - 58.0;
-        if clicked_down(end_btn_x, end_btn_y, 144.0, 38.0) {
-            self.end_turn();
+
+        self.check_outcome()
+    }
+
+    /// Hold on the victory overlay once the last enemy falls; leave when the whole party has fallen
+    fn check_outcome(&mut self) -> Option<StateTransition> {
+        if self.enemy.hp <= 0 && self.victory.is_none() && !self.advance_reserve() {
+            self.victory = Some(self.build_victory_summary());
+            self.settle_fallen();
         }
+        if self.victory.is_some() {
+            return None;
+        }
+        self.resolve_outcome()
+    }
 
-        // Check win/lose
+    /// Where a decided fight leads, without polling input: a fallen enemy returns to the
+    /// mission (or results outside one), a fallen party goes to results. None while undecided
+    pub(super) fn resolve_outcome(&mut self) -> Option<StateTransition> {
+        let all_down = self.players.iter().all(|p| p.hp <= 0);
+        if self.enemy.hp <= 0 || all_down {
+            self.settle_fallen();
+        }
         if self.enemy.hp <= 0 {
-            // Victory - return to mission if we came from one
-            if let Some(ctx) = &self.return_mission {
-                // Update party member states with current HP/stress
-                let updated_members: Vec<PartyMemberState> = self
-                    .players
-                    .iter()
-                    .enumerate()
-                    .map(|(i, p)| {
-                        let orig = ctx.party_members.get(i);
-                        PartyMemberState {
-                            id: orig.map(|m| m.id.clone()).unwrap_or_default(),
-                            name: p.name.clone(),
-                            hp: p.hp,
-                            max_hp: p.max_hp,
-                            stress: p.stress,
-                            image_path: p.image_path.clone(),
-                            class_name: orig
-                                .map(|m| m.class_name.clone())
-                                .unwrap_or_else(|| "Soldier".to_string()),
-                            deck_additions: orig
-                                .map(|m| m.deck_additions.clone())
-                                .unwrap_or_default(),
-                            traumas: p.traumas.clone(),
-                            resolve_state: p.resolve_state.clone(),
-                        }
-                    })
-                    .collect();
-
-                let mission_state =
-                    MissionState::from_mission_with_party(ctx.mission.clone(), updated_members)
-                        .with_node(ctx.current_node)
-                        .with_map_nodes(ctx.map_nodes.clone())
-                        .with_visited(ctx.visited_nodes.clone());
-                return Some(StateTransition::ToMission(mission_state));
-            } else {
-                // Not from mission - just show simple victory
-                let leader_id = self.players.first().map(|p| p.name.as_str()).unwrap_or("");
-                return Some(StateTransition::ToResults(ResultState::victory_for(
-                    leader_id,
-                )));
+            if self.victory.is_none() {
+                self.victory = Some(self.build_victory_summary());
             }
+            return self.leave_victorious();
         }
 
-        // Check if all players are dead
-        let all_dead = self.players.iter().all(|p| p.hp <= 0);
-        if all_dead {
+        if all_down {
             // Defeat - always go to results
             if let Some(ctx) = &self.return_mission {
                 let final_members = self.party_members_from_players(ctx);
@@ -256,63 +392,17 @@ impl CombatState {
         None
     }
 
-    /// Try to play the currently selected card
-    fn try_play_selected_card(&mut self) {
-        let Some(card_idx) = self.selected_card else {
-            self.set_feedback("Select a card first.".to_string());
-            return;
-        };
-        if card_idx >= self.hand.len() || self.current_player_idx >= self.players.len() {
-            self.selected_card = None;
-            self.set_feedback("That card is no longer available.".to_string());
-            return;
-        }
-
-        let card = self.hand[card_idx].clone();
-        let effective_cost = self.effective_card_cost(&card);
-        let can_afford = effective_cost <= self.energy;
-        let attack_blocked = card.is_attack() && self.resolver.turn_mods.attacks_disabled;
-
-        if !can_afford {
-            self.set_feedback(format!(
-                "{} needs {} energy. You have {}.",
-                card.name, effective_cost, self.energy
-            ));
-            return;
-        }
-        if attack_blocked {
-            self.set_feedback(format!("{} is blocked this turn.", card.name));
-            return;
+    /// The fight is decided: anyone still downed is beyond reviving
+    fn settle_fallen(&mut self) {
+        for player in self.players.iter_mut().filter(|p| p.hp <= 0) {
+            player.dead = true;
         }
-
-        if self.fearful_fumble(&card) {
-            self.selected_card = None;
-            self.set_feedback(format!("{} fumbled.", card.name));
-            return;
-        }
-
-        let player_name = self.players[self.current_player_idx].name.clone();
-        let card_name = card.name.clone();
-        let effects = card.effects.clone();
-        self.energy -= effective_cost;
-        self.resolver
-            .log
-            .push(format!("{} plays {}", player_name, card_name));
-
-        let player = &mut self.players[self.current_player_idx];
-        for effect in effects {
-            self.resolver.resolve(&effect, player, &mut self.enemy);
-        }
-
-        self.apply_card_turn_modifiers();
-        self.hand.remove(card_idx);
-        self.selected_card = None;
-        self.set_feedback(format!("{} played.", card_name));
     }
 
     fn select_card(&mut self, idx: usize) {
         if let Some(card) = self.hand.get(idx) {
             self.selected_card = Some(idx);
+            self.waste_warned = None;
             self.set_feedback(format!(
                 "{} selected. Click again or press Enter to play.",
                 card.name
@@ -327,97 +417,112 @@ impl CombatState {
                 self.feedback = None;
             }
         }
+        if let Some((_, time)) = &mut self.refused_card {
+            *time -= get_frame_time();
+            if *time <= 0.0 {
+                self.refused_card = None;
+            }
+        }
     }
 
     fn set_feedback(&mut self, text: String) {
         self.feedback = Some((text, 2.0));
     }
 
-    fn effective_card_cost(&self, card: &Card) -> i32 {
-        let Some(player) = self.players.get(self.current_player_idx) else {
-            return card.cost;
-        };
-
-        let mut cost = card.cost;
-        if player
-            .traumas
-            .iter()
-            .any(|t| t.trauma_type == TraumaType::Broken)
-        {
-            cost += 1;
+    /// Hand indices in the order cards are shown; play order and `selected_card` stay hand indices
+    pub fn display_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.hand.len()).collect();
+        if self.sort_hand {
+            order.sort_by_key(|idx| {
+                let card = &self.hand[*idx];
+                (self.effective_card_cost(card), !card.is_attack())
+            });
         }
-        if card
-            .effects
-            .iter()
-            .any(|e| matches!(e, crate::combat::CardEffect::Block(_)))
-            && player
-                .traumas
-                .iter()
-                .any(|t| t.trauma_type == TraumaType::Paranoid)
-        {
-            cost += 1;
-        }
-        cost
+        order
     }
 
-    fn fearful_fumble(&mut self, card: &Card) -> bool {
-        if !card.is_attack() {
-            return false;
-        }
-        let Some(player) = self.players.get_mut(self.current_player_idx) else {
-            return false;
-        };
-        if player
-            .traumas
-            .iter()
-            .any(|t| t.trauma_type == TraumaType::Fearful)
-            && macroquad_toolkit::rng::chance(0.15)
-        {
-            player.add_stress(3);
-            self.resolver
-                .log
-                .push(format!("{} hesitates and loses the attack", player.name));
-            true
-        } else {
-            false
-        }
+    /// Formation row of a player, falling back to slot order outside missions
+    fn player_row(&self, idx: usize) -> FormationRow {
+        self.return_mission
+            .as_ref()
+            .and_then(|ctx| ctx.party_members.get(idx))
+            .map(|member| member.row())
+            .unwrap_or_else(|| FormationRow::for_position(idx))
     }
 
-    fn apply_card_turn_modifiers(&mut self) {
-        if self.resolver.turn_mods.energy_to_gain > 0 {
-            self.energy += self.resolver.turn_mods.energy_to_gain;
-            self.resolver.turn_mods.energy_to_gain = 0;
+    /// Members the telegraphed intent would drop to 0 HP through their block
+    pub fn lethal_targets(&self) -> Vec<usize> {
+        let incoming = self.enemy.intent_damage();
+        if incoming <= 0 {
+            return vec![];
         }
+        let struck: Vec<usize> = if self.enemy.intent.hits_whole_party() {
+            (0..self.players.len()).collect()
+        } else {
+            vec![self.intent_target]
+        };
+        struck
+            .into_iter()
+            .filter(|idx| {
+                self.players.get(*idx).is_some_and(|target| {
+                    target.hp > 0 && target.damage_through_block(incoming) >= target.hp
+                })
+            })
+            .collect()
+    }
 
-        if self.resolver.turn_mods.cards_to_draw > 0 {
-            let count = self.resolver.turn_mods.cards_to_draw;
-            self.draw_extra_cards(count);
-            self.resolver.turn_mods.cards_to_draw = 0;
-        }
+    /// Active member and enemy as they would stand after the selected card resolves
+    pub fn selected_card_preview(&self) -> Option<(Unit, Unit)> {
+        let card = self.hand.get(self.selected_card?)?;
+        self.preview_card(card)
     }
 
-    fn draw_extra_cards(&mut self, count: i32) {
-        let deck = self.deck_for_current_player();
-        let mut remaining = count;
-        for card in deck {
-            if self.hand.len() >= 7 || remaining <= 0 {
-                break;
-            }
-            if !self.hand.iter().any(|c| c.id == card.id) {
-                self.hand.push(card);
-                remaining -= 1;
-            }
+    /// Active member and enemy as they would stand after `card` resolves
+    pub(super) fn preview_card(&self, card: &Card) -> Option<(Unit, Unit)> {
+        let player = self.players.get(self.current_player_idx)?;
+        let mut resolver = CombatResolver::new();
+        resolver.turn_mods = self.resolver.turn_mods.clone();
+        if card.is_x_cost() {
+            resolver.turn_mods.x_energy = self.energy - self.effective_card_cost(card);
         }
+        Some(resolver.preview(&card.effects, player, &self.enemy))
     }
 
-    fn deck_for_current_player(&self) -> Vec<Card> {
-        let (class_name, deck_additions) = self
-            .return_mission
-            .as_ref()
-            .and_then(|ctx| ctx.party_members.get(self.current_player_idx))
-            .map(|m| (m.class_name.as_str(), m.deck_additions.as_slice()))
-            .unwrap_or(("Soldier", &[]));
-        Card::load_deck_for_class(class_name, deck_additions)
+    /// Choose the party member the enemy's current intent lands on.
+    ///
+    /// The intent decides which row it can reach; the enemy's strategy picks within it.
+    fn target_for_intent(&self) -> usize {
+        // Untargeted intents land on the active member, or the foremost survivor if they fell
+        let fallback = if self
+            .players
+            .get(self.current_player_idx)
+            .is_some_and(|p| p.hp > 0)
+        {
+            self.current_player_idx
+        } else {
+            self.first_living_member()
+                .unwrap_or(self.current_player_idx)
+        };
+        let Some(row) = self.enemy.intent.target_row(&self.enemy.ai_pattern) else {
+            return fallback;
+        };
+        let living: Vec<(usize, i32)> = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, player)| player.hp > 0)
+            .map(|(idx, player)| (idx, player.hp))
+            .collect();
+        let in_row: Vec<(usize, i32)> = living
+            .iter()
+            .copied()
+            .filter(|(idx, _)| self.player_row(*idx) == row)
+            .collect();
+        let candidates = if in_row.is_empty() { living } else { in_row };
+        self.enemy
+            .target_strategy
+            .pick(&candidates)
+            .unwrap_or(fallback)
     }
 
     fn party_members_from_players(&self, ctx: &MissionContext) -> Vec<PartyMemberState> {
@@ -429,716 +534,253 @@ impl CombatState {
                 PartyMemberState {
                     id: orig.map(|m| m.id.clone()).unwrap_or_default(),
                     name: p.name.clone(),
+                    gender: orig.map(|m| m.gender.clone()).unwrap_or(Gender::Male),
                     hp: p.hp,
-                    max_hp: p.max_hp,
+                    // The fight ran on a fatigue-reduced max; the member keeps their own
+                    max_hp: orig.map(|m| m.max_hp).unwrap_or(p.max_hp),
                     stress: p.stress,
+                    fatigue: orig.map(|m| m.fatigue).unwrap_or_default(),
+                    level: orig.map(|m| m.level).unwrap_or(1),
                     image_path: p.image_path.clone(),
                     class_name: orig
                         .map(|m| m.class_name.clone())
                         .unwrap_or_else(|| "Soldier".to_string()),
                     deck_additions: orig.map(|m| m.deck_additions.clone()).unwrap_or_default(),
+                    traits: orig.map(|m| m.traits.clone()).unwrap_or_default(),
+                    injuries: orig.map(|m| m.injuries.clone()).unwrap_or_default(),
                     traumas: p.traumas.clone(),
                     resolve_state: p.resolve_state.clone(),
+                    position: orig.map(|m| m.position).unwrap_or(i),
+                    xp_earned: orig.map(|m| m.xp_earned).unwrap_or_default(),
+                    kills: orig.map(|m| m.kills).unwrap_or_default(),
+                    bonded_partners: orig.map(|m| m.bonded_partners).unwrap_or_default(),
                 }
             })
             .collect()
     }
+}
 
-    fn end_turn(&mut self) {
-        let actor_name = self
-            .players
-            .get(self.current_player_idx)
-            .map(|player| player.name.clone())
-            .unwrap_or_else(|| "Adventurer".to_string());
-        let old_intent = self.enemy.intent.description();
-
-        // Current player status tick and block reset
-        if let Some(player) = self.players.get_mut(self.current_player_idx) {
-            player.tick_statuses();
-            player.block = 0;
-        }
-
-        // Enemy Action
-        let (dmg, stress) = self.enemy.execute_intent();
-        let enemy_acted = dmg > 0 || stress > 0;
-
-        // Apply damage to current player
-        let mut actual_damage = 0;
-        if dmg > 0 {
-            if let Some(player) = self.players.get_mut(self.current_player_idx) {
-                let actual = player.take_damage(dmg);
-                actual_damage = actual;
-                if self.current_player_idx < self.damage_taken.len() {
-                    self.damage_taken[self.current_player_idx] += actual;
-                }
-            }
-        }
-
-        // Apply stress with resistance (uses resolver's turn mods)
-        let base_stress = 2 + stress;
-        if let Some(player) = self.players.get_mut(self.current_player_idx) {
-            self.resolver.apply_stress_to_player(player, base_stress);
-            if self.current_player_idx < self.stress_gained.len() {
-                self.stress_gained[self.current_player_idx] += base_stress;
-            }
-        }
-
-        // Enemy status tick
-        self.enemy.tick_statuses();
-        self.enemy.block = 0;
-
-        // Reset turn modifiers and track enemy action for next turn
-        self.resolver.end_turn(enemy_acted);
-
-        // Cycle to next living party member
-        if self.players.len() > 1 {
-            let start_idx = self.current_player_idx;
-            loop {
-                self.current_player_idx = (self.current_player_idx + 1) % self.players.len();
-                // Stop if alive or back to start
-                if self.players[self.current_player_idx].hp > 0
-                    || self.current_player_idx == start_idx
-                {
-                    break;
-                }
-            }
-        }
-
-        // Next Turn
-        self.turn += 1;
-        self.energy = self.max_energy + self.resolver.turn_mods.start_turn();
-
-        // Draw class-appropriate cards for current player
-        self.hand = self.deck_for_current_player().into_iter().take(5).collect();
-
-        // Roll new enemy intent for next turn
-        self.enemy.roll_intent(self.turn);
-
-        self.resolver.log.push(format!(
-            "End turn: {} resolved. {} took {} damage and {} stress.",
-            old_intent, actor_name, actual_damage, base_stress
-        ));
-        self.resolver.log.push(format!(
-            "Turn {} begins. Enemy intent: {}.",
-            self.turn,
-            self.enemy.intent.description()
-        ));
-        self.set_feedback(format!("Turn {} begins.", self.turn));
+/// Harden an enemy into an elite: more HP and better drop odds
+fn make_elite(enemy: &mut Unit) {
+    enemy.name = format!("Elite {}", enemy.name);
+    enemy.max_hp = enemy.max_hp * ELITE_HP_PERCENT / 100;
+    enemy.hp = enemy.max_hp;
+    for drop in &mut enemy.drops {
+        drop.chance = (drop.chance * ELITE_DROP_MULTIPLIER).min(1.0);
     }
+}
 
-    pub fn draw(&self, textures: &std::collections::HashMap<String, Texture2D>) {
-        let region_id = if let Some(ctx) = &self.return_mission {
-            &ctx.mission.region_id
-        } else {
-            "dark_woods"
-        };
-
-        let bg_path = format!("assets/images/regions/{}.png", region_id);
-        if let Some(tex) = textures.get(&bg_path) {
-            draw_texture_ex(
-                tex,
-                0.0,
-                0.0,
-                WHITE,
-                DrawTextureParams {
-                    dest_size: Some(vec2(screen_width(), screen_height())),
-                    ..Default::default()
-                },
-            );
-        } else {
-            clear_background(Color::from_rgba(9, 7, 6, 255));
-        }
-        draw_rectangle(
-            0.0,
-            0.0,
-            screen_width(),
-            screen_height(),
-            Color::from_rgba(0, 0, 0, 178),
-        );
-
-        draw_header(self.turn);
-        draw_party_panel(
-            &self.players,
-            self.current_player_idx,
-            self.energy,
-            self.max_energy,
-            textures,
-        );
-        draw_enemy_stage(&self.enemy, textures);
-
-        let preview_idx = hovered_card_index(&self.hand).or(self.selected_card);
-        draw_report_panel(self, preview_idx);
-        draw_feedback_panel(self.feedback.as_ref());
-
-        let mut hovered_card_idx: Option<usize> = None;
-        for (i, card) in self.hand.iter().enumerate() {
-            let (x, y, w, h) = combat_card_rect(i, self.hand.len());
-            let is_hovered = crate::ui::is_mouse_over(x, y, w, h);
-            if is_hovered {
-                hovered_card_idx = Some(i);
-            }
-            let effective_cost = self.effective_card_cost(card);
-            let can_afford = effective_cost <= self.energy;
-            let attack_blocked = card.is_attack() && self.resolver.turn_mods.attacks_disabled;
-            draw_combat_card(
-                i,
-                card,
-                self.hand.len(),
-                self.selected_card == Some(i),
-                is_hovered,
-                can_afford && !attack_blocked,
-                attack_blocked,
-                effective_cost,
-                textures,
-            );
-        }
-
-        let end_btn_x = screen_width() - 168.0;
-        let end_btn_y = screen_height() - 58.0;
-        draw_action_button("End Turn", end_btn_x, end_btn_y, 144.0, 38.0);
-        draw_ui_text(
-            "Shortcuts: 1-5 Select - Enter Play - E End Turn",
-            24.0,
-            screen_height() - 26.0,
-            14.0,
-            muted_text_color(),
+#[cfg(test)]
+mod tests {
+    use super::play::PlayError;
+    use super::*;
+    use crate::kingdom::{Adventurer, AdventurerClass, MAX_FATIGUE};
+
+    fn boar_fight(hero: &Adventurer) -> MissionContext {
+        MissionContext {
+            mission: Mission::first_mission(),
+            current_node: 0,
+            party_members: vec![PartyMemberState::from_adventurer(hero)],
+            map_nodes: vec![MapNode {
+                id: 0,
+                node_type: NodeType::Combat,
+                connections: vec![],
+                layer: 0,
+                position: 0,
+                enemy_id: Some("wild_boar".to_string()),
+            }],
+            visited_nodes: vec![0],
+            homeward_ambush: false,
+        }
+    }
+
+    #[test]
+    fn mission_combat_opens_with_the_enemys_first_turn_intent() {
+        let hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        let combat = CombatState::for_mission(boar_fight(&hero), &Settings::default());
+        let base = combat.enemy.base_damage;
+        // Ravagers open turn 1 with a heavy swing
+        assert_eq!(
+            combat.enemy.intent,
+            crate::combat::EnemyIntent::Attack(base + 1)
         );
-
-        if let Some(idx) = hovered_card_idx {
-            if let Some(card) = self.hand.get(idx) {
-                crate::ui::card_tooltip(&card.name, &card.description);
-            }
-        }
     }
-}
-
-fn draw_header(turn: usize) {
-    draw_rectangle(
-        0.0,
-        0.0,
-        screen_width(),
-        72.0,
-        Color::from_rgba(8, 7, 6, 232),
-    );
-    draw_line(0.0, 72.0, screen_width(), 72.0, 2.0, border_color());
-    draw_ui_text("COMBAT", 24.0, 42.0, 34.0, title_color());
-    draw_ui_text(&format!("Turn {}", turn), 188.0, 42.0, 20.0, candle_color());
-}
 
-fn draw_party_panel(
-    players: &[Unit],
-    current_player_idx: usize,
-    energy: i32,
-    max_energy: i32,
-    textures: &std::collections::HashMap<String, Texture2D>,
-) {
-    panel(24.0, 92.0, 260.0, 328.0, "PLAYER AREA");
-    let Some(active) = players.get(current_player_idx) else {
-        return;
-    };
-
-    draw_ui_text(&active.name, 44.0, 148.0, 24.0, title_color());
-    draw_ui_text(
-        &format!(
-            "HP {}/{}    Block {}",
-            active.hp, active.max_hp, active.block
-        ),
-        44.0,
-        178.0,
-        16.0,
-        text_color(),
-    );
-    draw_ui_text(
-        &format!(
-            "Stress {}    Energy {}/{}",
-            active.stress, energy, max_energy
-        ),
-        44.0,
-        204.0,
-        16.0,
-        muted_text_color(),
-    );
-    if let Some(resolve) = &active.resolve_state {
-        let (label, color) = match resolve {
-            ResolveState::Virtuous => ("Virtuous", ready_color()),
-            ResolveState::Afflicted => ("Afflicted", danger_color()),
+    #[test]
+    fn elite_handicap_setting_adds_energy_against_elites() {
+        let hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        let mut ctx = boar_fight(&hero);
+        ctx.map_nodes[0].node_type = NodeType::Elite;
+        let plain = CombatState::for_mission(ctx.clone(), &Settings::default());
+        let handicap = Settings {
+            elite_energy_bonus: true,
+            ..Settings::default()
         };
-        draw_ui_text(label, 44.0, 230.0, 16.0, color);
-    }
-
-    draw_ui_text("Party", 44.0, 268.0, 16.0, candle_color());
-    for (i, player) in players.iter().enumerate().take(4) {
-        let y = 300.0 + (i as f32 * 30.0);
-        let marker = if i == current_player_idx { ">" } else { " " };
-        draw_ui_text(marker, 44.0, y, 15.0, candle_color());
-        if let Some(path) = &player.image_path {
-            if let Some(tex) = textures.get(path) {
-                draw_texture_ex(
-                    tex,
-                    64.0,
-                    y - 20.0,
-                    if player.hp <= 0 {
-                        Color::from_rgba(90, 90, 90, 255)
-                    } else {
-                        WHITE
-                    },
-                    DrawTextureParams {
-                        dest_size: Some(vec2(22.0, 22.0)),
-                        ..Default::default()
-                    },
-                );
-            }
-        }
-        draw_ui_text(
-            &format!("{}  {}/{}", player.name, player.hp, player.max_hp),
-            94.0,
-            y,
-            14.0,
-            if player.hp <= 0 {
-                danger_color()
-            } else {
-                text_color()
-            },
-        );
+        let helped = CombatState::for_mission(ctx, &handicap);
+        assert_eq!(helped.max_energy, plain.max_energy + ELITE_ENERGY_BONUS);
+
+        let normal_fight = CombatState::for_mission(boar_fight(&hero), &handicap);
+        assert_eq!(normal_fight.max_energy, plain.max_energy);
+    }
+
+    #[test]
+    fn fatigued_members_enter_combat_with_less_max_hp() {
+        let mut hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        let rested = CombatState::for_mission(boar_fight(&hero), &Settings::default());
+        assert_eq!(rested.players[0].max_hp, hero.max_hp);
+
+        hero.add_fatigue(MAX_FATIGUE);
+        let tired = CombatState::for_mission(boar_fight(&hero), &Settings::default());
+        assert!(tired.players[0].max_hp < hero.max_hp);
+        assert!(tired.players[0].hp <= tired.players[0].max_hp);
+    }
+
+    #[test]
+    fn fatigue_does_not_shrink_max_hp_across_fights() {
+        let mut hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        hero.add_fatigue(MAX_FATIGUE);
+        let mut ctx = boar_fight(&hero);
+        for _ in 0..2 {
+            let combat = CombatState::for_mission(ctx.clone(), &Settings::default());
+            ctx.party_members = combat.party_members_from_players(&ctx);
+        }
+        assert_eq!(ctx.party_members[0].max_hp, hero.max_hp);
+        assert_eq!(ctx.party_members[0].fatigue, hero.fatigue);
+    }
+
+    #[test]
+    fn won_mission_fight_returns_to_the_mission() {
+        let hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        let mut combat = CombatState::for_mission(boar_fight(&hero), &Settings::default());
+        combat.enemy.hp = 0;
+        assert!(matches!(
+            combat.resolve_outcome(),
+            Some(StateTransition::ToMission(_))
+        ));
     }
-}
 
-fn draw_enemy_stage(enemy: &Unit, textures: &std::collections::HashMap<String, Texture2D>) {
-    panel(308.0, 92.0, 644.0, 204.0, "ENEMY AREA");
-    let center_x = 630.0;
-
-    if let Some(path) = &enemy.image_path {
-        if let Some(tex) = textures.get(path) {
-            draw_texture_ex(
-                tex,
-                center_x - 68.0,
-                128.0,
-                WHITE,
-                DrawTextureParams {
-                    dest_size: Some(vec2(136.0, 136.0)),
-                    ..Default::default()
-                },
-            );
-        }
-    } else {
-        draw_circle(center_x, 190.0, 54.0, Color::from_rgba(62, 44, 38, 255));
+    #[test]
+    fn won_fight_outside_a_mission_goes_to_results() {
+        let mut combat = CombatState::default();
+        combat.enemy.hp = 0;
+        assert!(matches!(
+            combat.resolve_outcome(),
+            Some(StateTransition::ToResults(_))
+        ));
     }
 
-    let name_w = measure_ui_text(&enemy.name, None, 26, 1.0).width;
-    draw_ui_text(
-        &enemy.name,
-        center_x - name_w / 2.0,
-        128.0,
-        26.0,
-        title_color(),
-    );
-    let hp = format!("HP {}/{}    Block {}", enemy.hp, enemy.max_hp, enemy.block);
-    let hp_w = measure_ui_text(&hp, None, 17, 1.0).width;
-    draw_ui_text(&hp, center_x - hp_w / 2.0, 280.0, 17.0, text_color());
-
-    let intent = format!("Intent: {}", enemy.intent.description());
-    let intent_color = match &enemy.intent {
-        crate::combat::EnemyIntent::Attack(_) => danger_color(),
-        crate::combat::EnemyIntent::Block(_) => info_color(),
-        crate::combat::EnemyIntent::Buff => candle_color(),
-        crate::combat::EnemyIntent::Debuff => mystery_color(),
-        crate::combat::EnemyIntent::Unknown => muted_text_color(),
-    };
-    draw_rectangle(720.0, 144.0, 196.0, 86.0, Color::from_rgba(22, 18, 16, 220));
-    draw_rectangle_lines(720.0, 144.0, 196.0, 86.0, 1.0, intent_color);
-    draw_ui_text("NEXT", 740.0, 172.0, 16.0, muted_text_color());
-    draw_wrapped_text(&intent, 740.0, 202.0, 156.0, 20.0, intent_color);
-
-    if !enemy.statuses.is_empty() {
-        let mut x = 332.0;
-        for status in enemy.statuses.iter().take(4) {
-            draw_ui_text(
-                &format!("{:?} {}", status.effect_type, status.duration),
-                x,
-                274.0,
-                14.0,
-                ready_color(),
-            );
-            x += 112.0;
+    #[test]
+    fn fallen_party_is_defeated() {
+        let hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        let mut combat = CombatState::for_mission(boar_fight(&hero), &Settings::default());
+        for player in &mut combat.players {
+            player.hp = 0;
         }
+        assert!(matches!(
+            combat.resolve_outcome(),
+            Some(StateTransition::ToResults(_))
+        ));
     }
-}
 
-fn draw_report_panel(state: &CombatState, preview_idx: Option<usize>) {
-    panel(308.0, 314.0, 644.0, 106.0, "BATTLE REPORT");
-    if let Some(idx) = preview_idx {
-        if let Some(card) = state.hand.get(idx) {
-            draw_ui_text(&card.name, 330.0, 362.0, 20.0, candle_color());
-            draw_wrapped_text(
-                &card_preview(state, card),
-                330.0,
-                390.0,
-                590.0,
-                15.0,
-                text_color(),
-            );
-            return;
-        }
+    #[test]
+    fn undecided_fight_stays_in_combat() {
+        let mut combat = CombatState::default();
+        assert!(combat.resolve_outcome().is_none());
     }
 
-    let mut y = 358.0;
-    let mut drew_any = false;
-    for line in state.resolver.log.iter().rev().take(3).rev() {
-        draw_ui_text(line, 330.0, y, 15.0, muted_text_color());
-        y += 22.0;
-        drew_any = true;
-    }
-    if !drew_any {
-        let Some(player) = state.players.get(state.current_player_idx) else {
-            return;
+    /// Three members with the middle one down
+    fn mixed_party() -> CombatState {
+        let mut combat = CombatState {
+            players: vec![
+                Unit::new_player("Ada", 30),
+                Unit::new_player("Bram", 30),
+                Unit::new_player("Cole", 30),
+            ],
+            damage_taken: vec![0; 3],
+            stress_gained: vec![0; 3],
+            ..Default::default()
         };
-        draw_ui_text(
-            &intent_warning(&player.name, &state.enemy.intent),
-            330.0,
-            372.0,
-            16.0,
-            muted_text_color(),
-        );
-    }
-}
-
-fn draw_feedback_panel(feedback: Option<&(String, f32)>) {
-    let Some((message, time_left)) = feedback else {
-        return;
-    };
-
-    let alpha = ((*time_left / 2.0).clamp(0.0, 1.0) * 210.0) as u8;
-    let width = measure_ui_text(message, None, 18, 1.0).width + 42.0;
-    let x = (screen_width() - width) / 2.0;
-    let y = 432.0;
-    draw_rectangle(x, y, width, 42.0, Color::from_rgba(28, 21, 14, alpha));
-    draw_rectangle_lines(x, y, width, 42.0, 1.0, candle_color());
-    draw_ui_text(message, x + 20.0, y + 27.0, 18.0, text_color());
-}
-
-fn draw_combat_card(
-    i: usize,
-    card: &Card,
-    hand_len: usize,
-    selected: bool,
-    hovered: bool,
-    can_play: bool,
-    attack_blocked: bool,
-    effective_cost: i32,
-    textures: &std::collections::HashMap<String, Texture2D>,
-) {
-    let (x, y, w, h) = combat_card_rect(i, hand_len);
-    let accent = card_accent(card);
-    let border = if selected {
-        candle_color()
-    } else if hovered && can_play {
-        ready_color()
-    } else if !can_play {
-        danger_color()
-    } else {
-        accent
-    };
-
-    draw_rectangle(x, y, w, h, Color::from_rgba(18, 16, 14, 245));
-    draw_rectangle_lines(x, y, w, h, if selected { 3.0 } else { 2.0 }, border);
-    draw_rectangle(
-        x + 6.0,
-        y + 6.0,
-        w - 12.0,
-        26.0,
-        Color::from_rgba(38, 32, 26, 246),
-    );
-    draw_ui_text(
-        &effective_cost.to_string(),
-        x + 14.0,
-        y + 25.0,
-        20.0,
-        if can_play {
-            candle_color()
-        } else {
-            danger_color()
-        },
-    );
-    draw_ui_text(
-        card_type(card),
-        x + w - 64.0,
-        y + 24.0,
-        13.0,
-        muted_text_color(),
-    );
-
-    let art_x = x + 8.0;
-    let art_y = y + 38.0;
-    let art_w = w - 16.0;
-    let art_h = 78.0;
-    draw_rectangle(
-        art_x,
-        art_y,
-        art_w,
-        art_h,
-        Color::from_rgba(42, 38, 34, 255),
-    );
-    if let Some(path) = &card.image_path {
-        if let Some(tex) = textures.get(path) {
-            draw_texture_ex(
-                tex,
-                art_x,
-                art_y,
-                if attack_blocked {
-                    Color::from_rgba(130, 105, 140, 255)
-                } else {
-                    WHITE
-                },
-                DrawTextureParams {
-                    dest_size: Some(vec2(art_w, art_h)),
-                    ..Default::default()
-                },
-            );
-        }
+        combat.players[1].hp = 0;
+        combat
+    }
+
+    #[test]
+    fn fallen_member_cannot_play_cards() {
+        let mut combat = mixed_party();
+        combat.current_player_idx = 1;
+        combat.energy = 10;
+        combat.selected_card = Some(0);
+        assert!(matches!(
+            combat.try_play_selected_card(),
+            Err(PlayError::Fallen(_))
+        ));
+        assert_eq!(combat.energy, 10);
     }
-    draw_rectangle(art_x, art_y, art_w, art_h, Color::from_rgba(0, 0, 0, 55));
-
-    draw_ui_text(&card.name, x + 10.0, y + 136.0, 15.0, text_color());
-    let status = if attack_blocked {
-        "Blocked this turn"
-    } else if can_play {
-        "Ready"
-    } else {
-        "Need energy"
-    };
-    draw_ui_text(
-        status,
-        x + 10.0,
-        y + 158.0,
-        12.0,
-        if can_play {
-            ready_color()
-        } else {
-            danger_color()
-        },
-    );
-    draw_wrapped_text(
-        &card.description,
-        x + 10.0,
-        y + 178.0,
-        w - 20.0,
-        11.0,
-        muted_text_color(),
-    );
-}
-
-fn draw_action_button(label: &str, x: f32, y: f32, w: f32, h: f32) {
-    let hovered = crate::ui::is_mouse_over(x, y, w, h);
-    let pressed = clicked_down(x, y, w, h);
-    let fill = if pressed {
-        Color::from_rgba(130, 92, 39, 255)
-    } else if hovered {
-        Color::from_rgba(95, 67, 31, 245)
-    } else {
-        Color::from_rgba(70, 49, 27, 238)
-    };
-    draw_rectangle(x, y, w, h, fill);
-    draw_rectangle_lines(x, y, w, h, 1.0, candle_color());
-    let tw = measure_ui_text(label, None, 16, 1.0).width;
-    draw_ui_text(label, x + (w - tw) / 2.0, y + 24.0, 16.0, text_color());
-}
 
-fn clicked_down(x: f32, y: f32, w: f32, h: f32) -> bool {
-    crate::ui::is_mouse_over(x, y, w, h) && is_mouse_button_pressed(MouseButton::Left)
-}
-
-fn panel(x: f32, y: f32, w: f32, h: f32, title: &str) {
-    draw_rectangle(x, y, w, h, Color::from_rgba(13, 11, 10, 210));
-    draw_rectangle(x, y, w, 32.0, Color::from_rgba(42, 30, 18, 222));
-    draw_rectangle_lines(x, y, w, h, 1.0, border_color());
-    draw_ui_text(title, x + 14.0, y + 22.0, 15.0, candle_color());
-}
-
-fn combat_card_rect(i: usize, hand_len: usize) -> (f32, f32, f32, f32) {
-    let card_w = 142.0;
-    let card_h = 202.0;
-    let gap = 14.0;
-    let count = hand_len.max(1).min(5) as f32;
-    let total_w = count * card_w + (count - 1.0) * gap;
-    let x = (screen_width() - total_w) / 2.0 + (i as f32 * (card_w + gap));
-    (x, screen_height() - 244.0, card_w, card_h)
-}
-
-fn hovered_card_index(hand: &[Card]) -> Option<usize> {
-    for i in 0..hand.len().min(5) {
-        let (x, y, w, h) = combat_card_rect(i, hand.len());
-        if crate::ui::is_mouse_over(x, y, w, h) {
-            return Some(i);
-        }
-    }
-    None
-}
+    #[test]
+    fn turn_rotation_never_lands_on_the_fallen() {
+        let mut combat = mixed_party();
+        assert_eq!(combat.next_rotating_member(0), Some(2));
+        assert_eq!(combat.next_rotating_member(2), Some(0));
+        assert_eq!(combat.next_living_member(0), Some(2));
 
-fn card_preview(state: &CombatState, card: &Card) -> String {
-    let player = state.players.get(state.current_player_idx);
-    let mut parts = Vec::new();
-    for effect in &card.effects {
-        match effect {
-            crate::combat::CardEffect::Damage(amount) => parts.push(format!(
-                "Deal {} damage to {}. Enemy HP after: {}/{}.",
-                amount,
-                state.enemy.name,
-                (state.enemy.hp - amount).max(0),
-                state.enemy.max_hp
-            )),
-            crate::combat::CardEffect::Block(amount) => parts.push(format!(
-                "Gain {} Block. Block after: {}.",
-                amount,
-                player.map(|p| p.block + amount).unwrap_or(*amount)
-            )),
-            crate::combat::CardEffect::Heal(amount) => parts.push(format!(
-                "Heal {} HP. HP after: {}/{}.",
-                amount,
-                player
-                    .map(|p| (p.hp + amount).min(p.max_hp))
-                    .unwrap_or(*amount),
-                player.map(|p| p.max_hp).unwrap_or(*amount)
-            )),
-            crate::combat::CardEffect::ReduceStress(amount) => {
-                parts.push(format!("Reduce stress by {}.", amount));
-            }
-            crate::combat::CardEffect::DrawCards(amount) => {
-                parts.push(format!("Draw {} card(s).", amount));
-            }
-            crate::combat::CardEffect::GainEnergy(amount) => {
-                parts.push(format!("Gain {} energy this turn.", amount));
-            }
-            crate::combat::CardEffect::EnemyStress(amount) => {
-                parts.push(format!("Apply {} stress to the enemy.", amount));
-            }
-            crate::combat::CardEffect::ApplyStatus {
-                effect_type,
-                duration,
-                ..
-            } => parts.push(format!("Apply {:?} for {} turn(s).", effect_type, duration)),
-            _ => parts.push(card.description.clone()),
-        }
-    }
-    if parts.is_empty() {
-        card.description.clone()
-    } else {
-        parts.join(" ")
+        combat.players[2].hp = 0;
+        assert_eq!(combat.next_rotating_member(0), Some(0));
+        combat.players[0].hp = 0;
+        assert_eq!(combat.next_rotating_member(0), None);
     }
-}
 
-fn intent_warning(player_name: &str, intent: &crate::combat::EnemyIntent) -> String {
-    match intent {
-        crate::combat::EnemyIntent::Attack(amount) => {
-            format!(
-                "{} will take {} damage unless blocked.",
-                player_name, amount
-            )
-        }
-        crate::combat::EnemyIntent::Block(amount) => {
-            format!("{} will gain {} Block if left alone.", "Enemy", amount)
-        }
-        crate::combat::EnemyIntent::Buff => "Enemy is preparing a buff.".to_string(),
-        crate::combat::EnemyIntent::Debuff => {
-            format!("{} is about to be weakened or stressed.", player_name)
-        }
-        crate::combat::EnemyIntent::Unknown => "Enemy intent is hidden.".to_string(),
+    #[test]
+    fn untargeted_intent_skips_a_fallen_active_member() {
+        let mut combat = mixed_party();
+        combat.current_player_idx = 1;
+        combat.enemy.intent = crate::combat::EnemyIntent::Block(5);
+        assert_eq!(combat.target_for_intent(), 0);
     }
-}
 
-fn card_type(card: &Card) -> &'static str {
-    if card.is_attack() {
-        "Attack"
-    } else if card
-        .effects
-        .iter()
-        .any(|effect| matches!(effect, crate::combat::CardEffect::Block(_)))
-    {
-        "Guard"
-    } else if card
-        .effects
-        .iter()
-        .any(|effect| matches!(effect, crate::combat::CardEffect::Heal(_)))
-    {
-        "Heal"
-    } else if card.effects.iter().any(|effect| {
-        matches!(
-            effect,
-            crate::combat::CardEffect::EnemyStress(_)
-                | crate::combat::CardEffect::ApplyStatus { .. }
-        )
-    }) {
-        "Mystic"
-    } else {
-        "Skill"
+    #[test]
+    fn attack_all_hits_every_living_member_through_their_own_block() {
+        let mut combat = mixed_party();
+        combat.enemy.intent = crate::combat::EnemyIntent::AttackAll(6);
+        combat.players[0].block = 4;
+        combat.run_enemy_phase();
+        assert_eq!(combat.players[0].hp, 28);
+        assert_eq!(combat.players[1].hp, 0);
+        assert_eq!(combat.players[2].hp, 24);
+        assert_eq!(combat.damage_taken, vec![2, 0, 6]);
     }
-}
 
-fn card_accent(card: &Card) -> Color {
-    match card_type(card) {
-        "Attack" => Color::from_rgba(143, 61, 49, 255),
-        "Guard" => Color::from_rgba(105, 128, 139, 255),
-        "Heal" => Color::from_rgba(128, 160, 96, 255),
-        "Mystic" => Color::from_rgba(132, 96, 158, 255),
-        _ => Color::from_rgba(171, 126, 62, 255),
+    fn card_by_id(id: &str) -> Card {
+        crate::data::cards::CardData::load_all()
+            .unwrap_or_default()
+            .iter()
+            .find(|card| card.id == id)
+            .map(crate::data::cards::CardData::to_card)
+            .expect("card in cards.json")
+    }
+
+    #[test]
+    fn off_class_cards_stay_in_hand() {
+        let hero = Adventurer::new("Marcus", AdventurerClass::Soldier, Gender::Male);
+        let mut combat = CombatState::for_mission(boar_fight(&hero), &Settings::default());
+        combat.hand = vec![card_by_id("arcane_bolt")];
+        combat.energy = 10;
+        combat.selected_card = Some(0);
+        assert!(matches!(
+            combat.try_play_selected_card(),
+            Err(PlayError::WrongClass { .. })
+        ));
+        assert_eq!(combat.hand.len(), 1);
+        assert_eq!(combat.energy, 10);
     }
-}
 
-fn draw_wrapped_text(text: &str, x: f32, y: f32, max_width: f32, font_size: f32, color: Color) {
-    let mut line = String::new();
-    let mut line_y = y;
-    for word in text.split_whitespace() {
-        let candidate = if line.is_empty() {
-            word.to_string()
-        } else {
-            format!("{} {}", line, word)
-        };
-        if measure_ui_text(&candidate, None, font_size as u16, 1.0).width > max_width
-            && !line.is_empty()
-        {
-            draw_ui_text(&line, x, line_y, font_size, color);
-            line = word.to_string();
-            line_y += font_size + 5.0;
-        } else {
-            line = candidate;
-        }
+    #[test]
+    fn revive_card_raises_the_downed_ally() {
+        let mut combat = mixed_party();
+        combat.hand = vec![card_by_id("rekindle")];
+        combat.energy = 10;
+        combat.selected_card = Some(0);
+        assert!(combat.try_play_selected_card().is_ok());
+        assert_eq!(combat.players[1].hp, 10);
     }
-    if !line.is_empty() {
-        draw_ui_text(&line, x, line_y, font_size, color);
-    }
-}
-
-fn text_color() -> Color {
-    Color::from_rgba(230, 221, 205, 255)
-}
-
-fn muted_text_color() -> Color {
-    Color::from_rgba(158, 145, 126, 255)
-}
-
-fn title_color() -> Color {
-    Color::from_rgba(239, 224, 190, 255)
-}
-
-fn candle_color() -> Color {
-    Color::from_rgba(207, 151, 54, 255)
-}
-
-fn ready_color() -> Color {
-    Color::from_rgba(130, 177, 101, 255)
-}
-
-fn danger_color() -> Color {
-    Color::from_rgba(168, 58, 48, 255)
-}
-
-fn info_color() -> Color {
-    Color::from_rgba(118, 151, 164, 255)
-}
-
-fn mystery_color() -> Color {
-    Color::from_rgba(138, 104, 167, 255)
-}
-
-fn border_color() -> Color {
-    Color::from_rgba(105, 76, 43, 210)
 }
@@ -0,0 +1,174 @@
+//! Card piles - each member draws from their own deck and discards what they play
+
+use super::CombatState;
+use crate::combat::Card;
+use serde::{Deserialize, Serialize};
+
+/// Most cards a hand may hold after extra draws
+const MAX_HAND_SIZE: usize = 7;
+
+/// One member's draw and discard piles for the fight
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CardPiles {
+    pub draw_pile: Vec<Card>,
+    pub discard_pile: Vec<Card>,
+}
+
+impl CardPiles {
+    /// Shuffle a full deck into a fresh draw pile
+    pub fn new(deck: Vec<Card>) -> Self {
+        let mut piles = Self {
+            draw_pile: deck,
+            discard_pile: Vec::new(),
+        };
+        shuffle(&mut piles.draw_pile);
+        piles
+    }
+
+    /// Take up to `count` cards, shuffling the discard pile back in when the draw pile runs out
+    pub fn draw(&mut self, count: usize) -> Vec<Card> {
+        let mut drawn = Vec::with_capacity(count);
+        while drawn.len() < count {
+            if self.draw_pile.is_empty() {
+                if self.discard_pile.is_empty() {
+                    break;
+                }
+                self.draw_pile.append(&mut self.discard_pile);
+                shuffle(&mut self.draw_pile);
+            }
+            drawn.extend(self.draw_pile.pop());
+        }
+        drawn
+    }
+}
+
+/// Fisher-Yates through the game RNG, so seeded runs shuffle the same way
+fn shuffle(cards: &mut [Card]) {
+    for i in (1..cards.len()).rev() {
+        let j = macroquad_toolkit::rng::gen_range(0, i + 1);
+        cards.swap(i, j);
+    }
+}
+
+impl CombatState {
+    /// Deal a member their opening hand for the turn, discarding whatever the last member held
+    pub(super) fn deal_hand(&mut self, member: usize) {
+        self.discard_hand();
+        let hand_size = self.hand_size;
+        self.hand = self.piles_for(member).draw(hand_size);
+    }
+
+    /// Move the hand to the discard pile of the member holding it
+    fn discard_hand(&mut self) {
+        let hand = std::mem::take(&mut self.hand);
+        self.piles_for(self.current_player_idx)
+            .discard_pile
+            .extend(hand);
+    }
+
+    /// A played card goes to the active member's discard pile
+    pub(super) fn discard_played(&mut self, card: Card) {
+        self.piles_for(self.current_player_idx)
+            .discard_pile
+            .push(card);
+    }
+
+    /// Extra draws from card effects, up to the hand limit
+    pub(super) fn draw_extra_cards(&mut self, count: i32) {
+        let room = MAX_HAND_SIZE.saturating_sub(self.hand.len());
+        let count = (count.max(0) as usize).min(room);
+        let drawn = self.piles_for(self.current_player_idx).draw(count);
+        self.hand.extend(drawn);
+    }
+
+    /// A member's piles, shuffling their deck in the first time they are needed
+    fn piles_for(&mut self, member: usize) -> &mut CardPiles {
+        while self.piles.len() <= member {
+            let (deck, warning) = self.deck_for_member(self.piles.len());
+            if let Some(warning) = warning.filter(|w| !self.notifications.contains(w)) {
+                self.notifications.push(warning);
+            }
+            self.piles.push(CardPiles::new(deck));
+        }
+        &mut self.piles[member]
+    }
+
+    /// (draw, discard) pile sizes for the active member
+    pub fn pile_counts(&self) -> (usize, usize) {
+        self.piles
+            .get(self.current_player_idx)
+            .map_or((0, 0), |piles| {
+                (piles.draw_pile.len(), piles.discard_pile.len())
+            })
+    }
+
+    fn deck_for_member(&self, member: usize) -> (Vec<Card>, Option<String>) {
+        let (class_name, deck_additions) = self
+            .return_mission
+            .as_ref()
+            .and_then(|ctx| ctx.party_members.get(member))
+            .map(|m| (m.class_name.as_str(), m.deck_additions.as_slice()))
+            .unwrap_or(("Soldier", &[]));
+        Card::load_deck_for_class(class_name, deck_additions)
+    }
+
+    /// Class of the active member; unknown outside a mission, where any card may be played
+    pub(super) fn current_class_name(&self) -> Option<&str> {
+        self.return_mission
+            .as_ref()
+            .and_then(|ctx| ctx.party_members.get(self.current_player_idx))
+            .map(|m| m.class_name.as_str())
+    }
+
+    /// True unless the card is restricted to another class than the active member's
+    pub(super) fn class_can_play(&self, card: &Card) -> bool {
+        self.current_class_name()
+            .is_none_or(|class| card.usable_by(class))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck(size: usize) -> Vec<Card> {
+        (0..size)
+            .map(|i| Card {
+                id: format!("card_{}", i),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn discards_come_back_once_the_draw_pile_is_empty() {
+        let mut piles = CardPiles::new(deck(6));
+        let first = piles.draw(5);
+        assert_eq!(first.len(), 5);
+        piles.discard_pile.extend(first);
+        assert_eq!(piles.draw(5).len(), 5);
+        assert_eq!(piles.draw_pile.len() + piles.discard_pile.len(), 1);
+    }
+
+    #[test]
+    fn the_rest_of_the_deck_comes_before_any_reshuffle() {
+        let mut combat = CombatState::default();
+        combat.hand.clear();
+        combat.piles = vec![CardPiles::new(deck(7))];
+        combat.deal_hand(0);
+        assert_eq!(combat.pile_counts(), (2, 0));
+        let played = combat.hand.remove(0);
+        combat.discard_played(played);
+        let unseen: Vec<String> = combat.piles[0]
+            .draw_pile
+            .iter()
+            .map(|c| c.id.clone())
+            .collect();
+        combat.deal_hand(0);
+        assert!(unseen
+            .iter()
+            .all(|id| combat.hand.iter().any(|c| &c.id == id)));
+        let (draw, discard) = combat.pile_counts();
+        assert_eq!(combat.hand.len() + draw + discard, 7);
+    }
+}
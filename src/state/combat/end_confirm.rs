@@ -0,0 +1,112 @@
+//! End-turn confirmation - spell out the enemy's coming move before it resolves
+
+use super::view::{end_cancel_button_rect, end_confirm_button_rect};
+use super::{clicked_down, CombatState};
+use crate::combat::EnemyIntent;
+use crate::kingdom::StatusType;
+use macroquad::prelude::*;
+
+/// End action held open until the player confirms it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingEnd {
+    /// End the active member's turn
+    Turn,
+    /// End the whole party phase
+    Phase,
+}
+
+impl CombatState {
+    /// Hold the end action for confirmation when it hands the enemy its move; otherwise end now
+    pub(super) fn request_end(&mut self, end: PendingEnd) {
+        if self.enemy_acts_after(end) {
+            self.pending_end = Some(end);
+        } else {
+            self.commit_end(end);
+        }
+    }
+
+    /// Enter or E (P for the phase) or Confirm goes through; Esc or Cancel keeps the turn going
+    pub(super) fn update_end_confirm(&mut self) {
+        let Some(end) = self.pending_end else {
+            return;
+        };
+        let repeat_key = match end {
+            PendingEnd::Turn => KeyCode::E,
+            PendingEnd::Phase => KeyCode::P,
+        };
+        let (cx, cy, cw, ch) = end_confirm_button_rect();
+        let (nx, ny, nw, nh) = end_cancel_button_rect();
+        if is_key_pressed(KeyCode::Enter)
+            || is_key_pressed(repeat_key)
+            || clicked_down(cx, cy, cw, ch)
+        {
+            self.pending_end = None;
+            self.commit_end(end);
+        } else if is_key_pressed(KeyCode::Escape) || clicked_down(nx, ny, nw, nh) {
+            self.pending_end = None;
+            self.set_feedback("Turn continues.".to_string());
+        }
+    }
+
+    /// What the telegraphed intent will do and to whom, e.g. "Wolf will Attack Marcus for 8 (5 after block)."
+    pub fn enemy_forecast(&self) -> String {
+        let enemy = &self.enemy;
+        if enemy.has_status(StatusType::Stun) {
+            return format!("{} is stunned and will not act.", enemy.name);
+        }
+        let Some(target) = self.players.get(self.intent_target) else {
+            return format!("{} will {}.", enemy.name, enemy.intent.description());
+        };
+        let incoming = enemy.intent_damage();
+        let hit = format!(
+            "for {} ({} after block)",
+            incoming,
+            target.damage_through_block(incoming)
+        );
+        match &enemy.intent {
+            EnemyIntent::Attack(_) => {
+                format!("{} will Attack {} {}.", enemy.name, target.name, hit)
+            }
+            EnemyIntent::AttackAll(_) => format!(
+                "{} will Attack the whole party for {} each, before block.",
+                enemy.name, incoming
+            ),
+            EnemyIntent::Special(action) if action.deals_damage() => {
+                format!(
+                    "{} will use {} on {} {}.",
+                    enemy.name, action.name, target.name, hit
+                )
+            }
+            EnemyIntent::Special(action) if action.targets_party() => {
+                format!(
+                    "{} will use {} on {}.",
+                    enemy.name, action.name, target.name
+                )
+            }
+            EnemyIntent::Special(action) => format!("{} will use {}.", enemy.name, action.name),
+            EnemyIntent::Block(amount) => format!("{} will gain {} Block.", enemy.name, amount),
+            EnemyIntent::Buff => format!("{} will strengthen itself.", enemy.name),
+            EnemyIntent::Debuff => {
+                format!(
+                    "{} will weaken, bleed or stress {}.",
+                    enemy.name, target.name
+                )
+            }
+            EnemyIntent::Unknown => format!("{}'s intent is hidden.", enemy.name),
+        }
+    }
+
+    /// True when this end action is the last one before the enemy phase
+    fn enemy_acts_after(&self, end: PendingEnd) -> bool {
+        !self.simultaneous_turns
+            || end == PendingEnd::Phase
+            || self.next_living_member(self.current_player_idx).is_none()
+    }
+
+    fn commit_end(&mut self, end: PendingEnd) {
+        match end {
+            PendingEnd::Turn => self.end_turn(),
+            PendingEnd::Phase => self.end_phase(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Fear paralysis - a Fearful member stepping up may freeze and lose the whole turn
+
+use super::CombatState;
+use crate::kingdom::TraumaType;
+use macroquad_toolkit::rng;
+
+/// Chance a Fearful member freezes as their turn begins
+const FEAR_SKIP_CHANCE: f32 = 0.25;
+
+impl CombatState {
+    /// Roll for a Fearful member who just became active; a frozen turn passes on the next update
+    pub(super) fn roll_fear_paralysis(&mut self, member: usize) {
+        let fearful = self.players.get(member).is_some_and(|player| {
+            player
+                .traumas
+                .iter()
+                .any(|t| t.trauma_type == TraumaType::Fearful)
+        });
+        if fearful && rng::chance(FEAR_SKIP_CHANCE) {
+            self.freeze_for_fear(member);
+        }
+    }
+
+    fn freeze_for_fear(&mut self, member: usize) {
+        let Some(name) = self.players.get(member).map(|p| p.name.clone()) else {
+            return;
+        };
+        self.frozen_by_fear = true;
+        self.resolver
+            .log
+            .push(format!("{} is paralyzed by fear and loses the turn", name));
+        self.notifications
+            .push(format!("{} is paralyzed by fear!", name));
+    }
+
+    /// End the frozen member's turn untouched, as if they had pressed End Turn
+    pub(super) fn pass_frozen_turn(&mut self) {
+        self.frozen_by_fear = false;
+        self.end_turn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Phase;
+    use super::*;
+    use crate::combat::Unit;
+
+    #[test]
+    fn a_frozen_member_hands_the_turn_on() {
+        let mut combat = CombatState {
+            players: vec![
+                Unit::new_player("Timid", 40),
+                Unit::new_player("Steady", 40),
+            ],
+            ..Default::default()
+        };
+        combat.freeze_for_fear(0);
+        assert_eq!(
+            combat.notifications,
+            vec!["Timid is paralyzed by fear!".to_string()]
+        );
+
+        combat.pass_frozen_turn();
+        assert!(!combat.frozen_by_fear);
+        assert_eq!(combat.phase, Phase::PlayerTurn { member: 1 });
+
+        combat.freeze_for_fear(1);
+        combat.pass_frozen_turn();
+        assert_eq!(combat.phase, Phase::EnemyTurn);
+    }
+
+    #[test]
+    fn only_the_fearful_freeze() {
+        let mut combat = CombatState::default();
+        for _ in 0..50 {
+            combat.roll_fear_paralysis(0);
+        }
+        assert!(!combat.frozen_by_fear);
+    }
+}
@@ -0,0 +1,376 @@
+//! Card play - costs, refusals, and resolving the selected card
+
+use super::CombatState;
+use crate::combat::{Card, CardEffect};
+use crate::kingdom::TraumaType;
+use std::fmt;
+
+/// How long a refused card shakes in the hand, in seconds
+pub(super) const REFUSED_SHAKE_SECS: f32 = 0.4;
+
+/// Why the selected card was not played
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayError {
+    /// Nothing is selected
+    NoSelection,
+    /// The selection no longer points at a card in hand
+    Unavailable,
+    /// Not enough energy for the card's effective cost
+    NotEnoughEnergy {
+        card: String,
+        needed: i32,
+        have: i32,
+    },
+    /// An effect has disabled attacks for the rest of the turn
+    AttacksDisabled,
+    /// Every effect would be wasted; playing again goes through
+    NoEffect(String),
+    /// A Fearful member lost the attack to hesitation
+    Fumbled(String),
+    /// The active member is down and cannot act
+    Fallen(String),
+    /// The card belongs to another class than the active member's
+    WrongClass { card: String, class: String },
+}
+
+impl fmt::Display for PlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayError::NoSelection => write!(f, "Select a card first."),
+            PlayError::Unavailable => write!(f, "That card is no longer available."),
+            PlayError::NotEnoughEnergy { card, needed, have } => {
+                write!(f, "{} needs {} energy. You have {}.", card, needed, have)
+            }
+            PlayError::AttacksDisabled => write!(f, "Attacks disabled this turn."),
+            PlayError::NoEffect(reason) => {
+                write!(f, "No effect: {}. Play again to use it anyway.", reason)
+            }
+            PlayError::Fumbled(card) => write!(f, "{} fumbled.", card),
+            PlayError::Fallen(name) => write!(f, "{} has fallen and cannot act.", name),
+            PlayError::WrongClass { card, class } => {
+                write!(f, "{} is a {} card.", card, class)
+            }
+        }
+    }
+}
+
+impl CombatState {
+    /// Play the selected card, or report why it stayed in hand
+    pub(super) fn try_play_selected_card(&mut self) -> Result<(), PlayError> {
+        let card_idx = self.selected_card.ok_or(PlayError::NoSelection)?;
+        if card_idx >= self.hand.len() || self.current_player_idx >= self.players.len() {
+            self.selected_card = None;
+            return Err(PlayError::Unavailable);
+        }
+        let active = &self.players[self.current_player_idx];
+        if active.hp <= 0 {
+            self.selected_card = None;
+            return Err(PlayError::Fallen(active.name.clone()));
+        }
+
+        let card = self.hand[card_idx].clone();
+        if !self.class_can_play(&card) {
+            return Err(PlayError::WrongClass {
+                card: card.name,
+                class: card.class.label().unwrap_or_default().to_string(),
+            });
+        }
+        let effective_cost = self.effective_card_cost(&card);
+
+        if !self.can_afford(&card) {
+            let needed = if card.is_x_cost() {
+                effective_cost + 1
+            } else {
+                effective_cost
+            };
+            return Err(PlayError::NotEnoughEnergy {
+                card: card.name,
+                needed,
+                have: self.energy,
+            });
+        }
+        if card.is_attack() && self.resolver.turn_mods.attacks_disabled {
+            return Err(PlayError::AttacksDisabled);
+        }
+
+        if self.waste_warned != Some(card_idx) {
+            if let Some(reason) = self.wasted_effect(&card) {
+                self.waste_warned = Some(card_idx);
+                return Err(PlayError::NoEffect(reason));
+            }
+        }
+
+        if self.fearful_fumble(&card) {
+            self.selected_card = None;
+            return Err(PlayError::Fumbled(card.name));
+        }
+
+        let player_name = self.players[self.current_player_idx].name.clone();
+        let card_name = card.name.clone();
+        let effects = card.effects.clone();
+        // X-cost cards take everything left; energy past their surcharge powers the effect
+        let spent = if card.is_x_cost() {
+            self.energy
+        } else {
+            effective_cost
+        };
+        self.resolver.turn_mods.x_energy = spent - effective_cost;
+        self.energy -= spent;
+        self.resolver
+            .log
+            .push(format!("{} plays {}", player_name, card_name));
+
+        for effect in effects {
+            if let CardEffect::Revive(hp) = effect {
+                self.revive_downed_ally(&player_name, hp);
+                continue;
+            }
+            let player = &mut self.players[self.current_player_idx];
+            self.resolver.resolve(&effect, player, &mut self.enemy);
+        }
+
+        self.resolver.turn_mods.x_energy = 0;
+        // The played card leaves the hand first so draws see the room it freed, but is
+        // discarded only after them so a reshuffle cannot return it at once
+        let played = self.hand.remove(card_idx);
+        self.apply_card_turn_modifiers();
+        self.discard_played(played);
+        self.selected_card = None;
+        self.waste_warned = None;
+        self.set_feedback(format!("{} played.", card_name));
+        Ok(())
+    }
+
+    /// Show why a play was refused; cards refused for cost or a blocked attack shake
+    pub(super) fn report_play_error(&mut self, error: PlayError) {
+        if matches!(
+            error,
+            PlayError::AttacksDisabled | PlayError::NotEnoughEnergy { .. }
+        ) {
+            self.refused_card = self.selected_card.map(|idx| (idx, REFUSED_SHAKE_SECS));
+        }
+        self.set_feedback(error.to_string());
+    }
+
+    /// Why every effect on `card` would be wasted on the active member, if it would be.
+    /// Cards with any effect that still lands are never flagged.
+    pub(super) fn wasted_effect(&self, card: &Card) -> Option<String> {
+        let player = self.players.get(self.current_player_idx)?;
+        let mut reason = None;
+        for effect in &card.effects {
+            let wasted = match effect {
+                CardEffect::Heal(_) if player.hp >= player.max_hp => {
+                    format!("{} is already at full HP", player.name)
+                }
+                CardEffect::ClearDebuffs if !player.statuses.iter().any(|s| s.is_debuff()) => {
+                    format!("{} has no debuffs to clear", player.name)
+                }
+                CardEffect::ReduceStress(_) if player.stress <= 0 => {
+                    format!("{} has no stress to relieve", player.name)
+                }
+                CardEffect::Revive(_) if self.first_downed_ally().is_none() => {
+                    "no ally is down".to_string()
+                }
+                _ => return None,
+            };
+            reason.get_or_insert(wasted);
+        }
+        reason
+    }
+
+    /// Foremost party member who is down but can still be revived
+    pub(super) fn first_downed_ally(&self) -> Option<usize> {
+        self.players.iter().position(|player| player.is_downed())
+    }
+
+    fn revive_downed_ally(&mut self, reviver: &str, hp: i32) {
+        if let Some(ally) = self
+            .first_downed_ally()
+            .and_then(|idx| self.players.get_mut(idx))
+        {
+            self.resolver.revive_ally(reviver, ally, hp);
+        }
+    }
+
+    /// Cost of a card for the active member
+    pub(super) fn effective_card_cost(&self, card: &Card) -> i32 {
+        self.effective_cost(card, self.current_player_idx)
+    }
+
+    /// Base cost plus the player's trauma and injury surcharges, less this turn's cost cuts
+    pub fn effective_cost(&self, card: &Card, player_idx: usize) -> i32 {
+        let Some(player) = self.players.get(player_idx) else {
+            return card.cost;
+        };
+
+        let mut cost = card.cost;
+        if player
+            .traumas
+            .iter()
+            .any(|t| t.trauma_type == TraumaType::Broken)
+        {
+            cost += 1;
+        }
+        if card
+            .effects
+            .iter()
+            .any(|e| matches!(e, CardEffect::Block(_)))
+            && player
+                .traumas
+                .iter()
+                .any(|t| t.trauma_type == TraumaType::Paranoid)
+        {
+            cost += 1;
+        }
+        for injury in &player.injuries {
+            if let Some((tag, surcharge)) = injury.cost_surcharge() {
+                if card.has_tag(tag) {
+                    cost += surcharge;
+                }
+            }
+        }
+        (cost - self.resolver.turn_mods.cost_reduction).max(0)
+    }
+
+    /// Spend the energy and draws a played card banked in `turn_mods` right away
+    fn apply_card_turn_modifiers(&mut self) {
+        let mods = &mut self.resolver.turn_mods;
+        let energy = std::mem::take(&mut mods.energy_to_gain);
+        let draws = std::mem::take(&mut mods.cards_to_draw);
+        self.energy += energy.max(0);
+        if draws > 0 {
+            self.draw_extra_cards(draws);
+        }
+    }
+
+    /// Enough energy for the card's cost; X-cost cards need at least one energy to spend
+    pub(super) fn can_afford(&self, card: &Card) -> bool {
+        let cost = self.effective_card_cost(card);
+        if card.is_x_cost() {
+            self.energy > cost
+        } else {
+            cost <= self.energy
+        }
+    }
+
+    fn fearful_fumble(&mut self, card: &Card) -> bool {
+        if !card.is_attack() {
+            return false;
+        }
+        let Some(player) = self.players.get_mut(self.current_player_idx) else {
+            return false;
+        };
+        if player
+            .traumas
+            .iter()
+            .any(|t| t.trauma_type == TraumaType::Fearful)
+            && macroquad_toolkit::rng::chance(0.15)
+        {
+            player.add_stress(3);
+            self.resolver
+                .log
+                .push(format!("{} hesitates and loses the attack", player.name));
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CardPiles;
+    use super::*;
+    use crate::combat::Unit;
+    use crate::kingdom::{Injury, Trauma};
+
+    fn card(tags: &[&str]) -> Card {
+        Card {
+            id: "step".to_string(),
+            cost: 1,
+            effects: vec![CardEffect::Block(4)],
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn wounded_party() -> CombatState {
+        let mut wounded = Unit::new_player("Wounded", 40);
+        wounded.injuries.push(Injury::wounded_leg());
+        CombatState {
+            players: vec![wounded, Unit::new_player("Hale", 40)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn wounded_leg_surcharges_movement_cards_only() {
+        let combat = wounded_party();
+        assert_eq!(combat.effective_cost(&card(&["movement"]), 0), 2);
+        assert_eq!(combat.effective_cost(&card(&["skill"]), 0), 1);
+        assert_eq!(combat.effective_cost(&card(&["movement"]), 1), 1);
+    }
+
+    #[test]
+    fn surcharges_stack_before_cost_cuts() {
+        let mut combat = wounded_party();
+        combat.players[0]
+            .traumas
+            .push(Trauma::new(TraumaType::Broken));
+        assert_eq!(combat.effective_cost(&card(&["movement"]), 0), 3);
+        combat.resolver.turn_mods.cost_reduction = 1;
+        assert_eq!(combat.effective_card_cost(&card(&["movement"])), 2);
+        combat.energy = 1;
+        assert!(!combat.can_afford(&card(&["movement"])));
+        combat.energy = 2;
+        assert!(combat.can_afford(&card(&["movement"])));
+    }
+
+    #[test]
+    fn tagged_cards_from_data_feel_the_wound() {
+        let evasion = crate::data::cards::CardData::load_all()
+            .unwrap()
+            .into_iter()
+            .find(|c| c.id == "evasion")
+            .unwrap()
+            .to_card();
+        assert!(evasion.has_tag("movement"));
+        let combat = wounded_party();
+        assert_eq!(combat.effective_cost(&evasion, 0), evasion.cost + 1);
+    }
+
+    #[test]
+    fn draw_and_energy_effects_land_in_the_same_turn() {
+        let mut combat = CombatState::default();
+        combat.piles = vec![CardPiles::new(vec![card(&[]); 4])];
+        combat.hand = vec![Card {
+            cost: 1,
+            effects: vec![CardEffect::DrawCards(2), CardEffect::GainEnergy(2)],
+            ..card(&[])
+        }];
+        combat.energy = 1;
+        combat.selected_card = Some(0);
+        combat.try_play_selected_card().unwrap();
+        assert_eq!(combat.hand.len(), 2);
+        assert_eq!(combat.energy, 2);
+        assert_eq!(combat.pile_counts(), (2, 1));
+    }
+
+    #[test]
+    fn x_cost_damage_scales_with_energy_spent() {
+        let mut combat = CombatState {
+            enemy: Unit::new_enemy("Brute", 40, None),
+            ..Default::default()
+        };
+        combat.hand = vec![Card {
+            cost: 0,
+            effects: vec![CardEffect::DamagePerEnergy(5)],
+            ..card(&[])
+        }];
+        combat.energy = 3;
+        combat.selected_card = Some(0);
+        combat.try_play_selected_card().unwrap();
+        assert_eq!(combat.energy, 0);
+        assert_eq!(combat.enemy.hp, 40 - 3 * 5);
+    }
+}
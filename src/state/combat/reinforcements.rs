@@ -0,0 +1,111 @@
+//! Reinforcements - enemies called in mid-fight wait behind the foe that called them,
+//! stepping up when it falls
+
+use super::CombatState;
+use crate::data::enemy_by_id;
+
+impl CombatState {
+    /// At the start of each round: bring in an announced reinforcement, or announce one
+    /// that is due next round
+    pub(super) fn check_reinforcements(&mut self) {
+        let (telegraphed, due) = match &self.enemy.reinforcement {
+            Some(call) => (call.telegraphed, call.due_next(self.turn, &self.enemy)),
+            None => return,
+        };
+        if telegraphed {
+            let Some(call) = self.enemy.reinforcement.take() else {
+                return;
+            };
+            if let Some(data) = enemy_by_id(&call.enemy_id) {
+                let unit = data.scaled_for_party(self.players.len()).to_unit();
+                self.resolver
+                    .log
+                    .push(format!("{} joins the fight!", unit.name));
+                self.reserve.push(unit);
+            }
+        } else if due {
+            if let Some(call) = self.enemy.reinforcement.as_mut() {
+                call.telegraphed = true;
+            }
+            self.resolver
+                .log
+                .push("Something approaches...".to_string());
+            self.set_feedback("Something approaches...".to_string());
+        }
+    }
+
+    /// Send the next reinforcement forward once the foe in front falls; false if none wait
+    pub(super) fn advance_reserve(&mut self) -> bool {
+        if self.reserve.is_empty() {
+            return false;
+        }
+        let fallen = std::mem::replace(&mut self.enemy, self.reserve.remove(0));
+        self.enemy.roll_intent(self.turn);
+        self.intent_target = self.target_for_intent();
+        self.set_feedback(format!(
+            "{} falls; {} steps up!",
+            fallen.name, self.enemy.name
+        ));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::Reinforcement;
+    use crate::state::combat::Phase;
+
+    fn called_at(turn: Option<usize>, hp_percent: Option<i32>) -> CombatState {
+        let mut combat = CombatState::default();
+        combat.enemy.reinforcement = Some(Reinforcement {
+            enemy_id: "wild_boar".to_string(),
+            turn,
+            hp_percent,
+            telegraphed: false,
+        });
+        combat
+    }
+
+    fn enemy_phase(combat: &mut CombatState) {
+        combat.phase = Phase::EnemyTurn;
+        combat.run_enemy_phase();
+    }
+
+    #[test]
+    fn reinforcements_are_announced_then_arrive_on_schedule() {
+        let mut combat = called_at(Some(3), None);
+        enemy_phase(&mut combat);
+        assert_eq!(combat.turn, 2);
+        assert!(combat.enemy.reinforcement.as_ref().unwrap().telegraphed);
+        assert!(combat.reserve.is_empty());
+
+        enemy_phase(&mut combat);
+        assert_eq!(combat.turn, 3);
+        assert!(combat.enemy.reinforcement.is_none());
+        assert_eq!(combat.reserve.len(), 1);
+        assert_eq!(combat.reserve[0].name, "Wild Boar");
+    }
+
+    #[test]
+    fn a_worn_down_caller_sends_for_help() {
+        let mut combat = called_at(None, Some(50));
+        combat.check_reinforcements();
+        assert!(!combat.enemy.reinforcement.as_ref().unwrap().telegraphed);
+        combat.enemy.hp = combat.enemy.max_hp / 2;
+        combat.check_reinforcements();
+        assert!(combat.enemy.reinforcement.as_ref().unwrap().telegraphed);
+    }
+
+    #[test]
+    fn the_reserve_steps_up_instead_of_ending_the_fight() {
+        let mut combat = called_at(Some(1), None);
+        combat.check_reinforcements();
+        combat.check_reinforcements();
+        combat.enemy.hp = 0;
+        assert!(combat.check_outcome().is_none());
+        assert!(combat.victory.is_none());
+        assert_eq!(combat.enemy.name, "Wild Boar");
+        assert!(combat.reserve.is_empty());
+    }
+}
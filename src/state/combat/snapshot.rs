@@ -0,0 +1,80 @@
+//! Combat snapshot - the fight as a save file sees it
+
+use super::{CardPiles, CombatState, MissionContext, Phase};
+use crate::combat::{Card, Unit};
+use crate::save::Settings;
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to resume a fight at the active member's turn. Card modifiers
+/// from effects played earlier this turn (cost cuts, disabled attacks) are not kept
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CombatSnapshot {
+    pub players: Vec<Unit>,
+    pub enemy: Unit,
+    #[serde(default)]
+    pub reserve: Vec<Unit>,
+    pub current_player_idx: usize,
+    pub hand: Vec<Card>,
+    #[serde(default)]
+    pub piles: Vec<CardPiles>,
+    pub energy: i32,
+    pub max_energy: i32,
+    #[serde(default)]
+    pub banked_energy: Vec<i32>,
+    pub hand_size: usize,
+    pub turn: usize,
+    pub damage_taken: Vec<i32>,
+    pub stress_gained: Vec<i32>,
+    pub return_mission: Option<MissionContext>,
+}
+
+impl CombatState {
+    /// Capture the fight for an in-progress save; None while its outcome is on screen
+    /// or the enemy is mid-action
+    pub fn snapshot(&self) -> Option<CombatSnapshot> {
+        if self.victory.is_some() || self.phase == Phase::EnemyTurn {
+            return None;
+        }
+        Some(CombatSnapshot {
+            players: self.players.clone(),
+            enemy: self.enemy.clone(),
+            reserve: self.reserve.clone(),
+            current_player_idx: self.current_player_idx,
+            hand: self.hand.clone(),
+            piles: self.piles.clone(),
+            energy: self.energy,
+            max_energy: self.max_energy,
+            banked_energy: self.banked_energy.clone(),
+            hand_size: self.hand_size,
+            turn: self.turn,
+            damage_taken: self.damage_taken.clone(),
+            stress_gained: self.stress_gained.clone(),
+            return_mission: self.return_mission.clone(),
+        })
+    }
+
+    pub fn from_snapshot(snapshot: CombatSnapshot, settings: &Settings) -> Self {
+        let member = snapshot.current_player_idx;
+        let mut state = Self {
+            players: snapshot.players,
+            current_player_idx: member,
+            phase: Phase::PlayerTurn { member },
+            enemy: snapshot.enemy,
+            reserve: snapshot.reserve,
+            hand: snapshot.hand,
+            piles: snapshot.piles,
+            energy: snapshot.energy,
+            max_energy: snapshot.max_energy,
+            banked_energy: snapshot.banked_energy,
+            hand_size: snapshot.hand_size,
+            turn: snapshot.turn,
+            damage_taken: snapshot.damage_taken,
+            stress_gained: snapshot.stress_gained,
+            return_mission: snapshot.return_mission,
+            ..Default::default()
+        };
+        state.use_settings(settings);
+        state.intent_target = state.target_for_intent();
+        state
+    }
+}
@@ -0,0 +1,45 @@
+//! Trauma interrupt - stop the fight for a beat when stress breaks a party member
+
+use super::{clicked_down, CombatState};
+use crate::kingdom::Trauma;
+use macroquad::prelude::*;
+
+impl CombatState {
+    /// Raise the overlay for a freshly gained trauma, unless the pause is switched off
+    pub(super) fn interrupt_for_trauma(&mut self, name: &str, trauma: &Trauma) {
+        if self.trauma_pause {
+            self.trauma_interrupt = Some(format!("{} is overcome - {}!", name, trauma.name()));
+        }
+    }
+
+    /// Enter, Space, or a click anywhere lets the fight go on
+    pub(super) fn update_trauma_interrupt(&mut self) {
+        if is_key_pressed(KeyCode::Enter)
+            || is_key_pressed(KeyCode::Space)
+            || clicked_down(0.0, 0.0, screen_width(), screen_height())
+        {
+            self.trauma_interrupt = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::TraumaType;
+
+    #[test]
+    fn switching_the_pause_off_lets_the_fight_run_on() {
+        let trauma = Trauma::new(TraumaType::Fearful);
+        let mut combat = CombatState::default();
+        combat.interrupt_for_trauma("Marcus", &trauma);
+        assert!(combat.trauma_interrupt.is_some());
+
+        let mut combat = CombatState {
+            trauma_pause: false,
+            ..Default::default()
+        };
+        combat.interrupt_for_trauma("Marcus", &trauma);
+        assert!(combat.trauma_interrupt.is_none());
+    }
+}
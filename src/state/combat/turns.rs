@@ -0,0 +1,375 @@
+//! Turn flow - party player phases, the enemy phase, and the legacy rotation
+
+use super::CombatState;
+use crate::combat::EnemyIntent;
+use crate::kingdom::{StatusType, StressOutcome, BOND_BLOCK};
+
+/// Where the current combat round stands
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// A party member is spending their own energy and hand
+    PlayerTurn { member: usize },
+    /// The enemy resolves its telegraphed intent once for the whole party
+    EnemyTurn,
+}
+
+impl CombatState {
+    /// End the active member's turn; the enemy acts once every living member is done
+    pub(super) fn end_turn(&mut self) {
+        if !self.simultaneous_turns {
+            self.end_rotating_turn();
+            return;
+        }
+
+        self.finish_member_turn();
+        match self.next_living_member(self.current_player_idx) {
+            Some(next) => self.begin_member_turn(next),
+            None => self.phase = Phase::EnemyTurn,
+        }
+    }
+
+    /// End the whole player phase, forfeiting turns of members who have not acted
+    pub(super) fn end_phase(&mut self) {
+        if !self.simultaneous_turns {
+            self.end_rotating_turn();
+            return;
+        }
+
+        self.finish_member_turn();
+        self.phase = Phase::EnemyTurn;
+    }
+
+    /// Resolve the enemy phase and open the next round's player phase
+    pub(super) fn run_enemy_phase(&mut self) {
+        let (enemy_acted, outcome, target_name) = self.resolve_enemy_action();
+        self.resolver.end_turn(enemy_acted);
+
+        for player in &mut self.players {
+            player.block = 0;
+        }
+
+        self.turn += 1;
+        self.check_reinforcements();
+        self.enemy.roll_intent(self.turn);
+        self.intent_target = self.target_for_intent();
+
+        // A wiped party gets no turn; the outcome check ends the fight
+        if let Some(first) = self.first_living_member() {
+            self.begin_member_turn(first);
+        }
+        self.announce_turn(outcome, &target_name);
+    }
+
+    /// Legacy mode: one member acts, then the enemy acts, then the next member
+    fn end_rotating_turn(&mut self) {
+        if let Some(player) = self.players.get_mut(self.current_player_idx) {
+            player.tick_statuses();
+        }
+
+        // Block holds through the enemy's action, as in the party phase
+        let (enemy_acted, outcome, target_name) = self.resolve_enemy_action();
+        self.resolver.end_turn(enemy_acted);
+        if let Some(player) = self.players.get_mut(self.current_player_idx) {
+            player.block = 0;
+        }
+
+        self.turn += 1;
+        self.check_reinforcements();
+        if let Some(next) = self.next_rotating_member(self.current_player_idx) {
+            self.begin_member_turn(next);
+        }
+
+        // Roll new enemy intent for next turn
+        self.enemy.roll_intent(self.turn);
+        self.intent_target = self.target_for_intent();
+        self.announce_turn(outcome, &target_name);
+    }
+
+    /// Give a member fresh energy and a hand drawn from their own piles
+    pub(super) fn begin_member_turn(&mut self, member: usize) {
+        // "Next turn" energy belongs to whoever played the card, not whoever acts next
+        let banked = self.resolver.turn_mods.start_turn();
+        self.bank_energy(self.current_player_idx, banked);
+        self.resolver.turn_mods.reset_member();
+        self.deal_hand(member);
+        self.current_player_idx = member;
+        self.phase = Phase::PlayerTurn { member };
+        self.selected_card = None;
+        self.energy = self.max_energy + self.take_banked_energy(member);
+        self.grant_bond_block(member);
+        self.roll_fear_paralysis(member);
+    }
+
+    fn bank_energy(&mut self, member: usize, amount: i32) {
+        if amount == 0 {
+            return;
+        }
+        if self.banked_energy.len() <= member {
+            self.banked_energy.resize(member + 1, 0);
+        }
+        self.banked_energy[member] += amount;
+    }
+
+    fn take_banked_energy(&mut self, member: usize) -> i32 {
+        self.banked_energy.get_mut(member).map_or(0, std::mem::take)
+    }
+
+    /// Bonded partners steady each other: block for every one fighting alongside
+    pub(super) fn grant_bond_block(&mut self, member: usize) {
+        let bonded = self
+            .return_mission
+            .as_ref()
+            .and_then(|ctx| ctx.party_members.get(member))
+            .map_or(0, |state| state.bonded_partners);
+        if let Some(player) = self.players.get_mut(member) {
+            player.add_block(BOND_BLOCK * bonded as i32);
+        }
+    }
+
+    /// Tick the active member's statuses as they step back; block holds until the enemy acts
+    fn finish_member_turn(&mut self) {
+        if let Some(player) = self.players.get_mut(self.current_player_idx) {
+            player.tick_statuses();
+        }
+        self.selected_card = None;
+    }
+
+    /// Next living member after `idx` in formation order, if any acts later this round
+    pub(super) fn next_living_member(&self, idx: usize) -> Option<usize> {
+        (idx + 1..self.players.len()).find(|i| self.players[*i].hp > 0)
+    }
+
+    /// Foremost living member, if any
+    pub(super) fn first_living_member(&self) -> Option<usize> {
+        self.players.iter().position(|player| player.hp > 0)
+    }
+
+    /// Legacy rotation: the next living member after `idx`, wrapping around the formation.
+    /// `idx` itself comes last, and only if still standing
+    pub(super) fn next_rotating_member(&self, idx: usize) -> Option<usize> {
+        let len = self.players.len();
+        (1..=len)
+            .map(|step| (idx + step) % len)
+            .find(|i| self.players[*i].hp > 0)
+    }
+
+    /// Execute the enemy's intent against its chosen target
+    fn resolve_enemy_action(&mut self) -> (bool, StressOutcome, String) {
+        let old_intent = self.enemy.intent.description();
+        // The telegraphed target may have fallen during the player phase; the dead are not struck
+        if self
+            .players
+            .get(self.intent_target)
+            .is_some_and(|player| player.hp <= 0)
+        {
+            self.intent_target = self.target_for_intent();
+        }
+        let target_idx = self.intent_target;
+        let target_name = self
+            .players
+            .get(target_idx)
+            .map(|player| player.name.clone())
+            .unwrap_or_else(|| "Adventurer".to_string());
+
+        let sweeping = self.enemy.intent.hits_whole_party();
+        let special = match &self.enemy.intent {
+            EnemyIntent::Special(action) if !self.enemy.has_status(StatusType::Stun) => {
+                Some(action.clone())
+            }
+            _ => None,
+        };
+        let debuff = match &self.enemy.intent {
+            EnemyIntent::Debuff if !self.enemy.has_status(StatusType::Stun) => {
+                self.enemy.debuff_status()
+            }
+            _ => None,
+        };
+
+        // Enemy Action
+        let (dmg, mut stress) = self.enemy.execute_intent();
+        let enemy_acted = dmg > 0 || stress > 0 || special.is_some();
+
+        if let (Some(status), Some(player)) = (debuff, self.players.get_mut(target_idx)) {
+            self.resolver.log.push(format!(
+                "{} is left with {:?} {} for {} turn(s).",
+                player.name, status.effect_type, status.value, status.duration
+            ));
+            player.add_status(status);
+        }
+
+        // Apply damage to the targeted party member
+        let mut actual_damage = 0;
+        if let (Some(action), Some(player)) = (&special, self.players.get_mut(target_idx)) {
+            let (special_damage, special_stress) =
+                self.resolver
+                    .resolve_enemy_action(action, &mut self.enemy, player);
+            actual_damage += special_damage;
+            stress += special_stress;
+            if target_idx < self.damage_taken.len() {
+                self.damage_taken[target_idx] += special_damage;
+            }
+        }
+        if dmg > 0 {
+            // A sweeping attack lands on every living member, each through their own block
+            let struck: Vec<usize> = if sweeping {
+                (0..self.players.len())
+                    .filter(|idx| self.players[*idx].hp > 0)
+                    .collect()
+            } else {
+                vec![target_idx]
+            };
+            for idx in struck {
+                if let Some(player) = self.players.get_mut(idx) {
+                    let actual = player.take_damage(dmg);
+                    if idx == target_idx {
+                        actual_damage += actual;
+                    }
+                    if idx < self.damage_taken.len() {
+                        self.damage_taken[idx] += actual;
+                    }
+                }
+            }
+        }
+        if sweeping && dmg > 0 {
+            self.resolver
+                .log
+                .push(format!("{} struck the whole party.", self.enemy.name));
+        }
+
+        // Apply stress with resistance (uses resolver's turn mods)
+        let base_stress = crate::data::balance().enemy_turn_stress + stress;
+        let mut stress_outcome = StressOutcome::Steady;
+        if let Some(player) = self.players.get_mut(target_idx) {
+            stress_outcome = self.resolver.apply_stress_to_player(player, base_stress);
+            if target_idx < self.stress_gained.len() {
+                self.stress_gained[target_idx] += base_stress;
+            }
+        }
+
+        // Enemy status tick
+        self.enemy.tick_statuses();
+        self.enemy.block = 0;
+
+        self.resolver.log.push(format!(
+            "End turn: {} resolved. {} took {} damage and {} stress.",
+            old_intent, target_name, actual_damage, base_stress
+        ));
+        (enemy_acted, stress_outcome, target_name)
+    }
+
+    fn announce_turn(&mut self, outcome: StressOutcome, target_name: &str) {
+        self.resolver.log.push(format!(
+            "Turn {} begins. Enemy intent: {}.",
+            self.turn,
+            self.enemy.intent.description()
+        ));
+        match outcome {
+            StressOutcome::Breakthrough => self.set_feedback(format!(
+                "{}'s resolve breaks through! +{} Strength this fight.",
+                target_name,
+                crate::data::balance().breakthrough_strength
+            )),
+            StressOutcome::Trauma(trauma) => {
+                self.notifications
+                    .push(format!("{} is now {}", target_name, trauma.name()));
+                self.interrupt_for_trauma(target_name, &trauma);
+                self.set_feedback(format!("{} is Afflicted: {}.", target_name, trauma.name()))
+            }
+            StressOutcome::Steady => self.set_feedback(format!("Turn {} begins.", self.turn)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::{Card, CardEffect, Unit};
+
+    fn play_overcharge(combat: &mut CombatState) {
+        combat.hand = vec![Card {
+            id: "overcharge".to_string(),
+            effects: vec![CardEffect::GainEnergyNextTurn(2)],
+            ..Default::default()
+        }];
+        combat.selected_card = Some(0);
+        combat.try_play_selected_card().unwrap();
+    }
+
+    #[test]
+    fn energy_banked_for_next_turn_arrives_with_it() {
+        let mut combat = CombatState::default();
+        combat.max_energy = 3;
+        play_overcharge(&mut combat);
+        combat.end_turn();
+        assert_eq!(combat.phase, Phase::EnemyTurn);
+        combat.run_enemy_phase();
+        assert_eq!(combat.energy, 5);
+    }
+
+    #[test]
+    fn banked_energy_waits_for_the_member_who_banked_it() {
+        let mut combat = CombatState {
+            players: vec![
+                Unit::new_player("First", 60),
+                Unit::new_player("Second", 60),
+            ],
+            max_energy: 3,
+            ..Default::default()
+        };
+        play_overcharge(&mut combat);
+        combat.end_turn();
+        assert_eq!(combat.current_player_idx, 1);
+        assert_eq!(combat.energy, 3);
+        combat.end_turn();
+        combat.run_enemy_phase();
+        assert_eq!(combat.current_player_idx, 0);
+        assert_eq!(combat.energy, 5);
+    }
+
+    #[test]
+    fn cost_cuts_end_with_the_member_who_played_them() {
+        let mut combat = CombatState {
+            players: vec![
+                Unit::new_player("Weaver", 60),
+                Unit::new_player("Second", 60),
+            ],
+            ..Default::default()
+        };
+        let step = Card {
+            cost: 2,
+            ..Default::default()
+        };
+        combat.hand = vec![Card {
+            id: "spell_weave".to_string(),
+            effects: vec![CardEffect::ReduceCardCost(1)],
+            ..Default::default()
+        }];
+        combat.selected_card = Some(0);
+        combat.try_play_selected_card().unwrap();
+        assert_eq!(combat.effective_card_cost(&step), 1);
+
+        combat.end_turn();
+        assert_eq!(combat.current_player_idx, 1);
+        assert_eq!(combat.effective_card_cost(&step), 2);
+    }
+
+    #[test]
+    fn enemy_turns_on_a_survivor_when_its_target_falls_first() {
+        let mut combat = CombatState {
+            players: vec![
+                Unit::new_player("Fallen", 60),
+                Unit::new_player("Survivor", 60),
+            ],
+            ..Default::default()
+        };
+        combat.enemy.intent = EnemyIntent::Attack(6);
+        combat.intent_target = 0;
+        combat.players[0].hp = 0;
+        combat.end_phase();
+        combat.run_enemy_phase();
+        assert_eq!(combat.players[0].hp, 0);
+        assert_eq!(combat.players[0].stress, 0);
+        assert_eq!(combat.players[1].hp, 54);
+        assert!(combat.players[1].stress > 0);
+    }
+}
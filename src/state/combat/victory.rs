@@ -0,0 +1,129 @@
+//! Combat victory - the spoils overlay shown before returning to the mission
+
+use super::{clicked_down, CombatState};
+use crate::combat::{knows_card, Card};
+use crate::data::cards::CardData;
+use crate::kingdom::PartyMemberState;
+use crate::state::{MissionState, ResultState, StateTransition};
+use macroquad::prelude::*;
+
+/// Flat XP every surviving member earns for winning a fight
+const VICTORY_XP_BASE: i32 = 4;
+/// Enemy max HP per extra point of victory XP
+const VICTORY_XP_HP_DIVISOR: i32 = 10;
+
+/// Spoils of a won fight, shown until the player dismisses them
+#[derive(Clone, Debug)]
+pub struct VictorySummary {
+    pub enemy_name: String,
+    /// Party index of the member who landed the killing blow
+    pub killer: Option<usize>,
+    /// XP earned by each surviving member
+    pub xp: i32,
+    /// Cards the enemy dropped
+    pub drops: Vec<Card>,
+}
+
+impl CombatState {
+    /// Tally the spoils once the enemy falls
+    pub(super) fn build_victory_summary(&self) -> VictorySummary {
+        let killer = self
+            .players
+            .get(self.current_player_idx)
+            .filter(|player| player.hp > 0)
+            .map(|_| self.current_player_idx);
+        VictorySummary {
+            enemy_name: self.enemy.name.clone(),
+            killer,
+            xp: VICTORY_XP_BASE + self.enemy.max_hp / VICTORY_XP_HP_DIVISOR,
+            drops: self.roll_drops(),
+        }
+    }
+
+    /// Roll the enemy's loot table against the game RNG
+    fn roll_drops(&self) -> Vec<Card> {
+        let dropped: Vec<_> = self.enemy.drops.iter().filter(|drop| drop.roll()).collect();
+        if dropped.is_empty() {
+            return Vec::new();
+        }
+        let all_cards = CardData::load_all().unwrap_or_default();
+        dropped
+            .iter()
+            .filter_map(|drop| all_cards.iter().find(|card| card.id == drop.card_id))
+            .map(CardData::to_card)
+            .collect()
+    }
+
+    /// Wait on the victory overlay; any confirm key or click moves on
+    pub(super) fn update_victory(&mut self) -> Option<StateTransition> {
+        let confirmed = is_key_pressed(KeyCode::Enter)
+            || is_key_pressed(KeyCode::Space)
+            || clicked_down(0.0, 0.0, screen_width(), screen_height());
+        if confirmed {
+            self.resolve_outcome()
+        } else {
+            None
+        }
+    }
+
+    /// Return to the mission (or results) carrying this fight's XP and kills. The mission
+    /// context is moved out rather than cloned, as this state is dropped once we leave
+    pub(super) fn leave_victorious(&mut self) -> Option<StateTransition> {
+        let Some(ctx) = self.return_mission.take() else {
+            // Not from mission - just show simple victory
+            let leader_id = self.players.first().map(|p| p.name.as_str()).unwrap_or("");
+            return Some(StateTransition::ToResults(ResultState::victory_for(
+                leader_id,
+            )));
+        };
+
+        let mut updated_members = self.party_members_from_players(&ctx);
+        if let Some(summary) = &self.victory {
+            for (i, member) in updated_members.iter_mut().enumerate() {
+                if member.hp > 0 {
+                    member.xp_earned += summary.xp;
+                }
+                if summary.killer == Some(i) {
+                    member.kills += 1;
+                }
+            }
+            for card in &summary.drops {
+                grant_dropped_card(&mut updated_members, summary.killer, card);
+            }
+        }
+
+        if ctx.homeward_ambush {
+            let shortfall =
+                ctx.mission
+                    .victory_shortfall(&ctx.map_nodes, &ctx.visited_nodes, &updated_members);
+            return Some(StateTransition::ToResults(
+                ResultState::victory_for_mission(&ctx.mission, &updated_members)
+                    .with_shortfall(shortfall),
+            ));
+        }
+
+        let mission_state = MissionState::from_mission_with_party(
+            ctx.mission,
+            updated_members,
+            Some(ctx.map_nodes),
+        )
+        .with_node(ctx.current_node)
+        .with_visited(ctx.visited_nodes);
+        Some(StateTransition::ToMission(mission_state))
+    }
+}
+
+/// Hand a dropped card to the killer, or the first survivor whose class can use it
+fn grant_dropped_card(members: &mut [PartyMemberState], killer: Option<usize>, card: &Card) {
+    let can_take = |member: &PartyMemberState| {
+        member.hp > 0
+            && card.usable_by(&member.class_name)
+            && !knows_card(&member.deck_additions, &card.id)
+    };
+    let recipient = killer
+        .filter(|idx| members.get(*idx).is_some_and(can_take))
+        .or_else(|| members.iter().position(can_take));
+    if let Some(member) = recipient.and_then(|idx| members.get_mut(idx)) {
+        member.deck_additions.push(card.id.clone());
+    }
+}
@@ -0,0 +1,680 @@
+//! Combat rendering - panels, cards, and intent readouts
+
+use super::{CombatState, Phase, VictorySummary};
+use crate::combat::{Card, Unit};
+use crate::kingdom::{FormationRow, ResolveState};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+mod hand;
+mod report;
+
+use hand::{draw_combat_card, HandCard};
+use report::draw_report_panel;
+
+impl CombatState {
+    pub fn draw(&self, textures: &std::collections::HashMap<String, Texture2D>) {
+        let region_id = if let Some(ctx) = &self.return_mission {
+            &ctx.mission.region_id
+        } else {
+            "dark_woods"
+        };
+
+        let bg_path = format!("assets/images/regions/{}.png", region_id);
+        if let Some(tex) = textures.get(&bg_path) {
+            draw_texture_ex(
+                tex,
+                0.0,
+                0.0,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(screen_width(), screen_height())),
+                    ..Default::default()
+                },
+            );
+        } else {
+            clear_background(Color::from_rgba(9, 7, 6, 255));
+        }
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            screen_height(),
+            Color::from_rgba(0, 0, 0, 178),
+        );
+
+        draw_header(self.turn, self.phase, &self.players);
+        let rows: Vec<FormationRow> = (0..self.players.len())
+            .map(|i| self.player_row(i))
+            .collect();
+        let lethal_targets = self.lethal_targets();
+        let lethal = !lethal_targets.is_empty();
+        let preview = self.selected_card_preview();
+        draw_party_panel(
+            &self.players,
+            &rows,
+            self.current_player_idx,
+            self.energy,
+            self.max_energy,
+            &lethal_targets,
+            preview.as_ref().map(|(player, _)| player),
+            textures,
+        );
+        draw_enemy_stage(
+            &self.enemy,
+            lethal,
+            preview.as_ref().map(|(_, enemy)| enemy),
+            textures,
+        );
+        draw_reinforcements(&self.enemy, &self.reserve);
+
+        let order = self.display_order();
+        let preview_idx = hovered_card_index(&order).or(self.selected_card);
+        draw_report_panel(self, preview_idx);
+        draw_feedback_panel(self.feedback.as_ref());
+
+        let mut hovered_card_idx: Option<usize> = None;
+        for (slot, i) in order.iter().copied().enumerate() {
+            let card = &self.hand[i];
+            let (x, y, w, h) = combat_card_rect(slot, self.hand.len());
+            let is_hovered = crate::ui::is_mouse_over(x, y, w, h);
+            if is_hovered {
+                hovered_card_idx = Some(i);
+            }
+            let effective_cost = self.effective_card_cost(card);
+            let can_afford = self.can_afford(card);
+            let attack_blocked = card.is_attack() && self.resolver.turn_mods.attacks_disabled;
+            let shake = match self.refused_card {
+                Some((idx, time)) if idx == i => refused_shake(time),
+                _ => 0.0,
+            };
+            let off_class = !self.class_can_play(card);
+            let state = HandCard {
+                selected: self.selected_card == Some(i),
+                hovered: is_hovered,
+                can_play: can_afford && !attack_blocked && !off_class,
+                attack_blocked,
+                off_class,
+                effective_cost,
+                shake,
+            };
+            draw_combat_card(slot, card, self.hand.len(), &state, textures);
+        }
+
+        let (end_x, end_y, end_w, end_h) = end_turn_button_rect();
+        draw_action_button("End Turn", end_x, end_y, end_w, end_h);
+        let (draw_count, discard_count) = self.pile_counts();
+        draw_ui_text(
+            &format!("Draw {}   Discard {}", draw_count, discard_count),
+            screen_width() - 324.0,
+            end_y - 12.0,
+            15.0,
+            muted_text_color(),
+        );
+        let shortcuts = if self.simultaneous_turns {
+            let (phase_x, phase_y, phase_w, phase_h) = end_phase_button_rect();
+            draw_action_button("End Phase", phase_x, phase_y, phase_w, phase_h);
+            "Shortcuts: 1-5 Select - Enter Play - E End Turn - P End Phase - F1 Help"
+        } else {
+            "Shortcuts: 1-5 Select - Enter Play - E End Turn - F1 Help"
+        };
+        draw_ui_text(
+            shortcuts,
+            24.0,
+            screen_height() - 26.0,
+            14.0,
+            muted_text_color(),
+        );
+
+        if let Some(idx) = hovered_card_idx {
+            if let Some(card) = self.hand.get(idx) {
+                crate::ui::card_tooltip(&card.name, &card.description);
+            }
+        }
+
+        if self.pending_end.is_some() {
+            draw_end_confirm(&self.enemy_forecast());
+        }
+
+        if let Some(message) = &self.trauma_interrupt {
+            draw_trauma_interrupt(message);
+        }
+
+        if let Some(summary) = &self.victory {
+            draw_victory_overlay(summary, &self.players);
+        }
+    }
+}
+
+fn draw_end_confirm(forecast: &str) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 150),
+    );
+    let (x, y, w, h) = end_confirm_panel_rect();
+    panel(x, y, w, h, "END TURN?");
+    draw_wrapped_text(forecast, x + 20.0, y + 66.0, w - 40.0, 17.0, text_color());
+    draw_ui_text(
+        "Enter confirm - Esc cancel",
+        x + 20.0,
+        y + 116.0,
+        14.0,
+        muted_text_color(),
+    );
+    let (cx, cy, cw, ch) = end_confirm_button_rect();
+    draw_action_button("Confirm", cx, cy, cw, ch);
+    let (nx, ny, nw, nh) = end_cancel_button_rect();
+    draw_action_button("Cancel", nx, ny, nw, nh);
+}
+
+fn draw_trauma_interrupt(message: &str) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(20, 0, 0, 170),
+    );
+    let (x, y, w, h) = end_confirm_panel_rect();
+    panel(x, y, w, h, "STRESS TAKES HOLD");
+    draw_wrapped_text(message, x + 20.0, y + 70.0, w - 40.0, 20.0, danger_color());
+    draw_ui_text(
+        "Enter or click to steel yourselves",
+        x + 20.0,
+        y + h - 24.0,
+        14.0,
+        muted_text_color(),
+    );
+}
+
+fn draw_victory_overlay(summary: &VictorySummary, players: &[Unit]) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 150),
+    );
+    let w = 440.0;
+    let h = 250.0;
+    let x = (screen_width() - w) / 2.0;
+    let y = (screen_height() - h) / 2.0;
+    panel(x, y, w, h, "VICTORY");
+
+    draw_ui_text(
+        &format!("{} is defeated.", summary.enemy_name),
+        x + 20.0,
+        y + 62.0,
+        22.0,
+        title_color(),
+    );
+    let killer = summary
+        .killer
+        .and_then(|idx| players.get(idx))
+        .map(|player| format!("Killing blow: {}", player.name))
+        .unwrap_or_else(|| "The enemy fell to its wounds.".to_string());
+    draw_ui_text(&killer, x + 20.0, y + 96.0, 17.0, text_color());
+    draw_ui_text(
+        &format!("+{} XP for each surviving member", summary.xp),
+        x + 20.0,
+        y + 124.0,
+        17.0,
+        ready_color(),
+    );
+    let drops = if summary.drops.is_empty() {
+        "No spoils dropped.".to_string()
+    } else {
+        let names: Vec<&str> = summary
+            .drops
+            .iter()
+            .map(|card| card.name.as_str())
+            .collect();
+        format!("Spoils: {}", names.join(", "))
+    };
+    draw_ui_text(&drops, x + 20.0, y + 152.0, 17.0, candle_color());
+    draw_ui_text(
+        "[Enter] Continue",
+        x + 20.0,
+        y + h - 24.0,
+        15.0,
+        muted_text_color(),
+    );
+}
+
+fn draw_header(turn: usize, phase: Phase, players: &[Unit]) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        72.0,
+        Color::from_rgba(8, 7, 6, 232),
+    );
+    draw_line(0.0, 72.0, screen_width(), 72.0, 2.0, border_color());
+    draw_ui_text("COMBAT", 24.0, 42.0, 34.0, title_color());
+    draw_ui_text(&format!("Turn {}", turn), 188.0, 42.0, 20.0, candle_color());
+    let phase_label = match phase {
+        Phase::PlayerTurn { member } => players
+            .get(member)
+            .map(|player| format!("{}'s turn", player.name))
+            .unwrap_or_default(),
+        Phase::EnemyTurn => "Enemy phase".to_string(),
+    };
+    draw_ui_text(&phase_label, 290.0, 42.0, 20.0, muted_text_color());
+}
+
+fn draw_party_panel(
+    players: &[Unit],
+    rows: &[FormationRow],
+    current_player_idx: usize,
+    energy: i32,
+    max_energy: i32,
+    lethal_targets: &[usize],
+    preview: Option<&Unit>,
+    textures: &std::collections::HashMap<String, Texture2D>,
+) {
+    panel(24.0, 92.0, 260.0, 328.0, "PLAYER AREA");
+    let Some(active) = players.get(current_player_idx) else {
+        return;
+    };
+
+    draw_ui_text(&active.name, 44.0, 148.0, 24.0, title_color());
+    if lethal_targets.contains(&current_player_idx) {
+        draw_rectangle_lines(38.0, 160.0, 232.0, 26.0, 2.0, lethal_flash_color());
+    }
+    let hp = format!(
+        "HP {}/{}    Block {}",
+        active.hp, active.max_hp, active.block
+    );
+    draw_ui_text(&hp, 44.0, 178.0, 16.0, text_color());
+    if let Some(suffix) = preview.and_then(|after| preview_suffix(active, after)) {
+        let hp_w = measure_ui_text(&hp, None, 16, 1.0).width;
+        draw_ui_text(&suffix, 44.0 + hp_w, 178.0, 16.0, preview_color());
+    }
+    draw_block_bar(active);
+    let vitals = format!("Stress {}    Energy", active.stress);
+    draw_ui_text(&vitals, 44.0, 216.0, 16.0, muted_text_color());
+    let vitals_w = measure_ui_text(&vitals, None, 16, 1.0).width;
+    crate::ui::draw_energy_pips(44.0 + vitals_w + 8.0, 216.0, energy, max_energy);
+    if let Some(resolve) = &active.resolve_state {
+        let (label, color) = match resolve {
+            ResolveState::Virtuous => ("Virtuous", ready_color()),
+            ResolveState::Afflicted => ("Afflicted", danger_color()),
+        };
+        draw_ui_text(label, 44.0, 240.0, 16.0, color);
+    }
+
+    draw_ui_text("Party", 44.0, 268.0, 16.0, candle_color());
+    for (i, player) in players.iter().enumerate().take(4) {
+        let y = 300.0 + (i as f32 * 30.0);
+        let marker = if i == current_player_idx { ">" } else { " " };
+        if lethal_targets.contains(&i) {
+            draw_rectangle_lines(38.0, y - 22.0, 232.0, 28.0, 2.0, lethal_flash_color());
+        }
+        draw_ui_text(marker, 44.0, y, 15.0, candle_color());
+        if let Some(path) = &player.image_path {
+            if let Some(tex) = textures.get(path) {
+                draw_texture_ex(
+                    tex,
+                    64.0,
+                    y - 20.0,
+                    if player.hp <= 0 {
+                        Color::from_rgba(90, 90, 90, 255)
+                    } else {
+                        WHITE
+                    },
+                    DrawTextureParams {
+                        dest_size: Some(vec2(22.0, 22.0)),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        let block = if player.block > 0 {
+            format!("  Blk {}", player.block)
+        } else {
+            String::new()
+        };
+        draw_ui_text(
+            &format!(
+                "{}  {}/{}{}  {}",
+                player.name,
+                player.hp,
+                player.max_hp,
+                block,
+                rows.get(i).map(|row| row.label()).unwrap_or_default()
+            ),
+            94.0,
+            y,
+            14.0,
+            if player.hp <= 0 {
+                danger_color()
+            } else {
+                text_color()
+            },
+        );
+    }
+}
+
+/// Announced reinforcements, and those already waiting behind the enemy
+fn draw_reinforcements(enemy: &Unit, reserve: &[Unit]) {
+    let mut y = 124.0;
+    if enemy
+        .reinforcement
+        .as_ref()
+        .is_some_and(|call| call.telegraphed)
+    {
+        draw_ui_text("Something approaches...", 332.0, y, 16.0, danger_color());
+        y += 20.0;
+    }
+    for waiting in reserve {
+        draw_ui_text(
+            &format!("Waiting: {}", waiting.name),
+            332.0,
+            y,
+            16.0,
+            muted_text_color(),
+        );
+        y += 20.0;
+    }
+}
+
+fn draw_enemy_stage(
+    enemy: &Unit,
+    lethal: bool,
+    preview: Option<&Unit>,
+    textures: &std::collections::HashMap<String, Texture2D>,
+) {
+    panel(308.0, 92.0, 644.0, 204.0, "ENEMY AREA");
+    let center_x = 630.0;
+
+    if let Some(path) = &enemy.image_path {
+        if let Some(tex) = textures.get(path) {
+            draw_texture_ex(
+                tex,
+                center_x - 68.0,
+                128.0,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(136.0, 136.0)),
+                    ..Default::default()
+                },
+            );
+        }
+    } else {
+        draw_circle(center_x, 190.0, 54.0, Color::from_rgba(62, 44, 38, 255));
+    }
+
+    let name_w = measure_ui_text(&enemy.name, None, 26, 1.0).width;
+    draw_ui_text(
+        &enemy.name,
+        center_x - name_w / 2.0,
+        128.0,
+        26.0,
+        title_color(),
+    );
+    let hp = format!("HP {}/{}    Block {}", enemy.hp, enemy.max_hp, enemy.block);
+    let hp_w = measure_ui_text(&hp, None, 17, 1.0).width;
+    let suffix = preview.and_then(|after| preview_suffix(enemy, after));
+    let suffix_w = suffix
+        .as_ref()
+        .map(|suffix| measure_ui_text(suffix, None, 17, 1.0).width)
+        .unwrap_or(0.0);
+    let hp_x = center_x - (hp_w + suffix_w) / 2.0;
+    draw_ui_text(&hp, hp_x, 280.0, 17.0, text_color());
+    if let Some(suffix) = &suffix {
+        draw_ui_text(suffix, hp_x + hp_w, 280.0, 17.0, preview_color());
+    }
+
+    let intent = format!("Intent: {}", enemy.intent.description());
+    let intent_color = if lethal {
+        lethal_flash_color()
+    } else {
+        match &enemy.intent {
+            crate::combat::EnemyIntent::Attack(_) | crate::combat::EnemyIntent::AttackAll(_) => {
+                danger_color()
+            }
+            crate::combat::EnemyIntent::Block(_) => info_color(),
+            crate::combat::EnemyIntent::Buff => candle_color(),
+            crate::combat::EnemyIntent::Debuff => mystery_color(),
+            crate::combat::EnemyIntent::Special(action) if action.deals_damage() => danger_color(),
+            crate::combat::EnemyIntent::Special(_) => mystery_color(),
+            crate::combat::EnemyIntent::Unknown => muted_text_color(),
+        }
+    };
+    draw_rectangle(720.0, 144.0, 196.0, 86.0, Color::from_rgba(22, 18, 16, 220));
+    draw_rectangle_lines(
+        720.0,
+        144.0,
+        196.0,
+        86.0,
+        if lethal { 3.0 } else { 1.0 },
+        intent_color,
+    );
+    draw_ui_text("NEXT", 740.0, 172.0, 16.0, muted_text_color());
+    if lethal {
+        draw_ui_text("LETHAL", 846.0, 172.0, 16.0, intent_color);
+    }
+    draw_wrapped_text(&intent, 740.0, 202.0, 156.0, 20.0, intent_color);
+
+    if !enemy.statuses.is_empty() {
+        let mut x = 332.0;
+        for status in enemy.statuses.iter().take(4) {
+            let color = if status.effect_type.is_debuff() {
+                danger_color()
+            } else {
+                ready_color()
+            };
+            draw_ui_text(
+                &format!(
+                    "{}{:?} {}",
+                    crate::ui::status_glyph(&status.effect_type),
+                    status.effect_type,
+                    status.duration
+                ),
+                x,
+                274.0,
+                14.0,
+                color,
+            );
+            x += 112.0;
+        }
+    }
+}
+
+/// Block as a bar against max HP that drains as hits land; it all clears once the enemy acts
+fn draw_block_bar(unit: &Unit) {
+    if unit.block <= 0 {
+        return;
+    }
+    let (x, y, w, h) = (44.0, 188.0, 120.0, 6.0);
+    let fill = (unit.block as f32 / unit.max_hp.max(1) as f32).min(1.0);
+    draw_rectangle(x, y, w, h, Color::from_rgba(30, 36, 40, 230));
+    draw_rectangle(x, y, w * fill, h, info_color());
+    draw_rectangle_lines(x, y, w, h, 1.0, border_color());
+    draw_ui_text("(expires)", x + w + 8.0, y + 7.0, 11.0, muted_text_color());
+}
+
+/// Ghost readout of HP and Block after the selected card, or None when it changes neither
+fn preview_suffix(before: &Unit, after: &Unit) -> Option<String> {
+    if before.hp == after.hp && before.block == after.block {
+        return None;
+    }
+    Some(format!("  -> {} HP  {} Blk", after.hp, after.block))
+}
+
+fn draw_feedback_panel(feedback: Option<&(String, f32)>) {
+    let Some((message, time_left)) = feedback else {
+        return;
+    };
+
+    let alpha = ((*time_left / 2.0).clamp(0.0, 1.0) * 210.0) as u8;
+    let width = measure_ui_text(message, None, 18, 1.0).width + 42.0;
+    let x = (screen_width() - width) / 2.0;
+    let y = 432.0;
+    draw_rectangle(x, y, width, 42.0, Color::from_rgba(28, 21, 14, alpha));
+    draw_rectangle_lines(x, y, width, 42.0, 1.0, candle_color());
+    draw_ui_text(message, x + 20.0, y + 27.0, 18.0, text_color());
+}
+
+fn draw_action_button(label: &str, x: f32, y: f32, w: f32, h: f32) {
+    let hovered = crate::ui::is_mouse_over(x, y, w, h);
+    let pressed = clicked_down(x, y, w, h);
+    let fill = if pressed {
+        Color::from_rgba(130, 92, 39, 255)
+    } else if hovered {
+        Color::from_rgba(95, 67, 31, 245)
+    } else {
+        Color::from_rgba(70, 49, 27, 238)
+    };
+    draw_rectangle(x, y, w, h, fill);
+    draw_rectangle_lines(x, y, w, h, 1.0, candle_color());
+    let tw = measure_ui_text(label, None, 16, 1.0).width;
+    draw_ui_text(label, x + (w - tw) / 2.0, y + 24.0, 16.0, text_color());
+}
+
+pub(super) fn clicked_down(x: f32, y: f32, w: f32, h: f32) -> bool {
+    crate::ui::is_mouse_over(x, y, w, h) && is_mouse_button_pressed(MouseButton::Left)
+}
+
+fn panel(x: f32, y: f32, w: f32, h: f32, title: &str) {
+    draw_rectangle(x, y, w, h, Color::from_rgba(13, 11, 10, 210));
+    draw_rectangle(x, y, w, 32.0, Color::from_rgba(42, 30, 18, 222));
+    draw_rectangle_lines(x, y, w, h, 1.0, border_color());
+    draw_ui_text(title, x + 14.0, y + 22.0, 15.0, candle_color());
+}
+
+pub(super) fn end_turn_button_rect() -> (f32, f32, f32, f32) {
+    (screen_width() - 168.0, screen_height() - 58.0, 144.0, 38.0)
+}
+
+fn end_confirm_panel_rect() -> (f32, f32, f32, f32) {
+    let (w, h) = (460.0, 190.0);
+    (
+        (screen_width() - w) / 2.0,
+        (screen_height() - h) / 2.0,
+        w,
+        h,
+    )
+}
+
+pub(super) fn end_confirm_button_rect() -> (f32, f32, f32, f32) {
+    let (x, y, w, h) = end_confirm_panel_rect();
+    (x + w - 296.0, y + h - 54.0, 130.0, 38.0)
+}
+
+pub(super) fn end_cancel_button_rect() -> (f32, f32, f32, f32) {
+    let (x, y, w, h) = end_confirm_panel_rect();
+    (x + w - 150.0, y + h - 54.0, 130.0, 38.0)
+}
+
+pub(super) fn end_phase_button_rect() -> (f32, f32, f32, f32) {
+    (screen_width() - 324.0, screen_height() - 58.0, 144.0, 38.0)
+}
+
+pub(super) fn combat_card_rect(i: usize, hand_len: usize) -> (f32, f32, f32, f32) {
+    let card_w = 142.0;
+    let card_h = 202.0;
+    let gap = 14.0;
+    let count = hand_len.max(1).min(5) as f32;
+    let total_w = count * card_w + (count - 1.0) * gap;
+    let x = (screen_width() - total_w) / 2.0 + (i as f32 * (card_w + gap));
+    (x, screen_height() - 244.0, card_w, card_h)
+}
+
+/// Sideways offset of a refused card, dying away over its shake time
+fn refused_shake(time_left: f32) -> f32 {
+    let fade = (time_left / super::play::REFUSED_SHAKE_SECS).min(1.0);
+    (get_time() as f32 * 60.0).sin() * 6.0 * fade
+}
+
+/// Hand index of the hovered card, given hand indices in display order
+fn hovered_card_index(order: &[usize]) -> Option<usize> {
+    for (slot, idx) in order.iter().enumerate().take(5) {
+        let (x, y, w, h) = combat_card_rect(slot, order.len());
+        if crate::ui::is_mouse_over(x, y, w, h) {
+            return Some(*idx);
+        }
+    }
+    None
+}
+
+fn draw_wrapped_text(text: &str, x: f32, y: f32, max_width: f32, font_size: f32, color: Color) {
+    let mut line = String::new();
+    let mut line_y = y;
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", line, word)
+        };
+        if measure_ui_text(&candidate, None, font_size as u16, 1.0).width > max_width
+            && !line.is_empty()
+        {
+            draw_ui_text(&line, x, line_y, font_size, color);
+            line = word.to_string();
+            line_y += font_size + 5.0;
+        } else {
+            line = candidate;
+        }
+    }
+    if !line.is_empty() {
+        draw_ui_text(&line, x, line_y, font_size, color);
+    }
+}
+
+fn text_color() -> Color {
+    Color::from_rgba(230, 221, 205, 255)
+}
+
+fn muted_text_color() -> Color {
+    Color::from_rgba(158, 145, 126, 255)
+}
+
+fn title_color() -> Color {
+    Color::from_rgba(239, 224, 190, 255)
+}
+
+fn candle_color() -> Color {
+    Color::from_rgba(207, 151, 54, 255)
+}
+
+fn ready_color() -> Color {
+    Color::from_rgba(130, 177, 101, 255)
+}
+
+/// Costs pushed up by a trauma or injury
+fn surcharge_color() -> Color {
+    Color::from_rgba(232, 112, 36, 255)
+}
+
+fn danger_color() -> Color {
+    Color::from_rgba(168, 58, 48, 255)
+}
+
+/// Pulses between deep and bright red to flag a killing blow
+fn lethal_flash_color() -> Color {
+    if (get_time() * 4.0) as i64 % 2 == 0 {
+        danger_color()
+    } else {
+        Color::from_rgba(255, 70, 60, 255)
+    }
+}
+
+fn info_color() -> Color {
+    Color::from_rgba(118, 151, 164, 255)
+}
+
+fn preview_color() -> Color {
+    Color::from_rgba(120, 214, 236, 255)
+}
+
+fn mystery_color() -> Color {
+    Color::from_rgba(138, 104, 167, 255)
+}
+
+fn border_color() -> Color {
+    Color::from_rgba(105, 76, 43, 210)
+}
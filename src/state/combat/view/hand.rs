@@ -0,0 +1,208 @@
+//! Hand cards - cost, art, class badge, and whether each can be played right now
+
+use super::*;
+
+/// How a card in hand stands this frame
+#[derive(Clone, Copy)]
+pub(super) struct HandCard {
+    pub selected: bool,
+    pub hovered: bool,
+    pub can_play: bool,
+    pub attack_blocked: bool,
+    /// Restricted to a class other than the active member's
+    pub off_class: bool,
+    pub effective_cost: i32,
+    pub shake: f32,
+}
+
+pub(super) fn draw_combat_card(
+    i: usize,
+    card: &Card,
+    hand_len: usize,
+    state: &HandCard,
+    textures: &std::collections::HashMap<String, Texture2D>,
+) {
+    let HandCard {
+        selected,
+        hovered,
+        can_play,
+        attack_blocked,
+        off_class,
+        effective_cost,
+        shake,
+    } = *state;
+    let (x, y, w, h) = combat_card_rect(i, hand_len);
+    let x = x + shake;
+    let accent = card_accent(card);
+    let border = if shake.abs() > f32::EPSILON {
+        lethal_flash_color()
+    } else if attack_blocked {
+        mystery_color()
+    } else if selected {
+        candle_color()
+    } else if hovered && can_play {
+        ready_color()
+    } else if !can_play {
+        danger_color()
+    } else {
+        accent
+    };
+
+    draw_rectangle(x, y, w, h, Color::from_rgba(18, 16, 14, 245));
+    draw_rectangle_lines(x, y, w, h, if selected { 3.0 } else { 2.0 }, border);
+    draw_rectangle(
+        x + 6.0,
+        y + 6.0,
+        w - 12.0,
+        26.0,
+        Color::from_rgba(38, 32, 26, 246),
+    );
+    let cost_label = if card.is_x_cost() {
+        "X".to_string()
+    } else {
+        effective_cost.to_string()
+    };
+    draw_ui_text(
+        &cost_label,
+        x + 14.0,
+        y + 25.0,
+        20.0,
+        if !can_play {
+            danger_color()
+        } else if effective_cost < card.cost {
+            ready_color()
+        } else if effective_cost > card.cost {
+            surcharge_color()
+        } else {
+            candle_color()
+        },
+    );
+    draw_ui_text(
+        card_type(card),
+        x + w - 64.0,
+        y + 24.0,
+        13.0,
+        muted_text_color(),
+    );
+
+    let art_x = x + 8.0;
+    let art_y = y + 38.0;
+    let art_w = w - 16.0;
+    let art_h = 78.0;
+    draw_rectangle(
+        art_x,
+        art_y,
+        art_w,
+        art_h,
+        Color::from_rgba(42, 38, 34, 255),
+    );
+    if let Some(path) = &card.image_path {
+        if let Some(tex) = textures.get(path) {
+            draw_texture_ex(
+                tex,
+                art_x,
+                art_y,
+                if attack_blocked {
+                    Color::from_rgba(130, 105, 140, 255)
+                } else {
+                    WHITE
+                },
+                DrawTextureParams {
+                    dest_size: Some(vec2(art_w, art_h)),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+    draw_rectangle(art_x, art_y, art_w, art_h, Color::from_rgba(0, 0, 0, 55));
+    if let Some(class) = card.class.label() {
+        draw_class_badge(class, art_x + art_w, art_y, off_class);
+    }
+
+    draw_ui_text(&card.name, x + 10.0, y + 136.0, 15.0, text_color());
+    let status = if off_class {
+        "Wrong class"
+    } else if attack_blocked {
+        "Blocked this turn"
+    } else if can_play {
+        "Ready"
+    } else {
+        "Need energy"
+    };
+    draw_ui_text(
+        status,
+        x + 10.0,
+        y + 158.0,
+        12.0,
+        if can_play {
+            ready_color()
+        } else {
+            danger_color()
+        },
+    );
+    draw_wrapped_text(
+        &card.description,
+        x + 10.0,
+        y + 178.0,
+        w - 20.0,
+        11.0,
+        muted_text_color(),
+    );
+}
+
+/// Class tag pinned to the art's top-right corner; red when the active member cannot use it
+fn draw_class_badge(class: &str, right: f32, top: f32, off_class: bool) {
+    let w = measure_ui_text(class, None, 11, 1.0).width + 10.0;
+    let color = if off_class {
+        danger_color()
+    } else {
+        info_color()
+    };
+    draw_rectangle(
+        right - w - 2.0,
+        top + 2.0,
+        w,
+        16.0,
+        Color::from_rgba(18, 16, 14, 230),
+    );
+    draw_rectangle_lines(right - w - 2.0, top + 2.0, w, 16.0, 1.0, color);
+    draw_ui_text(class, right - w + 3.0, top + 14.0, 11.0, color);
+}
+
+fn card_type(card: &Card) -> &'static str {
+    if card.is_attack() {
+        "Attack"
+    } else if card
+        .effects
+        .iter()
+        .any(|effect| matches!(effect, crate::combat::CardEffect::Block(_)))
+    {
+        "Guard"
+    } else if card
+        .effects
+        .iter()
+        .any(|effect| matches!(effect, crate::combat::CardEffect::Heal(_)))
+    {
+        "Heal"
+    } else if card.effects.iter().any(|effect| {
+        matches!(
+            effect,
+            crate::combat::CardEffect::EnemyStress(_)
+                | crate::combat::CardEffect::ApplyStatus { .. }
+        )
+    }) {
+        "Mystic"
+    } else {
+        "Skill"
+    }
+}
+
+fn card_accent(card: &Card) -> Color {
+    match card_type(card) {
+        "Attack" => Color::from_rgba(143, 61, 49, 255),
+        "Guard" => Color::from_rgba(105, 128, 139, 255),
+        "Heal" => Color::from_rgba(128, 160, 96, 255),
+        "Mystic" => Color::from_rgba(132, 96, 158, 255),
+        _ => Color::from_rgba(171, 126, 62, 255),
+    }
+}
@@ -0,0 +1,142 @@
+//! Battle report panel - card previews, the combat log, and intent warnings
+
+use super::*;
+
+pub(super) fn draw_report_panel(state: &CombatState, preview_idx: Option<usize>) {
+    panel(308.0, 314.0, 644.0, 106.0, "BATTLE REPORT");
+    if let Some(idx) = preview_idx {
+        if let Some(card) = state.hand.get(idx) {
+            draw_ui_text(&card.name, 330.0, 362.0, 20.0, candle_color());
+            draw_wrapped_text(
+                &card_preview(state, card),
+                330.0,
+                390.0,
+                590.0,
+                15.0,
+                text_color(),
+            );
+            return;
+        }
+    }
+
+    let mut y = 358.0;
+    let mut drew_any = false;
+    for line in state.resolver.log.iter().rev().take(3).rev() {
+        draw_ui_text(line, 330.0, y, 15.0, muted_text_color());
+        y += 22.0;
+        drew_any = true;
+    }
+    if !drew_any {
+        let Some(player) = state.players.get(state.intent_target) else {
+            return;
+        };
+        draw_ui_text(
+            &intent_warning(&player.name, &state.enemy.intent),
+            330.0,
+            372.0,
+            16.0,
+            muted_text_color(),
+        );
+    }
+}
+
+fn card_preview(state: &CombatState, card: &Card) -> String {
+    let player = state.players.get(state.current_player_idx);
+    let enemy_after = state
+        .preview_card(card)
+        .map(|(_, enemy)| enemy)
+        .unwrap_or_else(|| state.enemy.clone());
+    let mut parts = Vec::new();
+    for effect in &card.effects {
+        match effect {
+            crate::combat::CardEffect::Damage(amount) => parts.push(format!(
+                "Deal {} damage to {}. Enemy HP after: {}/{}.",
+                amount, state.enemy.name, enemy_after.hp, state.enemy.max_hp
+            )),
+            crate::combat::CardEffect::Block(amount) => parts.push(format!(
+                "Gain {} Block. Block after: {}.",
+                amount,
+                player.map(|p| p.block + amount).unwrap_or(*amount)
+            )),
+            crate::combat::CardEffect::Heal(amount) => parts.push(format!(
+                "Heal {} HP. HP after: {}/{}.",
+                amount,
+                player
+                    .map(|p| (p.hp + amount).min(p.max_hp))
+                    .unwrap_or(*amount),
+                player.map(|p| p.max_hp).unwrap_or(*amount)
+            )),
+            crate::combat::CardEffect::ReduceStress(amount) => {
+                parts.push(format!("Reduce stress by {}.", amount));
+            }
+            crate::combat::CardEffect::DrawCards(amount) => {
+                parts.push(format!("Draw {} card(s).", amount));
+            }
+            crate::combat::CardEffect::GainEnergy(amount) => {
+                parts.push(format!("Gain {} energy this turn.", amount));
+            }
+            crate::combat::CardEffect::ReduceCardCost(amount) => {
+                parts.push(format!("Cards cost {} less this turn.", amount));
+            }
+            crate::combat::CardEffect::DamagePerEnergy(amount) => parts.push(format!(
+                "Spend all energy: {} damage per energy. Enemy HP after: {}/{}.",
+                amount, enemy_after.hp, state.enemy.max_hp
+            )),
+            crate::combat::CardEffect::Revive(amount) => match state.first_downed_ally() {
+                Some(idx) => parts.push(format!(
+                    "Revive {} with {} HP.",
+                    state.players[idx].name, amount
+                )),
+                None => parts.push(format!("Revive a downed ally with {} HP.", amount)),
+            },
+            crate::combat::CardEffect::EnemyStress(amount) => {
+                parts.push(format!("Apply {} stress to the enemy.", amount));
+            }
+            crate::combat::CardEffect::ApplyStatus {
+                effect_type,
+                duration,
+                ..
+            } => parts.push(format!("Apply {:?} for {} turn(s).", effect_type, duration)),
+            _ => parts.push(card.description.clone()),
+        }
+    }
+    if let Some(reason) = state.wasted_effect(card) {
+        parts.push(format!("No effect: {}.", reason));
+    }
+    if parts.is_empty() {
+        card.description.clone()
+    } else {
+        parts.join(" ")
+    }
+}
+
+fn intent_warning(player_name: &str, intent: &crate::combat::EnemyIntent) -> String {
+    match intent {
+        crate::combat::EnemyIntent::Attack(amount) => {
+            format!(
+                "{} will take {} damage unless blocked.",
+                player_name, amount
+            )
+        }
+        crate::combat::EnemyIntent::AttackAll(amount) => {
+            format!(
+                "The whole party will take {} damage unless blocked.",
+                amount
+            )
+        }
+        crate::combat::EnemyIntent::Block(amount) => {
+            format!("{} will gain {} Block if left alone.", "Enemy", amount)
+        }
+        crate::combat::EnemyIntent::Buff => "Enemy is preparing a buff.".to_string(),
+        crate::combat::EnemyIntent::Debuff => {
+            format!("{} is about to be weakened, bled or stressed.", player_name)
+        }
+        crate::combat::EnemyIntent::Special(action) if action.targets_party() => {
+            format!("{} is the target of {}.", player_name, action.name)
+        }
+        crate::combat::EnemyIntent::Special(action) => {
+            format!("Enemy is preparing {}.", action.name)
+        }
+        crate::combat::EnemyIntent::Unknown => "Enemy intent is hidden.".to_string(),
+    }
+}
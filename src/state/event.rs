@@ -1,20 +1,19 @@
 //! Event state - narrative encounters with choices
 
 use super::{MissionState, StateTransition};
-use crate::kingdom::PartyMemberState;
+use crate::kingdom::{fill_pronouns, PartyMemberState};
 use crate::missions::events::{Event, EventOutcome};
 use crate::missions::{MapNode, Mission};
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// State for handling mission events
 pub struct EventState {
     pub event: Event,
     pub selected_choice: usize,
-    #[allow(dead_code)]
     pub adventurer_id: String,
-    #[allow(dead_code)]
     pub adventurer_name: String,
     pub return_to_mission: bool,
     /// Applied outcomes to pass back
@@ -29,7 +28,7 @@ pub struct EventState {
 }
 
 /// Context for returning to mission after event
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MissionReturnContext {
     pub mission: Mission,
     pub current_node: usize,
@@ -38,6 +37,15 @@ pub struct MissionReturnContext {
     pub visited_nodes: Vec<usize>,
 }
 
+/// An unanswered event saved mid-mission; choices resolve the moment they are made
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventSnapshot {
+    pub event: Event,
+    pub adventurer_id: String,
+    pub adventurer_name: String,
+    pub mission_context: Option<MissionReturnContext>,
+}
+
 impl EventState {
     pub fn new(event: Event, adventurer_id: String, adventurer_name: String) -> Self {
         Self {
@@ -56,6 +64,26 @@ impl EventState {
         }
     }
 
+    /// The event as it stood before any choice, for an in-progress save
+    pub fn snapshot(&self) -> EventSnapshot {
+        EventSnapshot {
+            event: self.event.clone(),
+            adventurer_id: self.adventurer_id.clone(),
+            adventurer_name: self.adventurer_name.clone(),
+            mission_context: self.mission_context.clone(),
+        }
+    }
+
+    pub fn from_snapshot(snapshot: EventSnapshot) -> Self {
+        let mut state = Self::new(
+            snapshot.event,
+            snapshot.adventurer_id,
+            snapshot.adventurer_name,
+        );
+        state.mission_context = snapshot.mission_context;
+        state
+    }
+
     /// Create event with mission context for returning
     pub fn with_mission_context(
         mut self,
@@ -76,61 +104,9 @@ impl EventState {
     }
 
     pub fn update(&mut self) -> Option<StateTransition> {
-        // Choice layout constants (must match draw)
-        let panel_x = 100.0;
-        let panel_y = 80.0;
-        let panel_w = screen_width() - 200.0;
-        let choices_y = panel_y + 200.0;
-        let choice_height = 45.0;
-
-        // Choice selection
-        let choice_count = self.event.choices.len();
-
-        // Keyboard navigation
-        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
-            if self.selected_choice > 0 {
-                self.selected_choice -= 1;
-            }
-        }
-        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
-            if self.selected_choice < choice_count.saturating_sub(1) {
-                self.selected_choice += 1;
-            }
-        }
-
-        // Number keys and mouse clicks
-        for i in 0..choice_count.min(9) {
-            let key = match i {
-                0 => KeyCode::Key1,
-                1 => KeyCode::Key2,
-                2 => KeyCode::Key3,
-                3 => KeyCode::Key4,
-                4 => KeyCode::Key5,
-                _ => continue,
-            };
-
-            // Keyboard
-            if is_key_pressed(key) {
-                self.selected_choice = i;
-            }
-
-            // Mouse click on choice
-            let choice_y = choices_y + 35.0 + (i as f32 * 50.0) - 15.0;
-            if crate::ui::was_clicked(panel_x + 20.0, choice_y, panel_w - 40.0, choice_height) {
-                if self.selected_choice == i {
-                    // Click on selected = confirm
-                    return self.confirm_choice();
-                } else {
-                    self.selected_choice = i;
-                }
-            }
-        }
-
-        // Confirm choice with Enter
-        if is_key_pressed(KeyCode::Enter) {
+        if update_choice_input(self.event.choices.len(), &mut self.selected_choice) {
             return self.confirm_choice();
         }
-
         None
     }
 
@@ -151,6 +127,8 @@ impl EventState {
                     EventOutcome::RevealTrait => {
                         self.knowledge_change += 5;
                     }
+                    // Kingdom-scoped outcomes have no meaning on the trail
+                    EventOutcome::Gold(_) | EventOutcome::Morale(_) => {}
                     EventOutcome::Nothing => {}
                 }
             }
@@ -165,11 +143,13 @@ impl EventState {
                     member.stress = (member.stress + self.stress_change).min(100).max(0);
                 }
 
-                let mission_state =
-                    MissionState::from_mission_with_party(ctx.mission.clone(), updated_members)
-                        .with_node(ctx.current_node)
-                        .with_map_nodes(ctx.map_nodes.clone())
-                        .with_visited(ctx.visited_nodes.clone());
+                let mission_state = MissionState::from_mission_with_party(
+                    ctx.mission.clone(),
+                    updated_members,
+                    Some(ctx.map_nodes.clone()),
+                )
+                .with_node(ctx.current_node)
+                .with_visited(ctx.visited_nodes.clone());
                 return Some(StateTransition::ToMission(mission_state));
             } else {
                 return Some(StateTransition::ToBase);
@@ -179,127 +159,194 @@ impl EventState {
     }
 
     pub fn draw(&self, _textures: &HashMap<String, Texture2D>) {
-        // Darken background
-        draw_rectangle(
-            0.0,
-            0.0,
-            screen_width(),
-            screen_height(),
-            Color::from_rgba(0, 0, 0, 180),
-        );
+        draw_event_panel(&self.event_for_leader(), self.selected_choice);
+    }
 
-        // Event panel
-        let panel_x = 100.0;
-        let panel_y = 80.0;
-        let panel_w = screen_width() - 200.0;
-        let panel_h = screen_height() - 160.0;
-
-        draw_rectangle(
-            panel_x,
-            panel_y,
-            panel_w,
-            panel_h,
-            Color::from_rgba(30, 30, 40, 250),
-        );
-        draw_rectangle_lines(panel_x, panel_y, panel_w, panel_h, 2.0, WHITE);
+    /// The event with pronoun tokens filled in for the party leader
+    fn event_for_leader(&self) -> Event {
+        let gender = self
+            .mission_context
+            .as_ref()
+            .and_then(|ctx| ctx.party_members.first())
+            .map(|leader| &leader.gender);
+        let mut event = self.event.clone();
+        event.description = fill_pronouns(&event.description, gender);
+        for choice in &mut event.choices {
+            choice.text = fill_pronouns(&choice.text, gender);
+        }
+        event
+    }
+}
 
-        // Title
-        draw_ui_text(
-            &self.event.title,
-            panel_x + 20.0,
-            panel_y + 40.0,
-            32.0,
-            YELLOW,
-        );
+/// Shared choice navigation for event screens; true when the selection is confirmed
+pub(super) fn update_choice_input(choice_count: usize, selected_choice: &mut usize) -> bool {
+    // Choice layout constants (must match draw)
+    let panel_x = 100.0;
+    let panel_y = 80.0;
+    let panel_w = screen_width() - 200.0;
+    let choices_y = panel_y + 200.0;
+    let choice_height = 45.0;
 
-        // Description
-        let desc_y = panel_y + 80.0;
-        // Simple word wrap
-        let max_width = panel_w - 40.0;
-        let words: Vec<&str> = self.event.description.split_whitespace().collect();
-        let mut line = String::new();
-        let mut y = desc_y;
-
-        for word in words {
-            let test_line = if line.is_empty() {
-                word.to_string()
-            } else {
-                format!("{} {}", line, word)
-            };
-
-            // Rough estimate: 8 pixels per character at size 18
-            if test_line.len() as f32 * 8.0 > max_width {
-                draw_ui_text(&line, panel_x + 20.0, y, 18.0, LIGHTGRAY);
-                y += 25.0;
-                line = word.to_string();
+    // Keyboard navigation
+    if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+        if *selected_choice > 0 {
+            *selected_choice -= 1;
+        }
+    }
+    if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+        if *selected_choice < choice_count.saturating_sub(1) {
+            *selected_choice += 1;
+        }
+    }
+
+    // Number keys and mouse clicks
+    for i in 0..choice_count.min(9) {
+        let key = match i {
+            0 => KeyCode::Key1,
+            1 => KeyCode::Key2,
+            2 => KeyCode::Key3,
+            3 => KeyCode::Key4,
+            4 => KeyCode::Key5,
+            _ => continue,
+        };
+
+        // Keyboard
+        if is_key_pressed(key) {
+            *selected_choice = i;
+        }
+
+        // Mouse click on choice
+        let choice_y = choices_y + 35.0 + (i as f32 * 50.0) - 15.0;
+        if crate::ui::was_clicked(panel_x + 20.0, choice_y, panel_w - 40.0, choice_height) {
+            if *selected_choice == i {
+                // Click on selected = confirm
+                return true;
             } else {
-                line = test_line;
+                *selected_choice = i;
             }
         }
-        if !line.is_empty() {
+    }
+
+    // Confirm choice with Enter
+    is_key_pressed(KeyCode::Enter)
+}
+
+/// Shared event panel: title, wrapped description, and the choice list
+pub(super) fn draw_event_panel(event: &Event, selected_choice: usize) {
+    // Darken background
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::from_rgba(0, 0, 0, 180),
+    );
+
+    // Event panel
+    let panel_x = 100.0;
+    let panel_y = 80.0;
+    let panel_w = screen_width() - 200.0;
+    let panel_h = screen_height() - 160.0;
+
+    draw_rectangle(
+        panel_x,
+        panel_y,
+        panel_w,
+        panel_h,
+        Color::from_rgba(30, 30, 40, 250),
+    );
+    draw_rectangle_lines(panel_x, panel_y, panel_w, panel_h, 2.0, WHITE);
+
+    // Title
+    draw_ui_text(&event.title, panel_x + 20.0, panel_y + 40.0, 32.0, YELLOW);
+
+    // Description
+    let desc_y = panel_y + 80.0;
+    // Simple word wrap
+    let max_width = panel_w - 40.0;
+    let words: Vec<&str> = event.description.split_whitespace().collect();
+    let mut line = String::new();
+    let mut y = desc_y;
+
+    for word in words {
+        let test_line = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", line, word)
+        };
+
+        // Rough estimate: 8 pixels per character at size 18
+        if test_line.len() as f32 * 8.0 > max_width {
             draw_ui_text(&line, panel_x + 20.0, y, 18.0, LIGHTGRAY);
+            y += 25.0;
+            line = word.to_string();
+        } else {
+            line = test_line;
         }
+    }
+    if !line.is_empty() {
+        draw_ui_text(&line, panel_x + 20.0, y, 18.0, LIGHTGRAY);
+    }
 
-        // Choices
-        let choices_y = panel_y + 200.0;
-        draw_ui_text("CHOOSE:", panel_x + 20.0, choices_y, 20.0, WHITE);
-
-        for (i, choice) in self.event.choices.iter().enumerate() {
-            let y = choices_y + 35.0 + (i as f32 * 50.0);
-            let choice_y = y - 15.0;
-            let choice_h = 45.0;
-            let is_selected = i == self.selected_choice;
-            let is_hovered =
-                crate::ui::is_mouse_over(panel_x + 20.0, choice_y, panel_w - 40.0, choice_h);
-
-            // Choice background with hover
-            let bg_color = if is_selected {
-                Color::from_rgba(60, 80, 60, 255)
-            } else if is_hovered {
-                Color::from_rgba(50, 55, 60, 255)
-            } else {
-                Color::from_rgba(40, 40, 50, 255)
-            };
-            draw_rectangle(panel_x + 20.0, choice_y, panel_w - 40.0, choice_h, bg_color);
-
-            if is_selected {
-                draw_rectangle_lines(
-                    panel_x + 20.0,
-                    choice_y,
-                    panel_w - 40.0,
-                    choice_h,
-                    2.0,
-                    GREEN,
-                );
-            } else if is_hovered {
-                draw_rectangle_lines(
-                    panel_x + 20.0,
-                    choice_y,
-                    panel_w - 40.0,
-                    choice_h,
-                    1.0,
-                    LIGHTGRAY,
-                );
-            }
+    // Choices
+    let choices_y = panel_y + 200.0;
+    draw_ui_text("CHOOSE:", panel_x + 20.0, choices_y, 20.0, WHITE);
+
+    for (i, choice) in event.choices.iter().enumerate() {
+        let y = choices_y + 35.0 + (i as f32 * 50.0);
+        let choice_y = y - 15.0;
+        let choice_h = 45.0;
+        let is_selected = i == selected_choice;
+        let is_hovered =
+            crate::ui::is_mouse_over(panel_x + 20.0, choice_y, panel_w - 40.0, choice_h);
 
-            // Choice text
-            let text_color = if is_selected { WHITE } else { GRAY };
-            draw_ui_text(
-                &format!("[{}] {}", i + 1, choice.text),
-                panel_x + 30.0,
-                y + 10.0,
-                18.0,
-                text_color,
+        // Choice background with hover
+        let bg_color = if is_selected {
+            Color::from_rgba(60, 80, 60, 255)
+        } else if is_hovered {
+            Color::from_rgba(50, 55, 60, 255)
+        } else {
+            Color::from_rgba(40, 40, 50, 255)
+        };
+        draw_rectangle(panel_x + 20.0, choice_y, panel_w - 40.0, choice_h, bg_color);
+
+        if is_selected {
+            draw_rectangle_lines(
+                panel_x + 20.0,
+                choice_y,
+                panel_w - 40.0,
+                choice_h,
+                2.0,
+                GREEN,
+            );
+        } else if is_hovered {
+            draw_rectangle_lines(
+                panel_x + 20.0,
+                choice_y,
+                panel_w - 40.0,
+                choice_h,
+                1.0,
+                LIGHTGRAY,
             );
         }
 
-        // Instructions
+        // Choice text
+        let text_color = if is_selected { WHITE } else { GRAY };
         draw_ui_text(
-            "Click choice to select, click again to confirm • Or use [↑/↓] and [ENTER]",
-            panel_x + 20.0,
-            panel_y + panel_h - 30.0,
-            16.0,
-            GREEN,
+            &format!("[{}] {}", i + 1, choice.text),
+            panel_x + 30.0,
+            y + 10.0,
+            18.0,
+            text_color,
         );
     }
+
+    // Instructions
+    draw_ui_text(
+        "Click choice to select, click again to confirm • Or use [↑/↓] and [ENTER]",
+        panel_x + 20.0,
+        panel_y + panel_h - 30.0,
+        16.0,
+        GREEN,
+    );
 }
@@ -0,0 +1,246 @@
+//! Foundry - spend supplies to forge an adventurer's learned cards into stronger versions
+
+use super::StateTransition;
+use crate::combat::{base_card_id, UPGRADE_SUFFIX};
+use crate::data::cards::CardData;
+use crate::kingdom::{KingdomState, Roster};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Supplies the Foundry charges to forge one card
+const UPGRADE_SUPPLY_COST: i32 = 15;
+
+/// State for upgrading learned cards at the Foundry
+#[derive(Default)]
+pub struct FoundryState {
+    selected_adventurer: usize,
+    selected_card: usize,
+    /// Card data, for names and descriptions of the ids in deck additions
+    cards: Vec<CardData>,
+    /// Forging feedback, drained by `Game` into toasts
+    pub notifications: Vec<String>,
+}
+
+impl FoundryState {
+    pub fn new() -> Self {
+        Self {
+            cards: CardData::load_all().unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        kingdom: &mut KingdomState,
+        roster: &mut Roster,
+    ) -> Option<StateTransition> {
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.select_adventurer(self.selected_adventurer.saturating_sub(1));
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            let last = roster.adventurers.len().saturating_sub(1);
+            self.select_adventurer((self.selected_adventurer + 1).min(last));
+        }
+
+        let learned = roster
+            .adventurers
+            .get(self.selected_adventurer)
+            .map_or(0, |adv| adv.deck_additions.len());
+        if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
+            self.selected_card = self.selected_card.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D) {
+            self.selected_card = (self.selected_card + 1).min(learned.saturating_sub(1));
+        }
+
+        if is_key_pressed(KeyCode::Enter) {
+            let message = match self.forge_selected(kingdom, roster) {
+                Ok(message) | Err(message) => message,
+            };
+            self.notifications.push(message);
+        }
+
+        if is_key_pressed(KeyCode::Escape) {
+            return Some(StateTransition::ToBase);
+        }
+
+        None
+    }
+
+    fn select_adventurer(&mut self, idx: usize) {
+        if idx != self.selected_adventurer {
+            self.selected_adventurer = idx;
+            self.selected_card = 0;
+        }
+    }
+
+    /// Display name for a deck-addition id, keeping the `+` of a forged card
+    fn card_name(&self, id: &str) -> String {
+        let base_id = base_card_id(id);
+        let name = self
+            .cards
+            .iter()
+            .find(|card| card.id == base_id)
+            .map_or(base_id, |card| card.name.as_str());
+        if base_id == id {
+            name.to_string()
+        } else {
+            format!("{}{}", name, UPGRADE_SUFFIX)
+        }
+    }
+
+    /// Forge the selected learned card, marking it upgraded; Err explains why not
+    fn forge_selected(
+        &self,
+        kingdom: &mut KingdomState,
+        roster: &mut Roster,
+    ) -> Result<String, String> {
+        let adv = roster
+            .adventurers
+            .get_mut(self.selected_adventurer)
+            .ok_or_else(|| "No adventurer selected".to_string())?;
+        let Some(id) = adv.deck_additions.get_mut(self.selected_card) else {
+            return Err(format!("{} has no learned cards to forge", adv.name));
+        };
+        if id.ends_with(UPGRADE_SUFFIX) {
+            return Err(format!("{} is already forged", self.card_name(id)));
+        }
+        if !kingdom.stats.spend_supplies(UPGRADE_SUPPLY_COST) {
+            return Err(format!(
+                "Need {} more supplies to forge a card",
+                UPGRADE_SUPPLY_COST - kingdom.stats.supplies
+            ));
+        }
+        id.push(UPGRADE_SUFFIX);
+        Ok(format!("{} forged {}", adv.name, self.card_name(id)))
+    }
+
+    pub fn draw(&self, kingdom: &KingdomState, roster: &Roster) {
+        draw_ui_text("FOUNDRY", 20.0, 40.0, 32.0, WHITE);
+        let can_afford = kingdom.stats.supplies >= UPGRADE_SUPPLY_COST;
+        draw_ui_text(
+            &format!(
+                "Supplies: {}   Forging a card costs {} supplies",
+                kingdom.stats.supplies, UPGRADE_SUPPLY_COST
+            ),
+            20.0,
+            70.0,
+            20.0,
+            if can_afford { YELLOW } else { RED },
+        );
+        draw_ui_text(
+            "Forged cards gain +3 damage, block, healing, stress relief and enemy stress, +1 draw and energy, and statuses last a turn longer.",
+            20.0,
+            98.0,
+            16.0,
+            LIGHTGRAY,
+        );
+
+        let start_y = 140.0;
+        for (i, adv) in roster.adventurers.iter().enumerate() {
+            let y = start_y + i as f32 * 30.0;
+            let is_selected = i == self.selected_adventurer;
+            if is_selected {
+                draw_rectangle(
+                    20.0,
+                    y - 20.0,
+                    300.0,
+                    28.0,
+                    Color::from_rgba(60, 70, 80, 255),
+                );
+            }
+            draw_ui_text(
+                &format!("{} ({:?})", adv.name, adv.class),
+                30.0,
+                y,
+                18.0,
+                if is_selected { WHITE } else { GRAY },
+            );
+        }
+
+        let Some(adv) = roster.adventurers.get(self.selected_adventurer) else {
+            return;
+        };
+        draw_ui_text("LEARNED CARDS", 360.0, start_y, 20.0, SKYBLUE);
+        if adv.deck_additions.is_empty() {
+            draw_ui_text(
+                "No learned cards yet - train them at the base first",
+                360.0,
+                start_y + 30.0,
+                18.0,
+                GRAY,
+            );
+        }
+        for (i, id) in adv.deck_additions.iter().enumerate() {
+            let y = start_y + 30.0 + i as f32 * 46.0;
+            let is_selected = i == self.selected_card;
+            let forged = id.ends_with(UPGRADE_SUFFIX);
+            if is_selected {
+                draw_rectangle_lines(352.0, y - 22.0, 480.0, 42.0, 2.0, GOLD);
+            }
+            draw_ui_text(
+                &self.card_name(id),
+                360.0,
+                y,
+                18.0,
+                if forged { GOLD } else { WHITE },
+            );
+            let base_id = base_card_id(id);
+            if let Some(card) = self.cards.iter().find(|card| card.id == base_id) {
+                draw_ui_text(&card.description, 360.0, y + 16.0, 14.0, LIGHTGRAY);
+            }
+            if forged {
+                draw_ui_text("Forged", 760.0, y, 16.0, GOLD);
+            }
+        }
+
+        draw_ui_text(
+            "[↑/↓] Adventurer  [←/→] Card  [ENTER] Forge  [ESC] Back  [F1] Help",
+            20.0,
+            screen_height() - 40.0,
+            20.0,
+            GREEN,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{Adventurer, AdventurerClass, Gender};
+
+    fn roster_with_learned(card_id: &str) -> Roster {
+        let mut adv = Adventurer::new("Brannoc", AdventurerClass::Soldier, Gender::Male);
+        adv.deck_additions.push(card_id.to_string());
+        let mut roster = Roster::new();
+        roster.adventurers.push(adv);
+        roster
+    }
+
+    #[test]
+    fn forging_spends_supplies_and_marks_the_card() {
+        let mut kingdom = KingdomState::default();
+        kingdom.stats.supplies = UPGRADE_SUPPLY_COST;
+        let mut roster = roster_with_learned("cleave");
+        let foundry = FoundryState::default();
+
+        assert!(foundry.forge_selected(&mut kingdom, &mut roster).is_ok());
+        assert_eq!(roster.adventurers[0].deck_additions, vec!["cleave+"]);
+        assert_eq!(kingdom.stats.supplies, 0);
+    }
+
+    #[test]
+    fn forged_cards_and_empty_stores_are_refused() {
+        let mut kingdom = KingdomState::default();
+        kingdom.stats.supplies = UPGRADE_SUPPLY_COST * 2;
+        let mut roster = roster_with_learned("cleave+");
+        let foundry = FoundryState::default();
+        assert!(foundry.forge_selected(&mut kingdom, &mut roster).is_err());
+
+        kingdom.stats.supplies = UPGRADE_SUPPLY_COST - 1;
+        let mut roster = roster_with_learned("cleave");
+        assert!(foundry.forge_selected(&mut kingdom, &mut roster).is_err());
+        assert_eq!(roster.adventurers[0].deck_additions, vec!["cleave"]);
+        assert_eq!(kingdom.stats.supplies, UPGRADE_SUPPLY_COST - 1);
+    }
+}
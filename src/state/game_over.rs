@@ -0,0 +1,154 @@
+//! Game over - the last of the roster has fallen; summarize the run and offer a fresh start
+
+use super::StateTransition;
+use crate::kingdom::{KingdomState, Roster};
+use crate::save::RunRecord;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Fallen heroes listed before the rest are summed up
+const MAX_FALLEN_SHOWN: usize = 10;
+
+/// Summary of a run that ended with no adventurers left
+pub struct GameOverState {
+    pub days_survived: u32,
+    /// Expeditions the fallen brought home between them
+    pub missions_won: u32,
+    /// One line per fallen hero, earliest first
+    pub fallen: Vec<String>,
+    /// Today's best daily challenge result, shown when this run was one
+    pub daily_best: Option<String>,
+}
+
+impl GameOverState {
+    pub fn new(kingdom: &KingdomState, roster: &Roster) -> Self {
+        Self {
+            days_survived: kingdom.day,
+            missions_won: roster.graveyard.iter().map(|a| a.missions_completed).sum(),
+            fallen: roster
+                .graveyard
+                .iter()
+                .map(|a| {
+                    format!(
+                        "{} - {:?}, level {}, {} kills",
+                        a.title(),
+                        a.class,
+                        a.level,
+                        a.kills
+                    )
+                })
+                .collect(),
+            daily_best: None,
+        }
+    }
+
+    /// This run's history entry, the first time it is asked for. The kingdom is marked
+    /// recorded, so reloading a save made after the run ended never records it twice
+    pub fn take_run_record(
+        &self,
+        kingdom: &mut KingdomState,
+        seed: Option<u64>,
+    ) -> Option<RunRecord> {
+        if kingdom.run_recorded {
+            return None;
+        }
+        kingdom.run_recorded = true;
+        Some(RunRecord {
+            seed,
+            difficulty: kingdom.difficulty,
+            days_survived: self.days_survived,
+            missions_completed: self.missions_won,
+            heroes_lost: self.fallen.len() as u32,
+        })
+    }
+
+    /// Enter founds a new kingdom; D starts today's daily challenge; L lists past runs
+    pub fn update(&mut self) -> Option<StateTransition> {
+        if is_key_pressed(KeyCode::Enter) {
+            return Some(StateTransition::ToNewGame);
+        }
+        if is_key_pressed(KeyCode::D) {
+            return Some(StateTransition::ToDailyChallenge);
+        }
+        if is_key_pressed(KeyCode::L) {
+            return Some(StateTransition::ToHistory);
+        }
+        None
+    }
+
+    pub fn draw(&self) {
+        draw_ui_text("THE LAST STAND HAS FALLEN", 20.0, 60.0, 36.0, RED);
+        draw_ui_text(
+            "No adventurers remain to defend the frontier.",
+            20.0,
+            92.0,
+            18.0,
+            LIGHTGRAY,
+        );
+
+        draw_ui_text(
+            &format!("Days survived: {}", self.days_survived),
+            20.0,
+            140.0,
+            22.0,
+            WHITE,
+        );
+        draw_ui_text(
+            &format!("Expeditions won: {}", self.missions_won),
+            20.0,
+            170.0,
+            22.0,
+            WHITE,
+        );
+
+        draw_ui_text("Fallen heroes", 20.0, 220.0, 22.0, GOLD);
+        let mut y = 250.0;
+        for line in self.fallen.iter().take(MAX_FALLEN_SHOWN) {
+            draw_ui_text(line, 40.0, y, 18.0, GRAY);
+            y += 24.0;
+        }
+        if self.fallen.len() > MAX_FALLEN_SHOWN {
+            draw_ui_text(
+                &format!("...and {} more", self.fallen.len() - MAX_FALLEN_SHOWN),
+                40.0,
+                y,
+                18.0,
+                GRAY,
+            );
+        }
+
+        if let Some(best) = &self.daily_best {
+            draw_ui_text(best, 20.0, screen_height() - 76.0, 18.0, GOLD);
+        }
+        draw_ui_text(
+            "[ENTER] Found a New Kingdom    [D] Daily Challenge    [L] Run History",
+            20.0,
+            screen_height() - 40.0,
+            20.0,
+            GREEN,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::{RunHistory, SaveData};
+
+    #[test]
+    fn a_reloaded_save_does_not_record_the_run_again() {
+        let mut kingdom = KingdomState::default();
+        let roster = Roster::new();
+        let run = GameOverState::new(&kingdom, &roster);
+        let mut history = RunHistory::default();
+        history.runs.extend(run.take_run_record(&mut kingdom, None));
+
+        // The autosave written as the run ends, read back at the next start
+        let saved = SaveData::new(kingdom, roster).export_string().unwrap();
+        let mut reloaded = SaveData::import_string(&saved).unwrap();
+        history
+            .runs
+            .extend(run.take_run_record(&mut reloaded.kingdom, None));
+        assert_eq!(history.runs.len(), 1);
+    }
+}
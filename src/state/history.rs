@@ -0,0 +1,170 @@
+//! Run history - past runs listed best first by the chosen measure
+
+use super::StateTransition;
+use crate::save::{RunHistory, RunRecord};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Rows drawn before the rest are summed up
+const MAX_RUNS_SHOWN: usize = 18;
+const COLUMNS_X: [f32; 5] = [20.0, 200.0, 340.0, 500.0, 660.0];
+
+/// Measure the history is ordered by
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum RunSort {
+    #[default]
+    Recent,
+    DaysSurvived,
+    MissionsCompleted,
+    /// Fewest losses first
+    HeroesLost,
+}
+
+impl RunSort {
+    fn next(self) -> Self {
+        match self {
+            RunSort::Recent => RunSort::DaysSurvived,
+            RunSort::DaysSurvived => RunSort::MissionsCompleted,
+            RunSort::MissionsCompleted => RunSort::HeroesLost,
+            RunSort::HeroesLost => RunSort::Recent,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            RunSort::Recent => "Most recent",
+            RunSort::DaysSurvived => "Days survived",
+            RunSort::MissionsCompleted => "Missions completed",
+            RunSort::HeroesLost => "Fewest heroes lost",
+        }
+    }
+
+    /// Order runs (given oldest first) for display
+    fn apply(self, runs: &mut [RunRecord]) {
+        match self {
+            RunSort::Recent => runs.reverse(),
+            RunSort::DaysSurvived => runs.sort_by(|a, b| b.days_survived.cmp(&a.days_survived)),
+            RunSort::MissionsCompleted => {
+                runs.sort_by(|a, b| b.missions_completed.cmp(&a.missions_completed))
+            }
+            RunSort::HeroesLost => runs.sort_by_key(|r| r.heroes_lost),
+        }
+    }
+}
+
+/// Finished runs read from disk, oldest first, plus the current ordering
+pub struct HistoryState {
+    runs: Vec<RunRecord>,
+    sort: RunSort,
+}
+
+impl HistoryState {
+    pub fn load() -> Self {
+        Self {
+            runs: RunHistory::load().runs,
+            sort: RunSort::default(),
+        }
+    }
+
+    /// Tab changes the ordering; Esc returns to the base
+    pub fn update(&mut self) -> Option<StateTransition> {
+        if is_key_pressed(KeyCode::Tab) {
+            self.sort = self.sort.next();
+        }
+        if is_key_pressed(KeyCode::Escape) {
+            return Some(StateTransition::ToBase);
+        }
+        None
+    }
+
+    fn sorted_runs(&self) -> Vec<RunRecord> {
+        let mut runs = self.runs.clone();
+        self.sort.apply(&mut runs);
+        runs
+    }
+
+    pub fn draw(&self) {
+        draw_ui_text("RUN HISTORY", 20.0, 40.0, 32.0, WHITE);
+        draw_ui_text(
+            &format!("Sorted by: {}  [TAB] change", self.sort.label()),
+            20.0,
+            70.0,
+            18.0,
+            GOLD,
+        );
+
+        if self.runs.is_empty() {
+            draw_ui_text("No runs have ended yet.", 20.0, 120.0, 20.0, LIGHTGRAY);
+        } else {
+            let headers = [
+                "Run",
+                "Difficulty",
+                "Days survived",
+                "Missions",
+                "Heroes lost",
+            ];
+            for (header, x) in headers.iter().zip(COLUMNS_X) {
+                draw_ui_text(header, x, 110.0, 18.0, GRAY);
+            }
+            let mut y = 138.0;
+            for run in self.sorted_runs().iter().take(MAX_RUNS_SHOWN) {
+                let kind = match run.seed {
+                    Some(seed) => format!("Daily #{:x}", seed % 0x10000),
+                    None => "Campaign".to_string(),
+                };
+                let cells = [
+                    kind,
+                    run.difficulty.label().to_string(),
+                    run.days_survived.to_string(),
+                    run.missions_completed.to_string(),
+                    run.heroes_lost.to_string(),
+                ];
+                for (cell, x) in cells.iter().zip(COLUMNS_X) {
+                    draw_ui_text(cell, x, y, 18.0, WHITE);
+                }
+                y += 24.0;
+            }
+            if self.runs.len() > MAX_RUNS_SHOWN {
+                draw_ui_text(
+                    &format!("...and {} more", self.runs.len() - MAX_RUNS_SHOWN),
+                    20.0,
+                    y,
+                    18.0,
+                    GRAY,
+                );
+            }
+        }
+
+        draw_ui_text("[ESC] Back", 20.0, screen_height() - 40.0, 20.0, GREEN);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::Difficulty;
+
+    fn run(days_survived: u32, missions_completed: u32, heroes_lost: u32) -> RunRecord {
+        RunRecord {
+            seed: None,
+            difficulty: Difficulty::Normal,
+            days_survived,
+            missions_completed,
+            heroes_lost,
+        }
+    }
+
+    #[test]
+    fn each_measure_puts_the_best_run_first() {
+        let runs = vec![run(5, 9, 4), run(30, 2, 6), run(12, 4, 1)];
+        let first = |sort: RunSort| {
+            let mut sorted = runs.clone();
+            sort.apply(&mut sorted);
+            sorted[0].clone()
+        };
+        assert_eq!(first(RunSort::Recent), run(12, 4, 1));
+        assert_eq!(first(RunSort::DaysSurvived), run(30, 2, 6));
+        assert_eq!(first(RunSort::MissionsCompleted), run(5, 9, 4));
+        assert_eq!(first(RunSort::HeroesLost), run(12, 4, 1));
+    }
+}
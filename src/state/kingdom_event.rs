@@ -0,0 +1,74 @@
+//! Kingdom event state - a daily decision at the base
+
+use super::event::{draw_event_panel, update_choice_input};
+use super::StateTransition;
+use crate::kingdom::{KingdomState, Roster};
+use crate::missions::events::{Event, EventOutcome};
+use macroquad::prelude::*;
+use std::collections::HashMap;
+
+/// State for a kingdom-scoped event between expeditions
+pub struct KingdomEventState {
+    pub event: Event,
+    pub selected_choice: usize,
+    /// Adventurer the event concerns, who takes any stress outcome
+    pub adventurer_id: Option<String>,
+}
+
+impl KingdomEventState {
+    /// Roll whether a kingdom event greets the new day
+    pub fn roll(kingdom: &KingdomState, roster: &Roster) -> Option<Self> {
+        let (event, adventurer_id) = crate::kingdom::events::roll_daily_event(kingdom, roster)?;
+        Some(Self {
+            event,
+            selected_choice: 0,
+            adventurer_id,
+        })
+    }
+
+    pub fn update(
+        &mut self,
+        kingdom: &mut KingdomState,
+        roster: &mut Roster,
+    ) -> Option<StateTransition> {
+        if !update_choice_input(self.event.choices.len(), &mut self.selected_choice) {
+            return None;
+        }
+
+        let choice = self.event.choices.get(self.selected_choice)?;
+        for outcome in &choice.outcomes {
+            match outcome {
+                EventOutcome::Gold(amt) => kingdom.stats.add_gold(*amt),
+                EventOutcome::Supplies(amt) => kingdom.stats.add_supplies(*amt),
+                EventOutcome::Morale(amt) => kingdom.stats.add_morale(*amt),
+                EventOutcome::Knowledge(amt) => kingdom.stats.add_knowledge(*amt),
+                EventOutcome::Stress(amt) => {
+                    let adv = self
+                        .adventurer_id
+                        .as_deref()
+                        .and_then(|id| roster.get_mut(id));
+                    if let Some(adv) = adv {
+                        if *amt >= 0 {
+                            adv.apply_stress_gain(*amt);
+                        } else {
+                            adv.reduce_stress(-amt);
+                        }
+                    }
+                }
+                // Trail-only outcomes do nothing at the base
+                EventOutcome::Heal(_)
+                | EventOutcome::Combat(_)
+                | EventOutcome::RevealTrait
+                | EventOutcome::SkipNode
+                | EventOutcome::Nothing => {}
+            }
+        }
+
+        kingdom.last_event = Some(format!("{}: {}", self.event.title, choice.text));
+        Some(StateTransition::ToBase)
+    }
+
+    pub fn draw(&self, _textures: &HashMap<String, Texture2D>) {
+        draw_event_panel(&self.event, self.selected_choice);
+    }
+}
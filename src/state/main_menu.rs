@@ -0,0 +1,90 @@
+//! Main menu - shown once loading finishes: carry on the campaign or pick another mode
+
+use super::StateTransition;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Menu entries: label, shortcut key, and a line on what it opens
+const OPTIONS: [(&str, KeyCode, &str); 4] = [
+    ("Continue", KeyCode::C, "Return to your kingdom"),
+    (
+        "Daily Challenge",
+        KeyCode::D,
+        "Today's seeded run - the same kingdom for everyone, scored on a local leaderboard",
+    ),
+    (
+        "Run History",
+        KeyCode::L,
+        "Past runs, sorted by a chosen measure",
+    ),
+    (
+        "Settings",
+        KeyCode::O,
+        "Accessibility, difficulty, and save options",
+    ),
+];
+
+#[derive(Default)]
+pub struct MainMenuState {
+    selected: usize,
+}
+
+impl MainMenuState {
+    pub fn update(&mut self) -> Option<StateTransition> {
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            self.selected = (self.selected + 1).min(OPTIONS.len() - 1);
+        }
+        if let Some(i) = OPTIONS.iter().position(|(_, key, _)| is_key_pressed(*key)) {
+            return Some(Self::open(i));
+        }
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+            return Some(Self::open(self.selected));
+        }
+        None
+    }
+
+    fn open(option: usize) -> StateTransition {
+        match option {
+            1 => StateTransition::ToDailyChallenge,
+            2 => StateTransition::ToHistory,
+            3 => StateTransition::ToSettings,
+            _ => StateTransition::ToContinue,
+        }
+    }
+
+    pub fn draw(&self) {
+        draw_ui_text("FRONTIER KINGDOM", 20.0, 60.0, 40.0, GOLD);
+        for (i, (label, key, blurb)) in OPTIONS.iter().enumerate() {
+            let y = 140.0 + i as f32 * 56.0;
+            let selected = i == self.selected;
+            if selected {
+                draw_rectangle(
+                    14.0,
+                    y - 26.0,
+                    760.0,
+                    50.0,
+                    Color::from_rgba(60, 70, 80, 255),
+                );
+            }
+            draw_ui_text(
+                &format!("[{:?}] {}", key, label),
+                24.0,
+                y,
+                24.0,
+                if selected { WHITE } else { GRAY },
+            );
+            draw_ui_text(blurb, 24.0, y + 18.0, 14.0, LIGHTGRAY);
+        }
+
+        draw_ui_text(
+            "[↑/↓] Select  [ENTER] Open  [F1] Help",
+            20.0,
+            screen_height() - 40.0,
+            20.0,
+            GREEN,
+        );
+    }
+}
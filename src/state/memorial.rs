@@ -0,0 +1,122 @@
+//! Memorial - the fallen, newest first, with what they achieved before they died
+
+use super::StateTransition;
+use crate::kingdom::{Adventurer, Roster};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Names drawn at once; Up / Down scrolls through the rest
+const MAX_FALLEN_SHOWN: usize = 16;
+const COLUMNS_X: [f32; 5] = [20.0, 320.0, 460.0, 560.0, 680.0];
+
+/// Scroll position through the graveyard
+#[derive(Default)]
+pub struct MemorialState {
+    first_shown: usize,
+}
+
+impl MemorialState {
+    /// Up / Down scroll; Esc returns to the base
+    pub fn update(&mut self, roster: &Roster) -> Option<StateTransition> {
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.first_shown = self.first_shown.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            let last_start = roster.graveyard.len().saturating_sub(MAX_FALLEN_SHOWN);
+            self.first_shown = (self.first_shown + 1).min(last_start);
+        }
+        if is_key_pressed(KeyCode::Escape) {
+            return Some(StateTransition::ToBase);
+        }
+        None
+    }
+
+    pub fn draw(&self, roster: &Roster) {
+        draw_ui_text("MEMORIAL", 20.0, 40.0, 32.0, WHITE);
+        let fallen = fallen_newest_first(roster);
+        let kills: u32 = fallen.iter().map(|adv| adv.kills).sum();
+        draw_ui_text(
+            &format!("{} fallen, {} foes slain between them", fallen.len(), kills),
+            20.0,
+            70.0,
+            18.0,
+            GOLD,
+        );
+
+        if fallen.is_empty() {
+            draw_ui_text(
+                "No names carved into the stone yet.",
+                20.0,
+                120.0,
+                20.0,
+                LIGHTGRAY,
+            );
+        } else {
+            let headers = ["Name", "Class", "Level", "Missions", "Kills"];
+            for (header, x) in headers.iter().zip(COLUMNS_X) {
+                draw_ui_text(header, x, 110.0, 18.0, GRAY);
+            }
+            let mut y = 138.0;
+            for adv in fallen.iter().skip(self.first_shown).take(MAX_FALLEN_SHOWN) {
+                let cells = [
+                    adv.title(),
+                    format!("{:?}", adv.class),
+                    adv.level.to_string(),
+                    adv.missions_completed.to_string(),
+                    adv.kills.to_string(),
+                ];
+                for (cell, x) in cells.iter().zip(COLUMNS_X) {
+                    draw_ui_text(cell, x, y, 18.0, WHITE);
+                }
+                y += 24.0;
+            }
+            let hidden = fallen
+                .len()
+                .saturating_sub(self.first_shown + MAX_FALLEN_SHOWN);
+            if hidden > 0 {
+                draw_ui_text(&format!("...and {} more", hidden), 20.0, y, 18.0, GRAY);
+            }
+        }
+
+        draw_ui_text(
+            "[↑/↓] Scroll  [ESC] Back",
+            20.0,
+            screen_height() - 40.0,
+            20.0,
+            GREEN,
+        );
+    }
+}
+
+/// The graveyard fills oldest first; the memorial leads with the most recent loss
+fn fallen_newest_first(roster: &Roster) -> Vec<&Adventurer> {
+    roster.graveyard.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{AdventurerClass, Gender};
+
+    #[test]
+    fn most_recent_death_is_listed_first() {
+        let mut roster = Roster::new();
+        for name in ["Aldric", "Brenna"] {
+            roster.adventurers.push(Adventurer::new(
+                name,
+                AdventurerClass::Scout,
+                Gender::Female,
+            ));
+        }
+        let first = roster.adventurers[0].id.clone();
+        let second = roster.adventurers[1].id.clone();
+        roster.record_death(&first);
+        roster.record_death(&second);
+
+        let names: Vec<&str> = fallen_newest_first(&roster)
+            .iter()
+            .map(|adv| adv.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Brenna", "Aldric"]);
+    }
+}
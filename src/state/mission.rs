@@ -2,11 +2,43 @@
 
 use super::combat::{CombatState, MissionContext};
 use super::{ResultState, StateTransition};
-use crate::kingdom::PartyMemberState;
+use crate::kingdom::{KingdomState, PartyMemberState};
 use crate::missions::{MapNode, Mission, NodeType};
+use crate::save::Settings;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
 
+mod view;
+
+/// Kingdom Knowledge needed to scout nodes beyond the next layer
+const ROUTE_SCOUTING_KNOWLEDGE: i32 = 25;
+
+/// Unzoomed map layout; zoom scales around the first layer's left edge and the map top
+const MAP_ORIGIN_X: f32 = 350.0;
+const MAP_ORIGIN_Y: f32 = 180.0;
+const MAP_NODE_SIZE: f32 = 48.0;
+const MAP_LAYER_GAP: f32 = 130.0;
+const MAP_NODE_GAP: f32 = 78.0;
+/// Zoom limits and the change per mouse wheel notch
+const MIN_MAP_ZOOM: f32 = 0.5;
+const MAX_MAP_ZOOM: f32 = 2.0;
+const MAP_ZOOM_STEP: f32 = 0.1;
+/// Header strip with one dot per map layer
+const PROGRESS_RIGHT_PAD: f32 = 24.0;
+const PROGRESS_MAX_W: f32 = 380.0;
+const PROGRESS_Y: f32 = 41.0;
+const PROGRESS_DOT_SPACING: f32 = 26.0;
+const PROGRESS_DOT_RADIUS: f32 = 7.0;
+
+/// One layer of the expedition as shown in the header progress strip
+pub struct LayerProgress {
+    pub layer: usize,
+    /// Node the party passed through in this layer, if it has been reached
+    pub visited_node: Option<usize>,
+    /// The party is standing in this layer
+    pub current: bool,
+}
+
 /// Active mission/expedition state with branching paths
 pub struct MissionState {
     pub mission: Mission,
@@ -22,6 +54,14 @@ pub struct MissionState {
     pub available_paths: Vec<usize>,
     /// Selected path index when at a fork
     pub selected_path: usize,
+    /// Nodes cut off by earlier fork choices; they can never be reached again
+    pub pruned: Vec<usize>,
+    /// Screen offset of the map, dragged with the middle mouse button
+    pub map_pan: Vec2,
+    /// Map scale, changed with the mouse wheel
+    pub map_zoom: f32,
+    /// Last cursor position while a middle-mouse drag is in progress
+    map_drag: Option<Vec2>,
 }
 
 impl Default for MissionState {
@@ -36,6 +76,10 @@ impl Default for MissionState {
             visited_nodes: vec![0],
             available_paths: vec![],
             selected_path: 0,
+            pruned: vec![],
+            map_pan: Vec2::ZERO,
+            map_zoom: 1.0,
+            map_drag: None,
         }
     }
 }
@@ -52,9 +96,14 @@ impl MissionState {
         self.party_members.first_mut()
     }
 
-    /// Create from a Mission object with a full party
-    pub fn from_mission_with_party(mission: Mission, party_members: Vec<PartyMemberState>) -> Self {
-        let map_nodes = mission.generate_branching_map();
+    /// Create from a Mission object with a full party. Pass the existing map when re-entering
+    /// an expedition (e.g. after combat); a fresh map is only generated when launching
+    pub fn from_mission_with_party(
+        mission: Mission,
+        party_members: Vec<PartyMemberState>,
+        map_nodes: Option<Vec<MapNode>>,
+    ) -> Self {
+        let map_nodes = map_nodes.unwrap_or_else(|| mission.generate_branching_map());
         Self {
             mission,
             current_node_id: 0,
@@ -63,27 +112,79 @@ impl MissionState {
             visited_nodes: vec![0],
             available_paths: vec![],
             selected_path: 0,
+            pruned: vec![],
+            map_pan: Vec2::ZERO,
+            map_zoom: 1.0,
+            map_drag: None,
+        }
+    }
+
+    /// The expedition as it stands, for an in-progress save
+    pub fn snapshot(&self) -> MissionContext {
+        MissionContext {
+            mission: self.mission.clone(),
+            current_node: self.current_node_id,
+            party_members: self.party_members.clone(),
+            map_nodes: self.map_nodes.clone(),
+            visited_nodes: self.visited_nodes.clone(),
+            homeward_ambush: false,
         }
     }
 
+    /// Resume an expedition saved with `snapshot`
+    pub fn from_snapshot(ctx: MissionContext) -> Self {
+        Self::from_mission_with_party(ctx.mission, ctx.party_members, Some(ctx.map_nodes))
+            .with_node(ctx.current_node)
+            .with_visited(ctx.visited_nodes)
+    }
+
     /// Set the current node (used when returning from combat)
     pub fn with_node(mut self, node: usize) -> Self {
         self.current_node_id = node;
-        self
-    }
-
-    /// Set map nodes (used when returning from combat to preserve the generated layout)
-    pub fn with_map_nodes(mut self, map_nodes: Vec<MapNode>) -> Self {
-        self.map_nodes = map_nodes;
+        self.update_pruned();
         self
     }
 
     /// Set visited nodes (used when returning from combat)
     pub fn with_visited(mut self, visited: Vec<usize>) -> Self {
         self.visited_nodes = visited;
+        self.update_pruned();
         self
     }
 
+    /// Step onto a node, committing to its branch
+    fn move_to_node(&mut self, node_id: usize) {
+        self.current_node_id = node_id;
+        self.visited_nodes.push(node_id);
+        self.available_paths.clear();
+        self.selected_path = 0;
+        self.update_pruned();
+    }
+
+    /// Recompute which unvisited nodes are no longer reachable from the current node
+    fn update_pruned(&mut self) {
+        let mut reachable = vec![self.current_node_id];
+        let mut frontier = vec![self.current_node_id];
+        while let Some(id) = frontier.pop() {
+            let Some(node) = self.map_nodes.iter().find(|n| n.id == id) else {
+                continue;
+            };
+            for &next in &node.connections {
+                if !reachable.contains(&next) {
+                    reachable.push(next);
+                    frontier.push(next);
+                }
+            }
+        }
+
+        self.pruned = self
+            .map_nodes
+            .iter()
+            .map(|n| n.id)
+            .filter(|id| !reachable.contains(id) && !self.visited_nodes.contains(id))
+            .collect();
+    }
+
     /// Get the current map node
     fn current_node(&self) -> Option<&MapNode> {
         self.map_nodes.iter().find(|n| n.id == self.current_node_id)
@@ -98,28 +199,107 @@ impl MissionState {
         }
     }
 
-    /// Get node screen position for mouse hit testing (x, y, size)
+    /// Get node screen position for mouse hit testing (x, y, size), after pan and zoom
     fn get_node_screen_pos(&self, node_id: usize) -> Option<(f32, f32, f32)> {
         let node = self.map_nodes.iter().find(|n| n.id == node_id)?;
 
-        let map_y = 180.0;
-        let node_size = 48.0;
-        let layer_gap = 130.0;
-        let node_gap = 78.0;
-        let start_x = 350.0;
-
-        let node_x = start_x + (node.layer as f32 * layer_gap);
-
-        let nodes_in_layer: Vec<_> = self
+        let node_x = MAP_ORIGIN_X + (node.layer as f32 * MAP_LAYER_GAP);
+        let layer_len = self
             .map_nodes
             .iter()
             .filter(|n| n.layer == node.layer)
-            .collect();
-        let layer_height = (nodes_in_layer.len() as f32 - 1.0) * node_gap;
-        let layer_start_y = map_y + (260.0 - layer_height) / 2.0;
-        let node_y = layer_start_y + (node.position as f32 * node_gap);
+            .count();
+        let layer_height = (layer_len as f32 - 1.0) * MAP_NODE_GAP;
+        let layer_start_y = MAP_ORIGIN_Y + (260.0 - layer_height) / 2.0;
+        let node_y = layer_start_y + (node.position as f32 * MAP_NODE_GAP);
+
+        let zoom = self.map_zoom;
+        Some((
+            MAP_ORIGIN_X + (node_x - MAP_ORIGIN_X) * zoom + self.map_pan.x,
+            MAP_ORIGIN_Y + (node_y - MAP_ORIGIN_Y) * zoom + self.map_pan.y,
+            MAP_NODE_SIZE * zoom,
+        ))
+    }
 
-        Some((node_x, node_y, node_size))
+    /// Progress through every map layer, for the condensed header strip
+    pub fn layer_progress(&self) -> Vec<LayerProgress> {
+        let max_layer = self.map_nodes.iter().map(|n| n.layer).max().unwrap_or(0);
+        let current_layer = self.current_node().map(|n| n.layer);
+        (0..=max_layer)
+            .map(|layer| LayerProgress {
+                layer,
+                visited_node: self
+                    .map_nodes
+                    .iter()
+                    .filter(|n| n.layer == layer)
+                    .map(|n| n.id)
+                    .find(|id| self.visited_nodes.contains(id)),
+                current: current_layer == Some(layer),
+            })
+            .collect()
+    }
+
+    /// Centre of the progress dot for `layer`, right-aligned in the header
+    fn progress_dot_pos(layer: usize, layer_count: usize) -> Vec2 {
+        let spacing = PROGRESS_DOT_SPACING.min(PROGRESS_MAX_W / layer_count.max(1) as f32);
+        let strip_w = spacing * layer_count.saturating_sub(1) as f32;
+        let start_x = screen_width() - PROGRESS_RIGHT_PAD - PROGRESS_DOT_RADIUS - strip_w;
+        vec2(start_x + layer as f32 * spacing, PROGRESS_Y)
+    }
+
+    /// Clicking a visited dot scrolls the map to the node taken in that layer
+    fn update_progress_strip(&mut self) {
+        if !is_mouse_button_pressed(MouseButton::Left) {
+            return;
+        }
+        let cursor = Vec2::from(mouse_position());
+        let progress = self.layer_progress();
+        let clicked = progress.iter().find_map(|dot| {
+            let pos = Self::progress_dot_pos(dot.layer, progress.len());
+            let hit = cursor.distance(pos) <= PROGRESS_DOT_RADIUS + 3.0;
+            dot.visited_node.filter(|_| hit)
+        });
+        if let Some(node_id) = clicked {
+            self.focus_node(node_id);
+        }
+    }
+
+    /// Pan horizontally so `node_id` sits in the middle of the map area
+    fn focus_node(&mut self, node_id: usize) {
+        let Some((x, _, size)) = self.get_node_screen_pos(node_id) else {
+            return;
+        };
+        let map_center_x = (MAP_ORIGIN_X + screen_width()) / 2.0;
+        self.map_pan.x += map_center_x - (x + size / 2.0);
+    }
+
+    /// Pan with a middle-mouse drag, zoom with the wheel around the cursor, reset with Home
+    fn update_map_view(&mut self) {
+        let cursor = Vec2::from(mouse_position());
+        if is_mouse_button_down(MouseButton::Middle) {
+            if let Some(last) = self.map_drag {
+                self.map_pan += cursor - last;
+            }
+            self.map_drag = Some(cursor);
+        } else {
+            self.map_drag = None;
+        }
+
+        let wheel = mouse_wheel().1;
+        if wheel != 0.0 {
+            let zoom = (self.map_zoom * (1.0 + MAP_ZOOM_STEP * wheel.signum()))
+                .clamp(MIN_MAP_ZOOM, MAX_MAP_ZOOM);
+            // Keep the map point under the cursor in place
+            let origin = vec2(MAP_ORIGIN_X, MAP_ORIGIN_Y);
+            let anchored = cursor - origin - self.map_pan;
+            self.map_pan = cursor - origin - anchored * (zoom / self.map_zoom);
+            self.map_zoom = zoom;
+        }
+
+        if is_key_pressed(KeyCode::Home) {
+            self.map_pan = Vec2::ZERO;
+            self.map_zoom = 1.0;
+        }
     }
 
     /// Process the current node's encounter
@@ -127,17 +307,11 @@ impl MissionState {
         let node = self.current_node()?.clone(); // Clone to avoid borrow issues
 
         match &node.node_type {
-            NodeType::Combat | NodeType::Boss => {
+            NodeType::Combat | NodeType::Elite | NodeType::Boss => {
                 // Create combat with the full party
-                let context = MissionContext {
-                    mission: self.mission.clone(),
-                    current_node: self.current_node_id,
-                    party_members: self.party_members.clone(),
-                    map_nodes: self.map_nodes.clone(),
-                    visited_nodes: self.visited_nodes.clone(),
-                };
-                let combat = CombatState::for_mission(context);
-                return Some(StateTransition::ToCombat(combat));
+                let combat =
+                    CombatState::for_mission(self.take_combat_context(false), &Settings::load());
+                return Some(StateTransition::ToCombat(Box::new(combat)));
             }
             NodeType::Event => {
                 if let Some(event) = crate::missions::events::random_event(
@@ -160,17 +334,54 @@ impl MissionState {
             }
             NodeType::Rest => {
                 // Rest nodes heal the party slightly and reduce stress
+                let balance = crate::data::balance();
                 for member in &mut self.party_members {
-                    let heal = (member.max_hp as f32 * 0.1) as i32;
+                    let heal = member.max_hp * balance.rest_heal_percent / 100;
                     member.hp = (member.hp + heal).min(member.max_hp);
-                    member.stress = (member.stress - 5).max(0);
+                    member.stress = (member.stress - balance.rest_stress_relief).max(0);
                 }
             }
         }
         None
     }
 
-    pub fn update(&mut self) -> Option<StateTransition> {
+    /// Hand this expedition to a fight. The map, party, and visit history are moved rather
+    /// than cloned, since this state is dropped by the transition and rebuilt from the
+    /// context afterwards; only the small `Mission` record is copied
+    fn take_combat_context(&mut self, homeward_ambush: bool) -> MissionContext {
+        MissionContext {
+            mission: self.mission.clone(),
+            current_node: self.current_node_id,
+            party_members: std::mem::take(&mut self.party_members),
+            map_nodes: std::mem::take(&mut self.map_nodes),
+            visited_nodes: std::mem::take(&mut self.visited_nodes),
+            homeward_ambush,
+        }
+    }
+
+    /// Head home victorious; a poorly guarded kingdom may see the party ambushed on the road
+    fn return_home(&mut self, kingdom: &KingdomState) -> StateTransition {
+        let chance = crate::kingdom::events::ambush_chance(kingdom.stats.security);
+        if macroquad_toolkit::rng::chance(chance) {
+            let combat =
+                CombatState::for_mission(self.take_combat_context(true), &Settings::load());
+            return StateTransition::ToCombat(Box::new(combat));
+        }
+        let shortfall = self.mission.victory_shortfall(
+            &self.map_nodes,
+            &self.visited_nodes,
+            &self.party_members,
+        );
+        StateTransition::ToResults(
+            ResultState::victory_for_mission(&self.mission, &self.party_members)
+                .with_shortfall(shortfall),
+        )
+    }
+
+    pub fn update(&mut self, kingdom: &mut KingdomState) -> Option<StateTransition> {
+        self.update_map_view();
+        self.update_progress_strip();
+
         // Check if we have path options to choose from
         if !self.available_paths.is_empty() {
             // Path selection with arrow keys
@@ -206,21 +417,14 @@ impl MissionState {
                         if mx >= nx && mx <= nx + size && my >= ny && my <= ny + size {
                             if self.selected_path == idx {
                                 // Already selected - confirm
-                                self.current_node_id = node_id;
-                                self.visited_nodes.push(node_id);
-                                self.available_paths.clear();
-                                self.selected_path = 0;
+                                self.move_to_node(node_id);
 
                                 if let Some(transition) = self.process_current_node() {
                                     return Some(transition);
                                 }
 
                                 if self.is_complete() {
-                                    let results = ResultState::victory_for_mission(
-                                        &self.mission,
-                                        &self.party_members,
-                                    );
-                                    return Some(StateTransition::ToResults(results));
+                                    return Some(self.return_home(kingdom));
                                 }
                                 break;
                             } else {
@@ -236,10 +440,7 @@ impl MissionState {
             // Confirm path with Space or Enter
             if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
                 if let Some(&next_node_id) = self.available_paths.get(self.selected_path) {
-                    self.current_node_id = next_node_id;
-                    self.visited_nodes.push(next_node_id);
-                    self.available_paths.clear();
-                    self.selected_path = 0;
+                    self.move_to_node(next_node_id);
 
                     // Process the new node
                     if let Some(transition) = self.process_current_node() {
@@ -248,9 +449,7 @@ impl MissionState {
 
                     // If mission complete after this node
                     if self.is_complete() {
-                        let results =
-                            ResultState::victory_for_mission(&self.mission, &self.party_members);
-                        return Some(StateTransition::ToResults(results));
+                        return Some(self.return_home(kingdom));
                     }
                 }
             }
@@ -262,13 +461,10 @@ impl MissionState {
 
                     if connections.is_empty() {
                         // Mission complete!
-                        let results =
-                            ResultState::victory_for_mission(&self.mission, &self.party_members);
-                        return Some(StateTransition::ToResults(results));
+                        return Some(self.return_home(kingdom));
                     } else if connections.len() == 1 {
                         // Only one path - auto-advance
-                        self.current_node_id = connections[0];
-                        self.visited_nodes.push(connections[0]);
+                        self.move_to_node(connections[0]);
 
                         if let Some(transition) = self.process_current_node() {
                             return Some(transition);
@@ -282,357 +478,12 @@ impl MissionState {
             }
         }
 
-        // Escape to retreat
+        // Escape to retreat; abandoning the contract destabilizes its region
         if is_key_pressed(KeyCode::Escape) {
+            kingdom.record_retreat(&self.mission.id, &self.mission.region_id);
             return Some(StateTransition::ToBase);
         }
 
         None
     }
-
-    pub fn draw(&self, textures: &std::collections::HashMap<String, Texture2D>) {
-        // Draw background
-        let bg_path = format!("assets/images/regions/{}.png", self.mission.region_id);
-        if let Some(tex) = textures.get(&bg_path) {
-            draw_texture_ex(
-                tex,
-                0.0,
-                0.0,
-                WHITE,
-                DrawTextureParams {
-                    dest_size: Some(vec2(screen_width(), screen_height())),
-                    ..Default::default()
-                },
-            );
-
-            // Dark overlay for readability
-            draw_rectangle(
-                0.0,
-                0.0,
-                screen_width(),
-                screen_height(),
-                Color::from_rgba(0, 0, 0, 150),
-            );
-        }
-
-        draw_rectangle(
-            0.0,
-            0.0,
-            screen_width(),
-            82.0,
-            Color::from_rgba(8, 7, 6, 228),
-        );
-        draw_line(0.0, 82.0, screen_width(), 82.0, 2.0, border_color());
-        draw_ui_text(
-            &format!("MISSION: {}", self.mission.name),
-            24.0,
-            38.0,
-            28.0,
-            title_color(),
-        );
-        draw_ui_text(
-            &format!("{:?} Mission", self.mission.mission_type),
-            24.0,
-            66.0,
-            18.0,
-            muted_text_color(),
-        );
-
-        draw_party_panel(&self.party_members, textures);
-        draw_legend_panel();
-        draw_route_panel();
-        // Draw branching map
-        self.draw_branching_map();
-        draw_current_node_panel(self.current_node(), self.available_paths.is_empty());
-
-        // Instructions
-        if self.available_paths.is_empty() {
-            draw_ui_text(
-                "Actions: [Space] Advance   [Esc] Retreat",
-                24.0,
-                screen_height() - 24.0,
-                16.0,
-                ready_color(),
-            );
-        } else {
-            draw_ui_text(
-                "Actions: [Left/Right or 1-3] Choose Path   [Space] Confirm   [Esc] Retreat",
-                24.0,
-                screen_height() - 24.0,
-                16.0,
-                candle_color(),
-            );
-        }
-    }
-
-    /// Draw the branching map visualization
-    fn draw_branching_map(&self) {
-        let map_y = 180.0;
-        let node_size = 48.0;
-        let layer_gap = 130.0;
-        let node_gap = 78.0;
-        let start_x = 350.0;
-
-        // Group nodes by layer
-        let max_layer = self.map_nodes.iter().map(|n| n.layer).max().unwrap_or(0);
-
-        // First pass: draw connections
-        for node in &self.map_nodes {
-            let node_x = start_x + (node.layer as f32 * layer_gap);
-
-            // Get nodes in this layer to calculate Y position
-            let nodes_in_layer: Vec<_> = self
-                .map_nodes
-                .iter()
-                .filter(|n| n.layer == node.layer)
-                .collect();
-            let layer_height = (nodes_in_layer.len() as f32 - 1.0) * node_gap;
-            let layer_start_y = map_y + (260.0 - layer_height) / 2.0;
-            let node_y = layer_start_y + (node.position as f32 * node_gap);
-
-            // Draw connections to next nodes
-            for &target_id in &node.connections {
-                if let Some(target) = self.map_nodes.iter().find(|n| n.id == target_id) {
-                    let target_x = start_x + (target.layer as f32 * layer_gap);
-                    let target_nodes_in_layer: Vec<_> = self
-                        .map_nodes
-                        .iter()
-                        .filter(|n| n.layer == target.layer)
-                        .collect();
-                    let target_layer_height = (target_nodes_in_layer.len() as f32 - 1.0) * node_gap;
-                    let target_layer_start_y = map_y + (260.0 - target_layer_height) / 2.0;
-                    let target_y = target_layer_start_y + (target.position as f32 * node_gap);
-
-                    // Line color based on whether this is a selectable path
-                    let line_color = if self.available_paths.contains(&target_id) {
-                        if self.available_paths.get(self.selected_path) == Some(&target_id) {
-                            candle_color()
-                        } else {
-                            Color::from_rgba(104, 137, 90, 255)
-                        }
-                    } else if self.visited_nodes.contains(&target_id)
-                        || self.visited_nodes.contains(&node.id)
-                    {
-                        ready_color()
-                    } else {
-                        Color::from_rgba(66, 60, 54, 255)
-                    };
-
-                    draw_line(
-                        node_x + node_size / 2.0,
-                        node_y + node_size / 2.0,
-                        target_x + node_size / 2.0,
-                        target_y + node_size / 2.0,
-                        2.0,
-                        line_color,
-                    );
-                }
-            }
-        }
-
-        // Second pass: draw nodes
-        for node in &self.map_nodes {
-            let node_x = start_x + (node.layer as f32 * layer_gap);
-
-            let nodes_in_layer: Vec<_> = self
-                .map_nodes
-                .iter()
-                .filter(|n| n.layer == node.layer)
-                .collect();
-            let layer_height = (nodes_in_layer.len() as f32 - 1.0) * node_gap;
-            let layer_start_y = map_y + (260.0 - layer_height) / 2.0;
-            let node_y = layer_start_y + (node.position as f32 * node_gap);
-
-            // Node color
-            let (bg_color, border_color) = if node.id == self.current_node_id {
-                (Color::from_rgba(155, 106, 36, 255), title_color())
-            } else if self.available_paths.contains(&node.id) {
-                let is_selected = self.available_paths.get(self.selected_path) == Some(&node.id);
-                if is_selected {
-                    (Color::from_rgba(91, 126, 75, 255), candle_color())
-                } else {
-                    (Color::from_rgba(50, 74, 52, 255), ready_color())
-                }
-            } else if self.visited_nodes.contains(&node.id) {
-                (Color::from_rgba(88, 114, 71, 255), title_color())
-            } else {
-                (Color::from_rgba(35, 33, 31, 255), muted_text_color())
-            };
-
-            draw_rectangle(node_x, node_y, node_size, node_size, bg_color);
-            draw_rectangle_lines(node_x, node_y, node_size, node_size, 2.0, border_color);
-
-            // Node icon
-            let (icon, icon_color) = match &node.node_type {
-                NodeType::Combat => ("X", danger_color()),
-                NodeType::Boss => ("!", mystery_color()),
-                NodeType::Event => ("?", info_color()),
-                NodeType::Rest => ("+", ready_color()),
-            };
-            let text_color =
-                if self.visited_nodes.contains(&node.id) || node.id == self.current_node_id {
-                    Color::from_rgba(12, 10, 8, 255)
-                } else {
-                    icon_color
-                };
-            draw_ui_text(icon, node_x + 17.0, node_y + 34.0, 26.0, text_color);
-
-            // Show selection number if path choice
-            if let Some(idx) = self.available_paths.iter().position(|&id| id == node.id) {
-                draw_ui_text(
-                    &format!("[{}]", idx + 1),
-                    node_x + 15.0,
-                    node_y - 5.0,
-                    16.0,
-                    candle_color(),
-                );
-            }
-        }
-
-        // Progress indicator
-        let current_layer = self.current_node().map(|n| n.layer).unwrap_or(0);
-        let progress = format!("Layer {}/{}", current_layer + 1, max_layer + 1);
-        draw_ui_text(&progress, 350.0, 147.0, 18.0, candle_color());
-    }
-}
-
-fn draw_party_panel(
-    party_members: &[PartyMemberState],
-    textures: &std::collections::HashMap<String, Texture2D>,
-) {
-    panel(24.0, 104.0, 284.0, 244.0, "EXPEDITION PARTY");
-    if party_members.is_empty() {
-        draw_ui_text("No party assigned.", 42.0, 156.0, 17.0, muted_text_color());
-        return;
-    }
-
-    for (i, member) in party_members.iter().enumerate().take(4) {
-        let y = 152.0 + (i as f32 * 48.0);
-        if let Some(path) = &member.image_path {
-            if let Some(tex) = textures.get(path) {
-                draw_texture_ex(
-                    tex,
-                    42.0,
-                    y - 30.0,
-                    WHITE,
-                    DrawTextureParams {
-                        dest_size: Some(vec2(38.0, 38.0)),
-                        ..Default::default()
-                    },
-                );
-            }
-        }
-        draw_ui_text(&member.name, 92.0, y - 9.0, 16.0, text_color());
-        draw_ui_text(
-            &format!(
-                "HP {}/{}    Stress {}",
-                member.hp, member.max_hp, member.stress
-            ),
-            92.0,
-            y + 12.0,
-            13.0,
-            muted_text_color(),
-        );
-    }
-}
-
-fn draw_legend_panel() {
-    panel(24.0, 366.0, 284.0, 132.0, "NODE LEGEND");
-    let rows = [
-        ("?", "Event / unknown", info_color()),
-        ("X", "Combat", danger_color()),
-        ("+", "Rest", ready_color()),
-        ("!", "Boss / critical threat", mystery_color()),
-    ];
-    for (i, (icon, label, color)) in rows.iter().enumerate() {
-        let y = 410.0 + (i as f32 * 23.0);
-        draw_ui_text(icon, 46.0, y, 18.0, *color);
-        draw_ui_text(label, 76.0, y, 15.0, muted_text_color());
-    }
-}
-
-fn draw_route_panel() {
-    panel(328.0, 104.0, 896.0, 456.0, "EXPEDITION ROUTE");
-    draw_ui_text(
-        "Read the route before committing. Branches become decisions when the trail forks.",
-        350.0,
-        536.0,
-        15.0,
-        muted_text_color(),
-    );
-}
-
-fn draw_current_node_panel(node: Option<&MapNode>, can_advance: bool) {
-    panel(24.0, 516.0, 284.0, 104.0, "CURRENT REPORT");
-    let Some(node) = node else {
-        draw_ui_text(
-            "Route data unavailable.",
-            42.0,
-            568.0,
-            16.0,
-            muted_text_color(),
-        );
-        return;
-    };
-    let label = match node.node_type {
-        NodeType::Combat => "Combat contact ahead",
-        NodeType::Event => "Uncertain trail marker",
-        NodeType::Rest => "Rest point",
-        NodeType::Boss => "Command warning: boss",
-    };
-    draw_ui_text(label, 42.0, 566.0, 17.0, text_color());
-    draw_ui_text(
-        if can_advance {
-            "Advance to reveal the report."
-        } else {
-            "Choose the next route."
-        },
-        42.0,
-        594.0,
-        14.0,
-        muted_text_color(),
-    );
-}
-
-fn panel(x: f32, y: f32, w: f32, h: f32, title: &str) {
-    draw_rectangle(x, y, w, h, Color::from_rgba(13, 11, 10, 210));
-    draw_rectangle(x, y, w, 32.0, Color::from_rgba(42, 30, 18, 222));
-    draw_rectangle_lines(x, y, w, h, 1.0, border_color());
-    draw_ui_text(title, x + 14.0, y + 22.0, 15.0, candle_color());
-}
-
-fn text_color() -> Color {
-    Color::from_rgba(230, 221, 205, 255)
-}
-
-fn muted_text_color() -> Color {
-    Color::from_rgba(158, 145, 126, 255)
-}
-
-fn title_color() -> Color {
-    Color::from_rgba(239, 224, 190, 255)
-}
-
-fn candle_color() -> Color {
-    Color::from_rgba(207, 151, 54, 255)
-}
-
-fn ready_color() -> Color {
-    Color::from_rgba(130, 177, 101, 255)
-}
-
-fn danger_color() -> Color {
-    Color::from_rgba(168, 58, 48, 255)
-}
-
-fn info_color() -> Color {
-    Color::from_rgba(118, 151, 164, 255)
-}
-
-fn mystery_color() -> Color {
-    Color::from_rgba(138, 104, 167, 255)
-}
-
-fn border_color() -> Color {
-    Color::from_rgba(105, 76, 43, 210)
 }
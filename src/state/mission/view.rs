@@ -0,0 +1,450 @@
+//! Mission map drawing
+
+use super::*;
+
+impl MissionState {
+    pub fn draw(
+        &self,
+        kingdom: &KingdomState,
+        textures: &std::collections::HashMap<String, Texture2D>,
+    ) {
+        // Draw background
+        let bg_path = format!("assets/images/regions/{}.png", self.mission.region_id);
+        if let Some(tex) = textures.get(&bg_path) {
+            draw_texture_ex(
+                tex,
+                0.0,
+                0.0,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(screen_width(), screen_height())),
+                    ..Default::default()
+                },
+            );
+
+            // Dark overlay for readability
+            draw_rectangle(
+                0.0,
+                0.0,
+                screen_width(),
+                screen_height(),
+                Color::from_rgba(0, 0, 0, 150),
+            );
+        }
+
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            82.0,
+            Color::from_rgba(8, 7, 6, 228),
+        );
+        draw_line(0.0, 82.0, screen_width(), 82.0, 2.0, border_color());
+        draw_ui_text(
+            &format!("MISSION: {}", self.mission.name),
+            24.0,
+            38.0,
+            28.0,
+            title_color(),
+        );
+        draw_ui_text(
+            &format!(
+                "{:?} Mission - Objective: {}",
+                self.mission.mission_type,
+                self.mission.victory_condition().describe()
+            ),
+            24.0,
+            66.0,
+            18.0,
+            muted_text_color(),
+        );
+
+        self.draw_progress_strip();
+        draw_party_panel(&self.party_members, textures);
+        draw_legend_panel();
+        draw_route_panel();
+        // Draw branching map
+        self.draw_branching_map();
+        draw_current_node_panel(self.current_node(), self.available_paths.is_empty());
+
+        // Instructions
+        if self.available_paths.is_empty() {
+            draw_ui_text(
+                "Actions: [Space] Advance   [Esc] Retreat   [F1] Help",
+                24.0,
+                screen_height() - 24.0,
+                16.0,
+                ready_color(),
+            );
+        } else {
+            draw_ui_text(
+                "Actions: [Left/Right or 1-3] Choose Path   [Space] Confirm   [Esc] Retreat   [F1] Help",
+                24.0,
+                screen_height() - 24.0,
+                16.0,
+                candle_color(),
+            );
+        }
+
+        self.draw_node_tooltip(kingdom.stats.knowledge);
+    }
+
+    /// Condensed one-dot-per-layer progress in the header; visited dots can be clicked
+    fn draw_progress_strip(&self) {
+        let progress = self.layer_progress();
+        for pair in progress.windows(2) {
+            let from = Self::progress_dot_pos(pair[0].layer, progress.len());
+            let to = Self::progress_dot_pos(pair[1].layer, progress.len());
+            let color = if pair[1].visited_node.is_some() {
+                ready_color()
+            } else {
+                border_color()
+            };
+            draw_line(from.x, from.y, to.x, to.y, 2.0, color);
+        }
+        for dot in &progress {
+            let pos = Self::progress_dot_pos(dot.layer, progress.len());
+            let (fill, radius) = if dot.current {
+                (title_color(), PROGRESS_DOT_RADIUS + 2.0)
+            } else if dot.visited_node.is_some() {
+                (ready_color(), PROGRESS_DOT_RADIUS)
+            } else {
+                (Color::from_rgba(35, 33, 31, 255), PROGRESS_DOT_RADIUS)
+            };
+            draw_circle(pos.x, pos.y, radius, fill);
+            draw_circle_lines(pos.x, pos.y, radius, 1.0, border_color());
+        }
+    }
+
+    /// Describe the hovered node; distant nodes stay hidden without enough Knowledge
+    fn draw_node_tooltip(&self, knowledge: i32) {
+        let current_layer = self.current_node().map(|n| n.layer).unwrap_or(0);
+        for node in &self.map_nodes {
+            let Some((x, y, size)) = self.get_node_screen_pos(node.id) else {
+                continue;
+            };
+            if !crate::ui::is_mouse_over(x, y, size, size) {
+                continue;
+            }
+
+            let scouted = node.layer <= current_layer + 1
+                || self.visited_nodes.contains(&node.id)
+                || knowledge >= ROUTE_SCOUTING_KNOWLEDGE;
+            if scouted {
+                let (title, body) = node_preview(&node.node_type);
+                match node.enemy_id.as_deref().and_then(crate::data::enemy_by_id) {
+                    Some(enemy) => crate::ui::text_tooltip(
+                        title,
+                        &format!(
+                            "{}\n{} - {} threat",
+                            body,
+                            enemy.name,
+                            crate::data::threat_label(enemy.threat_level)
+                        ),
+                    ),
+                    None => crate::ui::text_tooltip(title, body),
+                }
+            } else {
+                crate::ui::text_tooltip(
+                    "???",
+                    "Too far ahead to scout. More kingdom Knowledge would reveal it.",
+                );
+            }
+            return;
+        }
+    }
+
+    /// Draw the branching map visualization, panned and zoomed
+    fn draw_branching_map(&self) {
+        // Group nodes by layer
+        let max_layer = self.map_nodes.iter().map(|n| n.layer).max().unwrap_or(0);
+
+        // First pass: draw connections
+        for node in &self.map_nodes {
+            let Some((node_x, node_y, node_size)) = self.get_node_screen_pos(node.id) else {
+                continue;
+            };
+
+            // Draw connections to next nodes
+            for &target_id in &node.connections {
+                if let Some((target_x, target_y, _)) = self.get_node_screen_pos(target_id) {
+                    // Line color based on whether this is a selectable path
+                    let line_color = if self.available_paths.contains(&target_id) {
+                        if self.available_paths.get(self.selected_path) == Some(&target_id) {
+                            candle_color()
+                        } else {
+                            Color::from_rgba(104, 137, 90, 255)
+                        }
+                    } else if self.pruned.contains(&target_id) || self.pruned.contains(&node.id) {
+                        Color::from_rgba(34, 31, 28, 255)
+                    } else if self.visited_nodes.contains(&target_id)
+                        || self.visited_nodes.contains(&node.id)
+                    {
+                        ready_color()
+                    } else {
+                        Color::from_rgba(66, 60, 54, 255)
+                    };
+
+                    draw_line(
+                        node_x + node_size / 2.0,
+                        node_y + node_size / 2.0,
+                        target_x + node_size / 2.0,
+                        target_y + node_size / 2.0,
+                        2.0,
+                        line_color,
+                    );
+                }
+            }
+        }
+
+        // Second pass: draw nodes
+        let zoom = self.map_zoom;
+        for node in &self.map_nodes {
+            let Some((node_x, node_y, node_size)) = self.get_node_screen_pos(node.id) else {
+                continue;
+            };
+
+            // Node color
+            let (bg_color, border_color) = if node.id == self.current_node_id {
+                (Color::from_rgba(155, 106, 36, 255), title_color())
+            } else if self.available_paths.contains(&node.id) {
+                let is_selected = self.available_paths.get(self.selected_path) == Some(&node.id);
+                if is_selected {
+                    (Color::from_rgba(91, 126, 75, 255), candle_color())
+                } else {
+                    (Color::from_rgba(50, 74, 52, 255), ready_color())
+                }
+            } else if self.visited_nodes.contains(&node.id) {
+                (Color::from_rgba(88, 114, 71, 255), title_color())
+            } else if self.pruned.contains(&node.id) {
+                (Color::from_rgba(16, 15, 14, 255), pruned_color())
+            } else {
+                (Color::from_rgba(35, 33, 31, 255), muted_text_color())
+            };
+
+            draw_rectangle(node_x, node_y, node_size, node_size, bg_color);
+            draw_rectangle_lines(node_x, node_y, node_size, node_size, 2.0, border_color);
+
+            // Node icon
+            let (icon, icon_color) = match &node.node_type {
+                NodeType::Combat => ("X", danger_color()),
+                NodeType::Elite => ("E", elite_color()),
+                NodeType::Boss => ("!", mystery_color()),
+                NodeType::Event => ("?", info_color()),
+                NodeType::Rest => ("+", ready_color()),
+            };
+            let text_color =
+                if self.visited_nodes.contains(&node.id) || node.id == self.current_node_id {
+                    Color::from_rgba(12, 10, 8, 255)
+                } else if self.pruned.contains(&node.id) {
+                    pruned_color()
+                } else {
+                    icon_color
+                };
+            draw_ui_text(
+                icon,
+                node_x + 17.0 * zoom,
+                node_y + 34.0 * zoom,
+                26.0 * zoom,
+                text_color,
+            );
+            if crate::ui::colorblind_mode() {
+                draw_ui_text(
+                    node_preview(&node.node_type).0,
+                    node_x,
+                    node_y + node_size + 14.0 * zoom,
+                    13.0 * zoom,
+                    text_color,
+                );
+            }
+
+            // Show selection number if path choice
+            if let Some(idx) = self.available_paths.iter().position(|&id| id == node.id) {
+                draw_ui_text(
+                    &format!("[{}]", idx + 1),
+                    node_x + 15.0 * zoom,
+                    node_y - 5.0,
+                    16.0,
+                    candle_color(),
+                );
+            }
+        }
+
+        // Progress indicator
+        let current_layer = self.current_node().map(|n| n.layer).unwrap_or(0);
+        let progress = format!("Layer {}/{}", current_layer + 1, max_layer + 1);
+        draw_ui_text(&progress, 350.0, 147.0, 18.0, candle_color());
+        if !self.pruned.is_empty() {
+            draw_ui_text(
+                &format!(
+                    "Path committed - {} route(s) left behind",
+                    self.pruned.len()
+                ),
+                480.0,
+                147.0,
+                16.0,
+                muted_text_color(),
+            );
+        }
+    }
+}
+
+fn draw_party_panel(
+    party_members: &[PartyMemberState],
+    textures: &std::collections::HashMap<String, Texture2D>,
+) {
+    panel(24.0, 104.0, 284.0, 244.0, "EXPEDITION PARTY");
+    if party_members.is_empty() {
+        draw_ui_text("No party assigned.", 42.0, 156.0, 17.0, muted_text_color());
+        return;
+    }
+
+    for (i, member) in party_members.iter().enumerate().take(4) {
+        let y = 152.0 + (i as f32 * 48.0);
+        if let Some(path) = &member.image_path {
+            if let Some(tex) = textures.get(path) {
+                draw_texture_ex(
+                    tex,
+                    42.0,
+                    y - 30.0,
+                    WHITE,
+                    DrawTextureParams {
+                        dest_size: Some(vec2(38.0, 38.0)),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        draw_ui_text(&member.name, 92.0, y - 9.0, 16.0, text_color());
+        draw_ui_text(
+            &format!(
+                "HP {}/{}    Stress {}",
+                member.hp, member.max_hp, member.stress
+            ),
+            92.0,
+            y + 12.0,
+            13.0,
+            muted_text_color(),
+        );
+    }
+}
+
+fn draw_legend_panel() {
+    panel(24.0, 366.0, 284.0, 132.0, "NODE LEGEND");
+    let rows = [
+        ("?", "Event / unknown", info_color()),
+        ("X", "Combat", danger_color()),
+        ("+", "Rest", ready_color()),
+        ("!", "Boss / critical threat", mystery_color()),
+    ];
+    for (i, (icon, label, color)) in rows.iter().enumerate() {
+        let y = 410.0 + (i as f32 * 23.0);
+        draw_ui_text(icon, 46.0, y, 18.0, *color);
+        draw_ui_text(label, 76.0, y, 15.0, muted_text_color());
+    }
+}
+
+fn draw_route_panel() {
+    panel(328.0, 104.0, 896.0, 456.0, "EXPEDITION ROUTE");
+    draw_ui_text(
+        "Read the route before committing. Branches become decisions when the trail forks.",
+        350.0,
+        536.0,
+        15.0,
+        muted_text_color(),
+    );
+}
+
+fn draw_current_node_panel(node: Option<&MapNode>, can_advance: bool) {
+    panel(24.0, 516.0, 284.0, 104.0, "CURRENT REPORT");
+    let Some(node) = node else {
+        draw_ui_text(
+            "Route data unavailable.",
+            42.0,
+            568.0,
+            16.0,
+            muted_text_color(),
+        );
+        return;
+    };
+    let label = match node.node_type {
+        NodeType::Combat => "Combat contact ahead",
+        NodeType::Elite => "Elite contact: a hardened foe guards spoils",
+        NodeType::Event => "Uncertain trail marker",
+        NodeType::Rest => "Rest point",
+        NodeType::Boss => "Command warning: boss",
+    };
+    draw_ui_text(label, 42.0, 566.0, 17.0, text_color());
+    draw_ui_text(
+        if can_advance {
+            "Advance to reveal the report."
+        } else {
+            "Choose the next route."
+        },
+        42.0,
+        594.0,
+        14.0,
+        muted_text_color(),
+    );
+}
+
+fn panel(x: f32, y: f32, w: f32, h: f32, title: &str) {
+    draw_rectangle(x, y, w, h, Color::from_rgba(13, 11, 10, 210));
+    draw_rectangle(x, y, w, 32.0, Color::from_rgba(42, 30, 18, 222));
+    draw_rectangle_lines(x, y, w, h, 1.0, border_color());
+    draw_ui_text(title, x + 14.0, y + 22.0, 15.0, candle_color());
+}
+
+fn text_color() -> Color {
+    Color::from_rgba(230, 221, 205, 255)
+}
+
+fn muted_text_color() -> Color {
+    Color::from_rgba(158, 145, 126, 255)
+}
+
+fn title_color() -> Color {
+    Color::from_rgba(239, 224, 190, 255)
+}
+
+fn candle_color() -> Color {
+    Color::from_rgba(207, 151, 54, 255)
+}
+
+fn ready_color() -> Color {
+    Color::from_rgba(130, 177, 101, 255)
+}
+
+fn danger_color() -> Color {
+    Color::from_rgba(168, 58, 48, 255)
+}
+
+fn info_color() -> Color {
+    Color::from_rgba(118, 151, 164, 255)
+}
+
+fn node_preview(node_type: &NodeType) -> (&'static str, &'static str) {
+    match node_type {
+        NodeType::Combat => ("Combat", "A creature lurks here."),
+        NodeType::Elite => ("Elite", "A hardened foe guards better spoils."),
+        NodeType::Event => ("Event", "Unknown. Anything could be waiting on the trail."),
+        NodeType::Rest => ("Rest", "Recover a little HP and shed some stress."),
+        NodeType::Boss => ("Boss", "The threat behind this expedition."),
+    }
+}
+
+fn pruned_color() -> Color {
+    Color::from_rgba(58, 53, 48, 255)
+}
+
+fn elite_color() -> Color {
+    Color::from_rgba(214, 124, 52, 255)
+}
+
+fn mystery_color() -> Color {
+    Color::from_rgba(138, 104, 167, 255)
+}
+
+fn border_color() -> Color {
+    Color::from_rgba(105, 76, 43, 210)
+}
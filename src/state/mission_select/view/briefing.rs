@@ -0,0 +1,123 @@
+//! Embark briefing - region, route, rewards, and the committed party before launch.
+
+use super::*;
+
+impl MissionSelectState {
+    pub(super) fn draw_briefing(&self, kingdom: &KingdomState) {
+        let Some(mission) = self.selected_mission() else {
+            return;
+        };
+        let effective = mission.scaled_for_kingdom(kingdom);
+        let region = kingdom.region(&mission.region_id);
+
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            screen_height(),
+            Color::from_rgba(0, 0, 0, 170),
+        );
+        let (x, y, w, _) = briefing_rect();
+        panel(x, y, w, BRIEFING_H, "EXPEDITION BRIEFING");
+        let left = x + 24.0;
+        let inner_w = w - 48.0;
+
+        draw_ui_text(
+            &mission.name.to_uppercase(),
+            left,
+            y + 66.0,
+            24.0,
+            title_color(),
+        );
+        let region_name = region
+            .map(|region| region.name.as_str())
+            .unwrap_or_else(|| region_label(&mission.region_id));
+        let faction = region
+            .map(|region| region.faction.as_str())
+            .unwrap_or("Unknown");
+        draw_ui_text(
+            &format!("{} - Enemy faction: {}", region_name, faction),
+            left,
+            y + 94.0,
+            17.0,
+            mission_type_color(&mission.mission_type),
+        );
+        if let Some(region) = region {
+            draw_wrapped_text(
+                &region.description,
+                left,
+                y + 124.0,
+                inner_w,
+                16.0,
+                muted_text_color(),
+            );
+        }
+
+        draw_ui_text(
+            &format!(
+                "Length {} nodes    Difficulty {}    Stress Gain {}",
+                effective.length, effective.difficulty, effective.base_stress
+            ),
+            left,
+            y + 188.0,
+            17.0,
+            text_color(),
+        );
+        let rating = self.assess(&effective);
+        let mut outlook = format!("Outlook: {}", rating.label());
+        if mission.energy_modifier != 0 {
+            outlook.push_str(&format!("    Energy {:+}", mission.energy_modifier));
+        }
+        if mission.hand_size_modifier != 0 {
+            outlook.push_str(&format!("    Hand {:+}", mission.hand_size_modifier));
+        }
+        draw_ui_text(&outlook, left, y + 214.0, 17.0, rating_color(rating));
+        draw_ui_text(
+            &format!(
+                "Rewards: {} Gold    {} Supplies    {} Knowledge    {} Influence",
+                mission.reward_gold,
+                mission.reward_supplies,
+                mission.reward_knowledge,
+                mission.reward_influence
+            ),
+            left,
+            y + 240.0,
+            17.0,
+            reward_color(),
+        );
+
+        draw_ui_text("Committed Party", left, y + 278.0, 18.0, candle_color());
+        for (i, member) in self.party_members.iter().enumerate().take(4) {
+            let risk = member_risk_label(member);
+            draw_ui_text(
+                &format!(
+                    "{}  {}  HP {}/{}  Stress {}",
+                    member.name, member.class_name, member.hp, member.max_hp, member.stress
+                ),
+                left,
+                y + 304.0 + (i as f32 * 24.0),
+                16.0,
+                text_color(),
+            );
+            draw_ui_text(
+                risk,
+                left + inner_w - 60.0,
+                y + 304.0 + (i as f32 * 24.0),
+                16.0,
+                risk_color(risk),
+            );
+        }
+
+        let (confirm_x, confirm_y, confirm_w, confirm_h) = briefing_confirm_rect();
+        draw_action_button(
+            "[Enter] Confirm",
+            confirm_x,
+            confirm_y,
+            confirm_w,
+            confirm_h,
+            true,
+        );
+        let (back_x, back_y, back_w, back_h) = briefing_back_rect();
+        draw_action_button("[Esc] Back", back_x, back_y, back_w, back_h, true);
+    }
+}
@@ -5,26 +5,57 @@
 mod base;
 mod combat;
 mod event;
+mod foundry;
+mod game_over;
+mod history;
+mod kingdom_event;
+mod main_menu;
+mod memorial;
 mod mission;
 mod mission_select;
 mod recruit;
 mod results;
+mod settings;
+mod snapshot;
 
 pub use base::BaseState;
 pub use combat::CombatState;
 pub use event::EventState;
+pub use foundry::FoundryState;
+pub use game_over::GameOverState;
+pub use history::HistoryState;
+pub use kingdom_event::KingdomEventState;
+pub use main_menu::MainMenuState;
+pub use memorial::MemorialState;
 pub use mission::MissionState;
 pub use mission_select::MissionSelectState;
 pub use recruit::RecruitState;
 pub use results::ResultState;
+pub use settings::{apply_settings, SettingsState};
+pub use snapshot::InProgress;
 
 /// Explicit state transitions - no magic callbacks
 pub enum StateTransition {
     ToBase,
     ToMissionSelect(MissionSelectState),
     ToMission(MissionState),
-    ToCombat(CombatState),
+    ToCombat(Box<CombatState>),
     ToResults(ResultState),
     ToEvent(EventState),
+    ToKingdomEvent(KingdomEventState),
     ToRecruit,
+    /// Forge learned cards into upgraded versions
+    ToFoundry,
+    /// Found a fresh kingdom and roster after a game over
+    ToNewGame,
+    /// Pick the saved campaign back up, on the screen it was saved on
+    ToContinue,
+    /// Start today's seeded challenge run
+    ToDailyChallenge,
+    /// List past runs
+    ToHistory,
+    /// Honor the fallen
+    ToMemorial,
+    /// Edit saved preferences
+    ToSettings,
 }
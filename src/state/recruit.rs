@@ -1,18 +1,76 @@
 //! Recruitment state - hire new adventurers
 
 use super::StateTransition;
-use crate::kingdom::{Adventurer, AdventurerClass, KingdomState, Roster};
+use crate::data::names;
+use crate::kingdom::{Adventurer, AdventurerClass, KingdomState, Roster, Trait};
 use macroquad::prelude::*;
 use macroquad_toolkit::rng;
 use macroquad_toolkit::ui::draw_ui_text;
 use std::collections::HashMap;
 
-/// Names for random adventurers
-const FIRST_NAMES: &[&str] = &[
-    "Aldric", "Beatrix", "Cedric", "Diana", "Edmund", "Freya", "Godric", "Helena", "Ivan",
-    "Jocelyn", "Klaus", "Lydia", "Magnus", "Nadia", "Oscar", "Petra", "Quinn", "Rosa", "Stefan",
-    "Thea", "Ulric", "Vera", "Werner", "Xena",
-];
+/// Morale at or above which the applicant pool is eager
+const EAGER_MORALE: i32 = 70;
+/// Morale below which only desperate hires apply
+const DESPERATE_MORALE: i32 = 40;
+/// Max HP shifted by the Hardy and Frail traits
+const TRAIT_HP_SHIFT: i32 = 5;
+/// Stress a Haunted recruit arrives with
+const HAUNTED_STRESS: i32 = 20;
+/// Applicants the kingdom can hold back at once
+const MAX_RESERVED_RECRUITS: usize = 2;
+
+/// Applicant pool quality, set by kingdom morale
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApplicantPool {
+    Desperate,
+    Steady,
+    Eager,
+}
+
+impl ApplicantPool {
+    pub fn for_morale(morale: i32) -> Self {
+        if morale >= EAGER_MORALE {
+            ApplicantPool::Eager
+        } else if morale < DESPERATE_MORALE {
+            ApplicantPool::Desperate
+        } else {
+            ApplicantPool::Steady
+        }
+    }
+
+    fn recruit_count(self) -> usize {
+        match self {
+            ApplicantPool::Desperate => 2,
+            ApplicantPool::Steady => 3,
+            ApplicantPool::Eager => 4,
+        }
+    }
+
+    /// Odds of a positive and a negative trait per recruit
+    fn trait_odds(self) -> (f32, f32) {
+        match self {
+            ApplicantPool::Desperate => (0.0, 0.5),
+            ApplicantPool::Steady => (0.2, 0.1),
+            ApplicantPool::Eager => (0.5, 0.0),
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            ApplicantPool::Desperate => "Only the desperate answer a failing kingdom's call.",
+            ApplicantPool::Steady => "A steady trickle of hopefuls answers the call.",
+            ApplicantPool::Eager => "Word of your victories draws talent.",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            ApplicantPool::Desperate => ORANGE,
+            ApplicantPool::Steady => LIGHTGRAY,
+            ApplicantPool::Eager => GREEN,
+        }
+    }
+}
 
 /// A recruit available for hire
 #[derive(Clone)]
@@ -23,48 +81,107 @@ pub struct Recruit {
 
 impl Recruit {
     pub fn random(class: AdventurerClass) -> Self {
-        let name = FIRST_NAMES[rng::gen_range(0, FIRST_NAMES.len())];
         let gender = if rng::gen_range(0, 2) == 0 {
             crate::kingdom::Gender::Male
         } else {
             crate::kingdom::Gender::Female
         };
+        let name = names::random_full_name(&gender);
+        Self::from_adventurer(Adventurer::new(&name, class, gender))
+    }
 
-        let cost = match class {
+    /// Offer an existing applicant, e.g. one held in reserve, at their class fee
+    fn from_adventurer(adventurer: Adventurer) -> Self {
+        let cost = match adventurer.class {
             AdventurerClass::Soldier => 50,
             AdventurerClass::Scout => 40,
             AdventurerClass::Healer => 60,
             AdventurerClass::Mystic => 70,
         };
-        let adventurer = Adventurer::new(name, class, gender);
         Self { adventurer, cost }
     }
+
+    /// Roll a recruit whose traits reflect the applicant pool
+    pub fn for_pool(class: AdventurerClass, pool: ApplicantPool) -> Self {
+        let mut recruit = Self::random(class);
+        let (positive, negative) = pool.trait_odds();
+        if rng::chance(positive) {
+            let good = if rng::chance(0.5) {
+                Trait::hardy()
+            } else {
+                Trait::steady_nerves()
+            };
+            recruit.gain_trait(good);
+        } else if rng::chance(negative) {
+            let bad = if rng::chance(0.5) {
+                Trait::frail()
+            } else {
+                Trait::haunted()
+            };
+            recruit.gain_trait(bad);
+        }
+        recruit
+    }
+
+    /// Add a trait and apply its immediate effect
+    fn gain_trait(&mut self, new_trait: Trait) {
+        let adv = &mut self.adventurer;
+        match new_trait.id.as_str() {
+            "hardy" => adv.max_hp += TRAIT_HP_SHIFT,
+            "frail" => adv.max_hp -= TRAIT_HP_SHIFT,
+            "haunted" => adv.stress = HAUNTED_STRESS,
+            _ => {}
+        }
+        adv.hp = adv.max_hp;
+        adv.traits.push(new_trait);
+    }
 }
 
 /// State for recruiting new adventurers
 pub struct RecruitState {
     pub recruits: Vec<Recruit>,
     pub selected: usize,
+    pub pool: ApplicantPool,
+    /// Hiring feedback, drained by `Game` into toasts
+    pub notifications: Vec<String>,
 }
 
 impl Default for RecruitState {
     fn default() -> Self {
-        Self::new()
+        Self::generate(&KingdomState::default())
     }
 }
 
 impl RecruitState {
-    pub fn new() -> Self {
-        // Generate 3 random recruits
-        let recruits = vec![
-            Recruit::random(AdventurerClass::Soldier),
-            Recruit::random(AdventurerClass::Scout),
-            Recruit::random(AdventurerClass::Healer),
+    /// Roll today's applicants; kingdom morale sets how many and how good.
+    /// Anyone held in reserve waits at the front of the line, taking one of those places
+    pub fn generate(kingdom: &KingdomState) -> Self {
+        const CLASSES: [AdventurerClass; 4] = [
+            AdventurerClass::Soldier,
+            AdventurerClass::Scout,
+            AdventurerClass::Healer,
+            AdventurerClass::Mystic,
         ];
+        crate::kingdom::seed_rolls(kingdom, "recruits");
+        let pool = ApplicantPool::for_morale(kingdom.stats.morale);
+        let reserved = kingdom
+            .reserved_recruits
+            .iter()
+            .cloned()
+            .map(Recruit::from_adventurer);
+        let fresh = CLASSES
+            .into_iter()
+            .take(
+                pool.recruit_count()
+                    .saturating_sub(kingdom.reserved_recruits.len()),
+            )
+            .map(|class| Recruit::for_pool(class, pool));
 
         Self {
-            recruits,
+            recruits: reserved.chain(fresh).collect(),
             selected: 0,
+            pool,
+            notifications: vec![],
         }
     }
 
@@ -91,6 +208,7 @@ impl RecruitState {
                 0 => KeyCode::Key1,
                 1 => KeyCode::Key2,
                 2 => KeyCode::Key3,
+                3 => KeyCode::Key4,
                 _ => continue,
             };
             if is_key_pressed(key) {
@@ -100,16 +218,12 @@ impl RecruitState {
 
         // Hire with Enter
         if is_key_pressed(KeyCode::Enter) {
-            if let Some(recruit) = self.recruits.get(self.selected) {
-                if kingdom.stats.gold >= recruit.cost {
-                    kingdom.stats.gold -= recruit.cost;
-                    roster.add(recruit.adventurer.clone());
-                    self.recruits.remove(self.selected);
-                    if self.selected >= self.recruits.len() && self.selected > 0 {
-                        self.selected -= 1;
-                    }
-                }
-            }
+            self.hire_selected(kingdom, roster);
+        }
+
+        // Hold the selected applicant for a later visit
+        if is_key_pressed(KeyCode::R) {
+            self.toggle_reserve(kingdom);
         }
 
         // Escape to return
@@ -120,7 +234,67 @@ impl RecruitState {
         None
     }
 
-    pub fn draw(&self, kingdom: &KingdomState, textures: &HashMap<String, Texture2D>) {
+    /// Hire the selected recruit, explaining why not when the fee or roster won't allow it
+    fn hire_selected(&mut self, kingdom: &mut KingdomState, roster: &mut Roster) {
+        let Some(recruit) = self.recruits.get(self.selected) else {
+            return;
+        };
+        let name = recruit.adventurer.name.clone();
+        if kingdom.stats.gold < recruit.cost {
+            self.notifications.push(format!(
+                "Need {} more gold to hire {}",
+                recruit.cost - kingdom.stats.gold,
+                name
+            ));
+            return;
+        }
+        let id = recruit.adventurer.id.clone();
+        if !roster.add(recruit.adventurer.clone(), kingdom.max_roster_size()) {
+            self.notifications
+                .push("Roster full - dismiss an adventurer at base first".to_string());
+            return;
+        }
+        kingdom.stats.spend_gold(recruit.cost);
+        kingdom.reserved_recruits.retain(|adv| adv.id != id);
+        self.notifications
+            .push(format!("{} joins the roster", name));
+        self.recruits.remove(self.selected);
+        if self.selected >= self.recruits.len() && self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+
+    /// Reserve the selected recruit so they wait for the next visit, or release them
+    fn toggle_reserve(&mut self, kingdom: &mut KingdomState) {
+        let Some(recruit) = self.recruits.get(self.selected) else {
+            return;
+        };
+        let adv = &recruit.adventurer;
+        let reserved = &mut kingdom.reserved_recruits;
+        let message = if let Some(pos) = reserved.iter().position(|r| r.id == adv.id) {
+            reserved.remove(pos);
+            format!("{} is no longer held in reserve", adv.name)
+        } else if reserved.len() >= MAX_RESERVED_RECRUITS {
+            format!(
+                "Only {} applicants can be held at once",
+                MAX_RESERVED_RECRUITS
+            )
+        } else {
+            reserved.push(adv.clone());
+            format!(
+                "{} will wait until you can pay {} gold",
+                adv.name, recruit.cost
+            )
+        };
+        self.notifications.push(message);
+    }
+
+    pub fn draw(
+        &self,
+        kingdom: &KingdomState,
+        roster: &Roster,
+        textures: &HashMap<String, Texture2D>,
+    ) {
         draw_ui_text("RECRUITMENT", 20.0, 40.0, 32.0, WHITE);
         draw_ui_text(
             &format!("Gold: {}", kingdom.stats.gold),
@@ -130,6 +304,26 @@ impl RecruitState {
             YELLOW,
         );
 
+        let max_size = kingdom.max_roster_size();
+        let roster_full = roster.is_full(max_size);
+        draw_ui_text(
+            &format!("Roster: {}/{}", roster.adventurers.len(), max_size),
+            160.0,
+            70.0,
+            20.0,
+            if roster_full { RED } else { LIGHTGRAY },
+        );
+        if roster_full {
+            draw_ui_text(
+                "Roster full - dismiss an adventurer at base before hiring",
+                300.0,
+                70.0,
+                18.0,
+                RED,
+            );
+        }
+        draw_ui_text(self.pool.description(), 20.0, 98.0, 18.0, self.pool.color());
+
         let start_y = 120.0;
         let card_height = 120.0;
         let card_width = 500.0;
@@ -200,6 +394,20 @@ impl RecruitState {
                 GREEN,
             );
 
+            if let Some(recruit_trait) = recruit.adventurer.traits.first() {
+                draw_ui_text(
+                    &format!("{}: {}", recruit_trait.name, recruit_trait.description),
+                    220.0,
+                    y + 80.0,
+                    16.0,
+                    if recruit_trait.is_positive {
+                        GREEN
+                    } else {
+                        ORANGE
+                    },
+                );
+            }
+
             // Cost
             let cost_color = if can_afford { YELLOW } else { RED };
             draw_ui_text(
@@ -209,6 +417,13 @@ impl RecruitState {
                 18.0,
                 cost_color,
             );
+            let reserved = kingdom
+                .reserved_recruits
+                .iter()
+                .any(|adv| adv.id == recruit.adventurer.id);
+            if reserved {
+                draw_ui_text("Reserved", 350.0, y + 30.0, 16.0, GOLD);
+            }
         }
 
         if self.recruits.is_empty() {
@@ -216,7 +431,7 @@ impl RecruitState {
         }
 
         draw_ui_text(
-            "[↑/↓] Select  [ENTER] Hire  [ESC] Back",
+            "[↑/↓] Select  [ENTER] Hire  [R] Reserve  [ESC] Back  [F1] Help",
             20.0,
             screen_height() - 40.0,
             20.0,
@@ -1,11 +1,23 @@
 //! Results state - post-mission consequences and resolution
 
-use super::StateTransition;
-use crate::kingdom::{Injury, KingdomState, PartyMemberState, Roster};
+use super::{KingdomEventState, StateTransition};
+use crate::kingdom::{
+    fill_pronouns, Injury, KingdomState, KingdomStats, PartyMemberState, Roster,
+    FATIGUE_PER_MISSION, RIVAL_STRESS,
+};
 use crate::missions::Mission;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
 
+mod level_up;
+mod performance;
+
+use level_up::LevelUpPick;
+use performance::Performance;
+
+/// Share of the rewards paid when a mission's victory condition is not fully met
+const PARTIAL_REWARD_PERCENT: i32 = 50;
+
 /// Post-mission results state
 pub struct ResultState {
     pub victory: bool,
@@ -20,13 +32,31 @@ pub struct ResultState {
     pub reward_supplies: i32,
     pub reward_knowledge: i32,
     pub reward_influence: i32,
-    /// All party member IDs and their final states (for future party-wide results)
-    #[allow(dead_code)]
+    /// Every party member's final state, each applied back to the roster
     pub party_member_states: Vec<PartyMemberState>,
     /// Final HP after mission (if set, overrides hp_lost calculation)
     pub final_hp: Option<i32>,
     /// Final stress after mission
     pub final_stress: Option<i32>,
+    /// Consequences rolled when the results were applied (traumas, breakthroughs, events)
+    pub consequences: Vec<String>,
+    /// Results are applied once, on the first frame this screen is shown
+    pub applied: bool,
+    /// New traumas and injuries, drained by `Game` into toasts
+    pub notifications: Vec<String>,
+    /// Kingdom stats captured just before and after the results were applied
+    pub stats_before: Option<KingdomStats>,
+    pub stats_after: Option<KingdomStats>,
+    /// Grade of a won mission; scales gold, supplies and knowledge
+    pub performance: Option<Performance>,
+    /// How a won mission fell short of its victory condition, making it a partial success
+    pub shortfall: Option<String>,
+    /// Region a win steadies, and by how much threat
+    pub region_stabilization: Option<(String, i32)>,
+    /// Region name with its threat before and after, once a win has changed it
+    pub region_threat: Option<(String, i32, i32)>,
+    /// New-card choices owed to members who levelled up, answered one at a time
+    pub level_ups: Vec<LevelUpPick>,
 }
 
 impl Default for ResultState {
@@ -36,57 +66,70 @@ impl Default for ResultState {
 }
 
 impl ResultState {
-    pub fn victory_for(adventurer_id: &str) -> Self {
-        Self {
-            victory: true,
-            stress_gained: 5,
-            hp_lost: 0,
-            injuries: vec![],
-            rewards: vec![
+    /// A plain win or loss with the default stakes; every other constructor starts here,
+    /// so a new field is set in this one place
+    fn base(
+        victory: bool,
+        adventurer_id: String,
+        party_member_states: Vec<PartyMemberState>,
+    ) -> Self {
+        let (stress_gained, hp_lost, injuries, rewards) = if victory {
+            let rewards = vec![
                 "20 Gold".to_string(),
                 "10 Supplies".to_string(),
                 "+5 Knowledge".to_string(),
-            ],
-            adventurer_id: adventurer_id.to_string(),
+            ];
+            (5, 0, vec![], rewards)
+        } else {
+            (15, 20, vec!["Wounded Leg".to_string()], vec![])
+        };
+        let (reward_gold, reward_supplies, reward_knowledge) =
+            if victory { (20, 10, 5) } else { (0, 0, 0) };
+        Self {
+            victory,
+            stress_gained,
+            hp_lost,
+            injuries,
+            rewards,
+            adventurer_id,
             mission_id: None,
             mission_difficulty: 1,
-            reward_gold: 20,
-            reward_supplies: 10,
-            reward_knowledge: 5,
+            reward_gold,
+            reward_supplies,
+            reward_knowledge,
             reward_influence: 0,
-            party_member_states: vec![],
+            party_member_states,
             final_hp: None,
             final_stress: None,
+            consequences: vec![],
+            applied: false,
+            notifications: vec![],
+            stats_before: None,
+            stats_after: None,
+            performance: None,
+            shortfall: None,
+            region_stabilization: None,
+            region_threat: None,
+            level_ups: vec![],
         }
     }
 
-    /// Create victory result for a full party
-    pub fn victory_for_party(party_members: &[PartyMemberState]) -> Self {
+    /// A result for a whole party, led by its first member
+    fn for_party(victory: bool, party_members: &[PartyMemberState]) -> Self {
         let adventurer_id = party_members
             .first()
             .map(|m| m.id.clone())
             .unwrap_or_default();
-        Self {
-            victory: true,
-            stress_gained: 5,
-            hp_lost: 0,
-            injuries: vec![],
-            rewards: vec![
-                "20 Gold".to_string(),
-                "10 Supplies".to_string(),
-                "+5 Knowledge".to_string(),
-            ],
-            adventurer_id,
-            mission_id: None,
-            mission_difficulty: 1,
-            reward_gold: 20,
-            reward_supplies: 10,
-            reward_knowledge: 5,
-            reward_influence: 0,
-            party_member_states: party_members.to_vec(),
-            final_hp: None,
-            final_stress: None,
-        }
+        Self::base(victory, adventurer_id, party_members.to_vec())
+    }
+
+    pub fn victory_for(adventurer_id: &str) -> Self {
+        Self::base(true, adventurer_id.to_string(), vec![])
+    }
+
+    /// Create victory result for a full party
+    pub fn victory_for_party(party_members: &[PartyMemberState]) -> Self {
+        Self::for_party(true, party_members)
     }
 
     pub fn victory_for_mission(mission: &Mission, party_members: &[PartyMemberState]) -> Self {
@@ -94,66 +137,64 @@ impl ResultState {
         result.mission_id = Some(mission.id.clone());
         result.mission_difficulty = mission.difficulty;
         result.stress_gained = mission.base_stress;
-        result.reward_gold = mission.reward_gold;
-        result.reward_supplies = mission.reward_supplies;
-        result.reward_knowledge = mission.reward_knowledge;
-        result.reward_influence = mission.reward_influence;
-        result.rewards = vec![
-            format!("{} Gold", mission.reward_gold),
-            format!("{} Supplies", mission.reward_supplies),
-            format!("{} Knowledge", mission.reward_knowledge),
-        ];
-        if mission.reward_influence > 0 {
-            result
-                .rewards
-                .push(format!("{} Influence", mission.reward_influence));
+        let balance = crate::data::balance();
+        let performance = Performance::grade(party_members);
+        result.performance = performance;
+        let scale = |reward| match performance {
+            Some(performance) => performance.scale(balance.mission_reward(reward)),
+            None => balance.mission_reward(reward),
+        };
+        result.reward_gold = scale(mission.reward_gold);
+        result.reward_supplies = scale(mission.reward_supplies);
+        result.reward_knowledge = scale(mission.reward_knowledge);
+        result.reward_influence = balance.mission_reward(mission.reward_influence);
+        result.list_rewards();
+        let stabilization = mission.stabilization();
+        if stabilization > 0 {
+            result.region_stabilization = Some((mission.region_id.clone(), stabilization));
         }
         result
     }
 
-    pub fn defeat_for(adventurer_id: &str) -> Self {
-        Self {
-            victory: false,
-            stress_gained: 15,
-            hp_lost: 20,
-            injuries: vec!["Wounded Leg".to_string()],
-            rewards: vec![],
-            adventurer_id: adventurer_id.to_string(),
-            mission_id: None,
-            mission_difficulty: 1,
-            reward_gold: 0,
-            reward_supplies: 0,
-            reward_knowledge: 0,
-            reward_influence: 0,
-            party_member_states: vec![],
-            final_hp: None,
-            final_stress: None,
+    /// Mark a won mission as a partial success, cutting its rewards; None leaves it whole
+    pub fn with_shortfall(mut self, shortfall: Option<String>) -> Self {
+        if shortfall.is_some() {
+            for reward in [
+                &mut self.reward_gold,
+                &mut self.reward_supplies,
+                &mut self.reward_knowledge,
+                &mut self.reward_influence,
+            ] {
+                *reward = *reward * PARTIAL_REWARD_PERCENT / 100;
+            }
+            if let Some((_, amount)) = &mut self.region_stabilization {
+                *amount = *amount * PARTIAL_REWARD_PERCENT / 100;
+            }
+            self.list_rewards();
+        }
+        self.shortfall = shortfall;
+        self
+    }
+
+    fn list_rewards(&mut self) {
+        self.rewards = vec![
+            format!("{} Gold", self.reward_gold),
+            format!("{} Supplies", self.reward_supplies),
+            format!("{} Knowledge", self.reward_knowledge),
+        ];
+        if self.reward_influence > 0 {
+            self.rewards
+                .push(format!("{} Influence", self.reward_influence));
         }
     }
 
+    pub fn defeat_for(adventurer_id: &str) -> Self {
+        Self::base(false, adventurer_id.to_string(), vec![])
+    }
+
     /// Create defeat result for a full party
     pub fn defeat_for_party(party_members: &[PartyMemberState]) -> Self {
-        let adventurer_id = party_members
-            .first()
-            .map(|m| m.id.clone())
-            .unwrap_or_default();
-        Self {
-            victory: false,
-            stress_gained: 15,
-            hp_lost: 20,
-            injuries: vec!["Wounded Leg".to_string()],
-            rewards: vec![],
-            adventurer_id,
-            mission_id: None,
-            mission_difficulty: 1,
-            reward_gold: 0,
-            reward_supplies: 0,
-            reward_knowledge: 0,
-            reward_influence: 0,
-            party_member_states: party_members.to_vec(),
-            final_hp: None,
-            final_stress: None,
-        }
+        Self::for_party(false, party_members)
     }
 
     pub fn defeat_for_mission(mission: &Mission, party_members: &[PartyMemberState]) -> Self {
@@ -169,45 +210,99 @@ impl ResultState {
         kingdom: &mut KingdomState,
         roster: &mut Roster,
     ) -> Option<StateTransition> {
-        if is_key_pressed(KeyCode::Enter) {
-            // Apply consequences to kingdom
-            if self.victory {
-                kingdom.stats.gold += self.reward_gold;
-                kingdom.stats.supplies += self.reward_supplies;
-                kingdom.stats.knowledge += self.reward_knowledge;
-                kingdom.stats.influence += self.reward_influence;
-                kingdom.stats.security = (kingdom.stats.security + 3).min(100);
-                if let Some(mission_id) = &self.mission_id {
-                    kingdom.record_mission_complete(mission_id);
-                }
-            } else {
-                kingdom.stats.morale = (kingdom.stats.morale - 10).max(0);
-                kingdom.stats.security = (kingdom.stats.security - 5).max(0);
-            }
+        if !self.applied {
+            self.stats_before = Some(kingdom.stats.clone());
+            let afflictions_before = affliction_counts(roster);
+            let levels_before = level_up::levels(roster);
+            self.apply_results(kingdom, roster);
+            self.notifications =
+                new_affliction_toasts(&afflictions_before, &self.party_member_states, roster);
+            self.level_ups = level_up::picks_for(&levels_before, roster);
+            self.stats_after = Some(kingdom.stats.clone());
+            self.applied = true;
+        }
 
-            self.apply_roster_results(roster);
-            kingdom.day += 1;
-            kingdom.advance_threat(self.victory);
-            kingdom.last_event = self.roll_kingdom_event(kingdom, roster);
+        if !self.level_ups.is_empty() {
+            self.update_level_up(roster);
+            return None;
+        }
 
+        if is_key_pressed(KeyCode::Enter) {
+            // The new day may bring a decision before the base resumes
+            if let Some(event) = KingdomEventState::roll(kingdom, roster) {
+                return Some(StateTransition::ToKingdomEvent(event));
+            }
             return Some(StateTransition::ToBase);
         }
 
         None
     }
 
-    fn apply_roster_results(&self, roster: &mut Roster) {
-        if self.party_member_states.is_empty() {
-            self.apply_single_adventurer(roster);
+    /// Apply consequences to the kingdom and roster, recording what happened
+    fn apply_results(&mut self, kingdom: &mut KingdomState, roster: &mut Roster) {
+        if self.victory {
+            kingdom.stats.add_gold(self.reward_gold);
+            kingdom.stats.add_supplies(self.reward_supplies);
+            kingdom.stats.add_knowledge(self.reward_knowledge);
+            kingdom.stats.add_influence(self.reward_influence);
+            kingdom.stats.add_security(3);
+            if let Some(mission_id) = &self.mission_id {
+                kingdom.record_mission_complete(mission_id);
+            }
+        } else {
+            kingdom.stats.add_morale(-10);
+            kingdom.stats.add_security(-5);
+        }
+
+        self.consequences = self.apply_roster_results(roster);
+        let bonds = self.record_affinities(roster);
+        self.consequences.extend(bonds);
+        let day = kingdom.advance_day(roster);
+        self.consequences.extend(day);
+        // After the day's drift, so the results screen shows where the region now stands
+        if self.victory {
+            self.stabilize_region(kingdom);
+        }
+        kingdom.advance_threat(self.victory);
+        kingdom.last_event = self.roll_kingdom_event(kingdom, roster);
+        if let Some(event) = &kingdom.last_event {
+            self.consequences.push(event.clone());
+        }
+    }
+
+    /// Lower the mission region's threat, recording the change for the results screen
+    fn stabilize_region(&mut self, kingdom: &mut KingdomState) {
+        let Some((region_id, amount)) = &self.region_stabilization else {
             return;
+        };
+        if let Some(region) = kingdom.region_mut(region_id) {
+            let before = region.threat_level;
+            region.stabilize(*amount);
+            if region.threat_level != before {
+                self.region_threat = Some((region.name.clone(), before, region.threat_level));
+            }
+        }
+    }
+
+    fn apply_roster_results(&self, roster: &mut Roster) -> Vec<String> {
+        if self.party_member_states.is_empty() {
+            return self.apply_single_adventurer(roster);
         }
 
+        let survivors = self.survivor_ids();
+        let mut messages = Vec::new();
         for state in &self.party_member_states {
             if state.hp <= 0 {
                 roster.record_death(&state.id);
+                messages.push(format!(
+                    "{} did not come home; {}",
+                    state.name,
+                    fill_pronouns("{their} name joins the fallen", Some(&state.gender))
+                ));
                 continue;
             }
 
+            let rivals = roster.rival_count(&state.id, &survivors);
             if let Some(adv) = roster.get_mut(&state.id) {
                 adv.hp = state.hp.max(1).min(adv.max_hp);
                 if state.resolve_state.is_some() {
@@ -227,39 +322,71 @@ impl ResultState {
                 if stress_delta < 0 {
                     adv.reduce_stress(-stress_delta);
                 }
-                let total_stress_gain = stress_delta.max(0) + self.stress_gained;
+                let rival_stress = RIVAL_STRESS * rivals as i32;
+                if rival_stress > 0 {
+                    messages.push(format!("{} chafed at sharing the road", adv.name));
+                }
+                let total_stress_gain = stress_delta.max(0) + self.stress_gained + rival_stress;
                 if total_stress_gain > 0 {
-                    adv.apply_stress_gain(total_stress_gain);
+                    messages.extend(adv.apply_stress_gain(total_stress_gain));
                 }
 
                 if adv.hp <= adv.max_hp / 3 && !adv.injuries.iter().any(|i| i.id == "wounded_leg") {
                     adv.injuries.push(Injury::wounded_leg());
                 }
 
+                for card_id in &state.deck_additions {
+                    if !adv.deck_additions.contains(card_id) {
+                        adv.deck_additions.push(card_id.clone());
+                    }
+                }
+                adv.kills += state.kills;
+                let mut xp = state.xp_earned;
+                adv.add_fatigue(FATIGUE_PER_MISSION);
                 if self.victory {
                     adv.missions_completed += 1;
-                    adv.xp += 10 + (self.mission_difficulty * 2);
-                    let needed = adv.level * 20;
-                    if adv.xp >= needed {
-                        adv.xp -= needed;
-                        adv.level += 1;
-                        adv.max_hp += 3;
-                        adv.hp = (adv.hp + 3).min(adv.max_hp);
-                    }
+                    let balance = crate::data::balance();
+                    xp += balance.victory_xp_base
+                        + self.mission_difficulty * balance.victory_xp_per_difficulty;
                 } else if !adv.injuries.iter().any(|i| i.id == "broken_arm") {
                     adv.injuries.push(Injury::broken_arm());
                 }
+                if adv.gain_xp(xp) > 0 {
+                    messages.push(format!("{} reached level {}", adv.name, adv.level));
+                }
+                messages.extend(adv.update_epithet());
             }
         }
+        messages
+    }
+
+    /// Party members who came home
+    fn survivor_ids(&self) -> Vec<&str> {
+        self.party_member_states
+            .iter()
+            .filter(|state| state.hp > 0)
+            .map(|state| state.id.as_str())
+            .collect()
+    }
+
+    /// Survivors grow closer, or drift apart over the partners they lost
+    fn record_affinities(&self, roster: &mut Roster) -> Vec<String> {
+        let fallen = self
+            .party_member_states
+            .iter()
+            .filter(|state| state.hp <= 0)
+            .count();
+        roster.record_shared_mission(&self.survivor_ids(), fallen)
     }
 
-    fn apply_single_adventurer(&self, roster: &mut Roster) {
+    fn apply_single_adventurer(&self, roster: &mut Roster) -> Vec<String> {
         let is_dead = self.final_hp.map_or(false, |final_hp| final_hp <= 0);
         if is_dead {
             roster.record_death(&self.adventurer_id);
-            return;
+            return vec![];
         }
 
+        let mut messages = Vec::new();
         if let Some(adv) = roster.get_mut(&self.adventurer_id) {
             if let Some(final_hp) = self.final_hp {
                 adv.hp = final_hp.max(1);
@@ -270,18 +397,21 @@ impl ResultState {
             if let Some(final_stress) = self.final_stress {
                 let delta = final_stress - adv.stress;
                 if delta >= 0 {
-                    adv.apply_stress_gain(delta);
+                    messages.extend(adv.apply_stress_gain(delta));
                 } else {
                     adv.reduce_stress(-delta);
                 }
             } else {
-                adv.apply_stress_gain(self.stress_gained);
+                messages.extend(adv.apply_stress_gain(self.stress_gained));
             }
 
+            adv.add_fatigue(FATIGUE_PER_MISSION);
             if self.victory {
                 adv.missions_completed += 1;
             }
+            messages.extend(adv.update_epithet());
         }
+        messages
     }
 
     fn roll_kingdom_event(
@@ -295,7 +425,7 @@ impl ResultState {
 
         match macroquad_toolkit::rng::gen_range(0, 4) {
             0 => {
-                kingdom.stats.morale = (kingdom.stats.morale - 6).max(0);
+                kingdom.stats.add_morale(-6);
                 if let Some(adv) = roster.adventurers.first_mut() {
                     adv.apply_stress_gain(6);
                 }
@@ -303,24 +433,23 @@ impl ResultState {
             }
             1 => {
                 let stolen = kingdom.stats.gold.min(25);
-                kingdom.stats.gold -= stolen;
+                kingdom.stats.add_gold(-stolen);
                 Some(format!(
                     "Thieves: {} gold was stolen from the stores.",
                     stolen
                 ))
             }
             2 => {
-                if kingdom.stats.gold >= 15 {
-                    kingdom.stats.gold -= 15;
-                    kingdom.stats.supplies += 25;
+                if kingdom.stats.spend_gold(15) {
+                    kingdom.stats.add_supplies(25);
                     Some("Traders: paid 15 gold for 25 supplies.".to_string())
                 } else {
-                    kingdom.stats.gold += 10;
+                    kingdom.stats.add_gold(10);
                     Some("Traders: a small debt was forgiven for 10 gold.".to_string())
                 }
             }
             _ => {
-                kingdom.stats.morale = (kingdom.stats.morale + 8).min(100);
+                kingdom.stats.add_morale(8);
                 for adv in &mut roster.adventurers {
                     adv.reduce_stress(3);
                 }
@@ -334,20 +463,58 @@ impl ResultState {
 
         let title = if is_dead {
             "FALLEN IN BATTLE"
+        } else if self.shortfall.is_some() {
+            "PARTIAL SUCCESS"
         } else if self.victory {
             "MISSION COMPLETE"
         } else {
             "MISSION FAILED"
         };
-        let title_color = if self.victory { GREEN } else { RED };
+        let title_color = if self.shortfall.is_some() {
+            ORANGE
+        } else if self.victory {
+            GREEN
+        } else {
+            RED
+        };
 
         draw_ui_text(title, 20.0, 60.0, 36.0, title_color);
+        if let Some(leader) = self.party_member_states.first().filter(|_| !is_dead) {
+            let line = if self.victory {
+                "brought {their} party home"
+            } else {
+                "led {their} party in retreat"
+            };
+            draw_ui_text(
+                &format!("{} {}", leader.name, self.for_leader(line)),
+                20.0,
+                92.0,
+                18.0,
+                LIGHTGRAY,
+            );
+        }
 
         let mut y = 120.0;
+        if let Some(shortfall) = &self.shortfall {
+            draw_ui_text(
+                &format!("{} - rewards halved", shortfall),
+                20.0,
+                y,
+                18.0,
+                ORANGE,
+            );
+            y += 30.0;
+        }
 
         if is_dead {
             draw_ui_text("The adventurer has perished.", 20.0, y, 24.0, RED);
-            draw_ui_text("Their name will be remembered.", 20.0, y + 30.0, 20.0, GRAY);
+            draw_ui_text(
+                &self.for_leader("{Their} name will be remembered."),
+                20.0,
+                y + 30.0,
+                20.0,
+                GRAY,
+            );
             draw_ui_text(
                 "[ENTER] Return to Kingdom",
                 20.0,
@@ -400,10 +567,37 @@ impl ResultState {
         if !self.rewards.is_empty() {
             draw_ui_text("Rewards:", 20.0, y, 20.0, GREEN);
             y += 25.0;
+            if let Some(performance) = self.performance {
+                draw_ui_text(
+                    &format!("  {}", performance.label()),
+                    20.0,
+                    y,
+                    18.0,
+                    performance.color(),
+                );
+                y += 22.0;
+            }
             for reward in &self.rewards {
                 draw_ui_text(&format!("  + {}", reward), 20.0, y, 18.0, LIME);
                 y += 22.0;
             }
+            y += 10.0;
+        }
+
+        if !self.consequences.is_empty() {
+            draw_ui_text("Consequences:", 20.0, y, 20.0, ORANGE);
+            y += 25.0;
+            for line in &self.consequences {
+                draw_ui_text(&format!("  * {}", line), 20.0, y, 18.0, LIGHTGRAY);
+                y += 22.0;
+            }
+        }
+
+        self.draw_stat_deltas();
+
+        if let Some(pick) = self.level_ups.first() {
+            pick.draw();
+            return;
         }
 
         draw_ui_text(
@@ -414,4 +608,127 @@ impl ResultState {
             GREEN,
         );
     }
+
+    /// Fill pronoun tokens for the party leader
+    fn for_leader(&self, text: &str) -> String {
+        let gender = self
+            .party_member_states
+            .first()
+            .map(|leader| &leader.gender);
+        fill_pronouns(text, gender)
+    }
+
+    /// Before -> after kingdom stats, so rewards and losses read in context
+    fn draw_stat_deltas(&self) {
+        let (Some(before), Some(after)) = (&self.stats_before, &self.stats_after) else {
+            return;
+        };
+
+        let x = screen_width() / 2.0 + 40.0;
+        let mut y = 120.0;
+        draw_ui_text("Kingdom:", x, y, 20.0, GOLD);
+        y += 25.0;
+        for (label, old, new) in [
+            ("Gold", before.gold, after.gold),
+            ("Supplies", before.supplies, after.supplies),
+            ("Knowledge", before.knowledge, after.knowledge),
+            ("Influence", before.influence, after.influence),
+            ("Morale", before.morale, after.morale),
+        ] {
+            let color = match new.cmp(&old) {
+                std::cmp::Ordering::Greater => LIME,
+                std::cmp::Ordering::Less => PINK,
+                std::cmp::Ordering::Equal => LIGHTGRAY,
+            };
+            draw_ui_text(
+                &format!("  {}: {} → {}", label, old, new),
+                x,
+                y,
+                18.0,
+                color,
+            );
+            y += 22.0;
+        }
+        if let Some((region, old, new)) = &self.region_threat {
+            draw_ui_text(
+                &format!("{} threat: {} → {}", region, old, new),
+                x,
+                y + 8.0,
+                18.0,
+                LIME,
+            );
+        }
+    }
+}
+
+/// Trauma and injury counts per adventurer id
+fn affliction_counts(roster: &Roster) -> Vec<(String, usize, usize)> {
+    roster
+        .adventurers
+        .iter()
+        .map(|adv| (adv.id.clone(), adv.traumas.len(), adv.injuries.len()))
+        .collect()
+}
+
+/// Toasts for every trauma or injury gained since the snapshot.
+/// Traumas carried back from combat were already announced mid-fight.
+fn new_affliction_toasts(
+    before: &[(String, usize, usize)],
+    party: &[PartyMemberState],
+    roster: &Roster,
+) -> Vec<String> {
+    let mut toasts = Vec::new();
+    for adv in &roster.adventurers {
+        let (traumas, injuries) = before
+            .iter()
+            .find(|(id, _, _)| *id == adv.id)
+            .map(|(_, traumas, injuries)| (*traumas, *injuries))
+            .unwrap_or((0, 0));
+        let announced = party.iter().find(|member| member.id == adv.id);
+        for trauma in adv.traumas.iter().skip(traumas) {
+            if announced.is_some_and(|member| {
+                member
+                    .traumas
+                    .iter()
+                    .any(|t| t.trauma_type == trauma.trauma_type)
+            }) {
+                continue;
+            }
+            toasts.push(format!("{} is now {}", adv.name, trauma.name()));
+        }
+        for injury in adv.injuries.iter().skip(injuries) {
+            toasts.push(format!("{} suffered a {}", adv.name, injury.name));
+        }
+    }
+    toasts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{Adventurer, AdventurerClass, Gender};
+
+    #[test]
+    fn every_member_carries_their_outcome_home() {
+        let mut roster = Roster::new();
+        for name in ["Ada", "Bram", "Cole"] {
+            let adv = Adventurer::new(name, AdventurerClass::Soldier, Gender::Female);
+            roster.adventurers.push(adv);
+        }
+        let party: Vec<PartyMemberState> = roster
+            .adventurers
+            .iter()
+            .zip([30, 0, 12])
+            .map(|(adv, hp)| PartyMemberState {
+                hp,
+                ..PartyMemberState::from_adventurer(adv)
+            })
+            .collect();
+
+        ResultState::defeat_for_party(&party).apply_roster_results(&mut roster);
+        assert_eq!(roster.get(&party[0].id).map(|a| a.hp), Some(30));
+        assert_eq!(roster.get(&party[2].id).map(|a| a.hp), Some(12));
+        assert!(roster.get(&party[1].id).is_none());
+        assert_eq!(roster.fallen_count(), 1);
+    }
 }
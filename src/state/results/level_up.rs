@@ -0,0 +1,172 @@
+//! Level-up picks - each member who levels up chooses a new card for their deck
+
+use super::ResultState;
+use crate::combat::knows_card;
+use crate::data::cards::CardData;
+use crate::kingdom::Roster;
+use macroquad::prelude::*;
+use macroquad_toolkit::rng;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Cards offered with each level-up
+const PICK_OPTIONS: usize = 3;
+
+/// A levelled-up member and the cards they may choose between
+#[derive(Clone, Debug)]
+pub struct LevelUpPick {
+    pub adventurer_id: String,
+    pub name: String,
+    pub level: i32,
+    /// Offered cards as (id, name)
+    pub options: Vec<(String, String)>,
+}
+
+impl LevelUpPick {
+    pub fn draw(&self) {
+        let (w, h) = (460.0, 150.0 + 28.0 * self.options.len() as f32);
+        let x = (screen_width() - w) / 2.0;
+        let y = (screen_height() - h) / 2.0;
+        draw_rectangle(x, y, w, h, Color::from_rgba(18, 16, 14, 240));
+        draw_rectangle_lines(x, y, w, h, 2.0, GOLD);
+        draw_ui_text(
+            &format!("{} reached level {}!", self.name, self.level),
+            x + 20.0,
+            y + 40.0,
+            24.0,
+            GOLD,
+        );
+        draw_ui_text(
+            "Choose a card to learn:",
+            x + 20.0,
+            y + 72.0,
+            18.0,
+            LIGHTGRAY,
+        );
+        let mut line_y = y + 104.0;
+        for (i, (_, name)) in self.options.iter().enumerate() {
+            draw_ui_text(
+                &format!("[{}] {}", i + 1, name),
+                x + 32.0,
+                line_y,
+                20.0,
+                WHITE,
+            );
+            line_y += 28.0;
+        }
+        draw_ui_text("[ESC] Skip", x + 20.0, y + h - 20.0, 16.0, GRAY);
+    }
+}
+
+/// Every adventurer's level, keyed by id, to spot level-ups once results are applied
+pub(super) fn levels(roster: &Roster) -> Vec<(String, i32)> {
+    roster
+        .adventurers
+        .iter()
+        .map(|adv| (adv.id.clone(), adv.level))
+        .collect()
+}
+
+/// One pick for each adventurer whose level rose, if any card is left for them to learn
+pub(super) fn picks_for(before: &[(String, i32)], roster: &Roster) -> Vec<LevelUpPick> {
+    let cards = CardData::load_all().unwrap_or_default();
+    roster
+        .adventurers
+        .iter()
+        .filter(|adv| {
+            before
+                .iter()
+                .any(|(id, level)| *id == adv.id && adv.level > *level)
+        })
+        .filter_map(|adv| {
+            let class_name = format!("{:?}", adv.class);
+            let mut candidates: Vec<&CardData> = cards
+                .iter()
+                .filter(|card| {
+                    card.class_matches(&class_name)
+                        && card.is_unlockable()
+                        && !knows_card(&adv.deck_additions, &card.id)
+                })
+                .collect();
+            let mut options = Vec::new();
+            while options.len() < PICK_OPTIONS && !candidates.is_empty() {
+                let card = candidates.remove(rng::gen_range(0, candidates.len()));
+                options.push((card.id.clone(), card.name.clone()));
+            }
+            (!options.is_empty()).then(|| LevelUpPick {
+                adventurer_id: adv.id.clone(),
+                name: adv.name.clone(),
+                level: adv.level,
+                options,
+            })
+        })
+        .collect()
+}
+
+impl ResultState {
+    /// Number keys learn an offered card, Escape passes; either moves to the next pick
+    pub(super) fn update_level_up(&mut self, roster: &mut Roster) {
+        let keys = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3];
+        let chosen = keys.iter().position(|key| is_key_pressed(*key));
+        if let Some(choice) = chosen {
+            if !self.learn_level_up_card(roster, choice) {
+                return;
+            }
+        } else if is_key_pressed(KeyCode::Escape) {
+            self.level_ups.remove(0);
+        }
+    }
+
+    /// Add the chosen card to the first pending member's deck; false if no such option
+    fn learn_level_up_card(&mut self, roster: &mut Roster, choice: usize) -> bool {
+        let Some(pick) = self.level_ups.first() else {
+            return false;
+        };
+        let Some((card_id, _)) = pick.options.get(choice) else {
+            return false;
+        };
+        if let Some(adv) = roster.get_mut(&pick.adventurer_id) {
+            if !adv.deck_additions.contains(card_id) {
+                adv.deck_additions.push(card_id.clone());
+            }
+        }
+        self.level_ups.remove(0);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{Adventurer, AdventurerClass, Gender};
+
+    #[test]
+    fn surplus_xp_rolls_into_further_levels() {
+        let mut adv = Adventurer::new("Ada", AdventurerClass::Soldier, Gender::Female);
+        let (level, max_hp) = (adv.level, adv.max_hp);
+        let needed = level * 20 + (level + 1) * 20;
+        assert_eq!(adv.gain_xp(needed + 5), 2);
+        assert_eq!((adv.level, adv.max_hp, adv.xp), (level + 2, max_hp + 6, 5));
+    }
+
+    #[test]
+    fn levelled_members_pick_a_card_to_learn() {
+        let mut roster = Roster::new();
+        roster.adventurers.push(Adventurer::new(
+            "Ada",
+            AdventurerClass::Soldier,
+            Gender::Female,
+        ));
+        let before = levels(&roster);
+        assert!(picks_for(&before, &roster).is_empty());
+
+        roster.adventurers[0].gain_xp(1000);
+        let mut results = ResultState {
+            level_ups: picks_for(&before, &roster),
+            ..Default::default()
+        };
+        let card_id = results.level_ups[0].options[0].0.clone();
+        assert!(results.learn_level_up_card(&mut roster, 0));
+        assert!(results.level_ups.is_empty());
+        assert!(roster.adventurers[0].deck_additions.contains(&card_id));
+    }
+}
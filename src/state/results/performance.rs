@@ -0,0 +1,120 @@
+//! Mission performance - how cleanly a victory was won, and what it adds to the spoils
+
+use crate::kingdom::PartyMemberState;
+use macroquad::prelude::*;
+
+/// Average remaining HP (as a share of max) needed for each grade
+const FLAWLESS_HP: f32 = 0.9;
+const STEADY_HP: f32 = 0.5;
+
+/// Grade of a won mission, from the party's final health and losses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Performance {
+    /// Everyone home and barely scratched
+    Flawless,
+    /// Everyone home in fair shape
+    Steady,
+    /// Everyone home, but only just
+    Scraped,
+    /// Someone did not come back
+    Costly,
+}
+
+impl Performance {
+    /// Grade a party's final states; None when there is no party to judge
+    pub fn grade(party: &[PartyMemberState]) -> Option<Self> {
+        if party.is_empty() {
+            return None;
+        }
+        if party.iter().any(|member| member.hp <= 0) {
+            return Some(Performance::Costly);
+        }
+        let average_hp = party
+            .iter()
+            .map(|member| (member.hp as f32 / member.max_hp.max(1) as f32).min(1.0))
+            .sum::<f32>()
+            / party.len() as f32;
+        Some(if average_hp >= FLAWLESS_HP {
+            Performance::Flawless
+        } else if average_hp >= STEADY_HP {
+            Performance::Steady
+        } else {
+            Performance::Scraped
+        })
+    }
+
+    pub fn reward_percent(self) -> i32 {
+        match self {
+            Performance::Flawless => 150,
+            Performance::Steady => 125,
+            Performance::Scraped => 100,
+            Performance::Costly => 75,
+        }
+    }
+
+    pub fn scale(self, reward: i32) -> i32 {
+        reward * self.reward_percent() / 100
+    }
+
+    /// Results screen line, e.g. "Flawless! x1.5 rewards"
+    pub fn label(self) -> String {
+        let name = match self {
+            Performance::Flawless => "Flawless!",
+            Performance::Steady => "Well fought",
+            Performance::Scraped => "Scraped through",
+            Performance::Costly => "A costly victory",
+        };
+        format!("{} x{} rewards", name, self.reward_percent() as f32 / 100.0)
+    }
+
+    pub fn color(self) -> Color {
+        match self.reward_percent() {
+            p if p > 100 => GOLD,
+            100 => LIGHTGRAY,
+            _ => ORANGE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kingdom::{Adventurer, AdventurerClass, Gender};
+
+    fn member(hp: i32) -> PartyMemberState {
+        let adv = Adventurer::new("Test", AdventurerClass::Soldier, Gender::Female);
+        PartyMemberState {
+            hp,
+            max_hp: 40,
+            ..PartyMemberState::from_adventurer(&adv)
+        }
+    }
+
+    #[test]
+    fn grades_follow_health_and_losses() {
+        assert_eq!(
+            Performance::grade(&[member(40), member(38)]),
+            Some(Performance::Flawless)
+        );
+        assert_eq!(
+            Performance::grade(&[member(40), member(10)]),
+            Some(Performance::Steady)
+        );
+        assert_eq!(
+            Performance::grade(&[member(8), member(10)]),
+            Some(Performance::Scraped)
+        );
+        assert_eq!(
+            Performance::grade(&[member(40), member(0)]),
+            Some(Performance::Costly)
+        );
+        assert_eq!(Performance::grade(&[]), None);
+    }
+
+    #[test]
+    fn flawless_pays_half_again() {
+        assert_eq!(Performance::Flawless.scale(40), 60);
+        assert_eq!(Performance::Flawless.label(), "Flawless! x1.5 rewards");
+        assert_eq!(Performance::Costly.scale(40), 30);
+    }
+}
@@ -0,0 +1,148 @@
+//! Settings screen - flip saved preferences without leaving the game
+
+use super::StateTransition;
+use crate::save::Settings;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// One preference listed on the settings screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SettingRow {
+    Colorblind,
+    Difficulty,
+    SkipTraumaPause,
+    DumpCombatLog,
+    BinarySaves,
+    SortHandByCost,
+    EliteEnergyBonus,
+}
+
+impl SettingRow {
+    const ALL: [SettingRow; 7] = [
+        SettingRow::Colorblind,
+        SettingRow::Difficulty,
+        SettingRow::SkipTraumaPause,
+        SettingRow::DumpCombatLog,
+        SettingRow::BinarySaves,
+        SettingRow::SortHandByCost,
+        SettingRow::EliteEnergyBonus,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SettingRow::Colorblind => "Colorblind mode",
+            SettingRow::Difficulty => "New kingdoms start on",
+            SettingRow::SkipTraumaPause => "Skip the mid-fight trauma pause",
+            SettingRow::DumpCombatLog => "Write combat logs to disk",
+            SettingRow::BinarySaves => "Compressed binary saves",
+            SettingRow::SortHandByCost => "Sort the combat hand by cost",
+            SettingRow::EliteEnergyBonus => "Extra energy against elites",
+        }
+    }
+
+    fn value(self, settings: &Settings) -> String {
+        let on_off = |on: bool| if on { "On" } else { "Off" }.to_string();
+        match self {
+            SettingRow::Colorblind => on_off(settings.colorblind),
+            SettingRow::Difficulty => settings.difficulty.label().to_string(),
+            SettingRow::SkipTraumaPause => on_off(settings.skip_trauma_pause),
+            SettingRow::DumpCombatLog => on_off(settings.dump_combat_log),
+            SettingRow::BinarySaves if !cfg!(feature = "binary-saves") => {
+                format!("{} (not in this build)", on_off(settings.binary_saves))
+            }
+            SettingRow::BinarySaves => on_off(settings.binary_saves),
+            SettingRow::SortHandByCost => on_off(settings.sort_hand_by_cost),
+            SettingRow::EliteEnergyBonus => on_off(settings.elite_energy_bonus),
+        }
+    }
+
+    fn toggle(self, settings: &mut Settings) {
+        match self {
+            SettingRow::Colorblind => settings.colorblind = !settings.colorblind,
+            SettingRow::Difficulty => settings.difficulty = settings.difficulty.next(),
+            SettingRow::SkipTraumaPause => settings.skip_trauma_pause = !settings.skip_trauma_pause,
+            SettingRow::DumpCombatLog => settings.dump_combat_log = !settings.dump_combat_log,
+            SettingRow::BinarySaves => settings.binary_saves = !settings.binary_saves,
+            SettingRow::SortHandByCost => settings.sort_hand_by_cost = !settings.sort_hand_by_cost,
+            SettingRow::EliteEnergyBonus => {
+                settings.elite_energy_bonus = !settings.elite_energy_bonus
+            }
+        }
+    }
+}
+
+/// Put the preferences that act while the game runs into effect
+pub fn apply_settings(settings: &Settings) {
+    crate::ui::set_colorblind_mode(settings.colorblind);
+}
+
+/// Saved preferences being edited, each change written as soon as it is made
+pub struct SettingsState {
+    settings: Settings,
+    selected: usize,
+    /// Save failures, drained by `Game` into toasts
+    pub notifications: Vec<String>,
+}
+
+impl SettingsState {
+    pub fn load() -> Self {
+        Self {
+            settings: Settings::load(),
+            selected: 0,
+            notifications: vec![],
+        }
+    }
+
+    pub fn update(&mut self) -> Option<StateTransition> {
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            self.selected = (self.selected + 1).min(SettingRow::ALL.len() - 1);
+        }
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+            self.toggle_selected();
+        }
+        if is_key_pressed(KeyCode::Escape) {
+            return Some(StateTransition::ToBase);
+        }
+        None
+    }
+
+    fn toggle_selected(&mut self) {
+        SettingRow::ALL[self.selected].toggle(&mut self.settings);
+        apply_settings(&self.settings);
+        if let Err(e) = self.settings.save() {
+            self.notifications
+                .push(format!("Settings not saved: {}", e));
+        }
+    }
+
+    pub fn draw(&self) {
+        draw_ui_text("SETTINGS", 20.0, 40.0, 32.0, WHITE);
+        for (i, row) in SettingRow::ALL.iter().enumerate() {
+            let y = 100.0 + i as f32 * 34.0;
+            let selected = i == self.selected;
+            if selected {
+                draw_rectangle(
+                    14.0,
+                    y - 22.0,
+                    560.0,
+                    30.0,
+                    Color::from_rgba(60, 70, 80, 255),
+                );
+            }
+            let color = if selected { WHITE } else { GRAY };
+            draw_ui_text(row.label(), 24.0, y, 20.0, color);
+            draw_ui_text(&row.value(&self.settings), 380.0, y, 20.0, GOLD);
+        }
+
+        draw_ui_text(
+            "[↑/↓] Select  [ENTER] Change  [ESC] Back  [F1] Help",
+            20.0,
+            screen_height() - 40.0,
+            20.0,
+            GREEN,
+        );
+    }
+}
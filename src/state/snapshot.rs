@@ -0,0 +1,35 @@
+//! In-progress saves - where a run saved away from the base picks up again
+
+use super::combat::{CombatSnapshot, MissionContext};
+use super::event::EventSnapshot;
+use super::{CombatState, EventState, MissionState, StateTransition};
+use crate::save::Settings;
+use serde::{Deserialize, Serialize};
+
+/// The screen a save was made on, when it was not the base
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum InProgress {
+    /// Walking the mission map
+    Mission(MissionContext),
+    /// Facing an event that has not been answered yet
+    Event(EventSnapshot),
+    /// In a fight, at the active member's turn
+    Combat(Box<CombatSnapshot>),
+}
+
+impl InProgress {
+    /// Transition that puts the player back where the save was made
+    pub fn resume(self) -> StateTransition {
+        match self {
+            InProgress::Mission(ctx) => {
+                StateTransition::ToMission(MissionState::from_snapshot(ctx))
+            }
+            InProgress::Event(snapshot) => {
+                StateTransition::ToEvent(EventState::from_snapshot(snapshot))
+            }
+            InProgress::Combat(snapshot) => StateTransition::ToCombat(Box::new(
+                CombatState::from_snapshot(*snapshot, &Settings::load()),
+            )),
+        }
+    }
+}
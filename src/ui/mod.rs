@@ -1,12 +1,66 @@
 //! UI modules - immediate mode, stateless rendering with mouse support
 
+use crate::kingdom::StatusType;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // Import toolkit utilities
 use macroquad_toolkit::input::{is_hovered_rect, was_clicked_rect, was_pressed_rect};
 pub use macroquad_toolkit::input::{is_mouse_over, was_clicked};
 
+/// Colorblind mode: color-coded indicators also carry symbols and labels
+static COLORBLIND_MODE: AtomicBool = AtomicBool::new(false);
+
+pub fn colorblind_mode() -> bool {
+    COLORBLIND_MODE.load(Ordering::Relaxed)
+}
+
+pub fn set_colorblind_mode(enabled: bool) {
+    COLORBLIND_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Prefix marking a status as harmful or helpful in colorblind mode; empty otherwise
+pub fn status_glyph(status_type: &StatusType) -> &'static str {
+    if !colorblind_mode() {
+        ""
+    } else if status_type.is_debuff() {
+        "▼ "
+    } else {
+        "▲ "
+    }
+}
+
+/// Energy beyond this is only shown as text
+const MAX_ENERGY_PIPS: i32 = 10;
+const ENERGY_PIP_RADIUS: f32 = 6.0;
+const ENERGY_PIP_SPACING: f32 = 16.0;
+
+/// Draw energy as one pip per point, filled while unspent; `y` is the text baseline.
+/// Energy above `max` shows as gold pips. The "current/max" count follows the pips in
+/// colorblind mode, or whenever there are too many pips to draw
+pub fn draw_energy_pips(x: f32, y: f32, current: i32, max: i32) {
+    let pips = current.max(max).clamp(0, MAX_ENERGY_PIPS);
+    let cy = y - ENERGY_PIP_RADIUS;
+    for i in 0..pips {
+        let cx = x + ENERGY_PIP_RADIUS + i as f32 * ENERGY_PIP_SPACING;
+        if i < current {
+            let fill = if i >= max {
+                GOLD
+            } else {
+                Color::from_rgba(110, 190, 255, 255)
+            };
+            draw_circle(cx, cy, ENERGY_PIP_RADIUS, fill);
+        }
+        draw_circle_lines(cx, cy, ENERGY_PIP_RADIUS, 1.5, LIGHTGRAY);
+    }
+
+    if colorblind_mode() || current.max(max) > MAX_ENERGY_PIPS {
+        let text_x = x + pips as f32 * ENERGY_PIP_SPACING + 4.0;
+        draw_ui_text(&format!("{}/{}", current, max), text_x, y, 16.0, LIGHTGRAY);
+    }
+}
+
 /// Draw a button and return true if clicked
 ///
 /// Note: Frontier uses a different parameter order (text first) than other games
@@ -56,6 +110,7 @@ pub fn card_tooltip(card_name: &str, description: &str) {
         "Stun",
         "Poison",
         "Burn",
+        "Bleed",
         "Regen",
         "Strength",
         "Energy",
@@ -75,6 +130,11 @@ pub fn card_tooltip(card_name: &str, description: &str) {
     draw_tooltip(card_name, &body);
 }
 
+/// Show a plain titled tooltip near the mouse.
+pub fn text_tooltip(title: &str, body: &str) {
+    draw_tooltip(title, body);
+}
+
 fn keyword_definition(keyword: &str) -> Option<&'static str> {
     match keyword {
         "Stress" => Some("Persistent pressure. At 100, a Resolve Check can cause Virtue or Affliction; at 200, Heart Attack damage is applied."),
@@ -84,6 +144,7 @@ fn keyword_definition(keyword: &str) -> Option<&'static str> {
         "Stun" => Some("The affected unit skips its next action while the effect lasts."),
         "Poison" => Some("Damage over time applied at the end of the affected unit's turn."),
         "Burn" => Some("Damage over time applied at the end of the affected unit's turn."),
+        "Bleed" => Some("Damage over time applied at the end of the affected unit's turn, ignoring Block."),
         "Regen" => Some("Healing over time applied at the end of the affected unit's turn."),
         "Strength" => Some("Bonus outgoing damage while the status lasts."),
         "Energy" => Some("Resource spent to play cards. It refreshes at the start of each turn."),